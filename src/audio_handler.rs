@@ -0,0 +1,208 @@
+use bevy::{
+    audio::Volume,
+    prelude::*,
+};
+
+use bevy_rapier3d::prelude::*;
+
+use std::collections::HashMap;
+
+// Resources
+use crate::{
+    AudioCategory,
+    AudioCue,
+    AudioHandler,
+    AudioLoop,
+    AudioSound,
+    GameHandler,
+    GolfBall,
+    HazardType,
+    PhysicsHandler,
+    Preferences,
+    SceneInstanceCupGolfBall,
+    SceneInstanceHazardGolfBall,
+    SceneInstanceOutOfBoundGolfBall,
+};
+
+const AUDIO_SOUNDS: [(AudioSound, &str); 6] = [
+    (AudioSound::Cup, "audio/sfx/cup.wav"),
+    (AudioSound::Impact, "audio/sfx/impact.wav"),
+    (AudioSound::MenuClick, "audio/sfx/menu_click.wav"),
+    (AudioSound::OutOfBounds, "audio/sfx/out_of_bounds.wav"),
+    (AudioSound::Putt, "audio/sfx/putt.wav"),
+    (AudioSound::Splash, "audio/sfx/splash.wav"),
+];
+const AUDIO_AMBIENT_COURSE: &str = "audio/ambient/course.wav";
+const AUDIO_IMPACT_SPEED_MIN: f32 = 0.15;   // Below this it's the ball settling, not a hit
+const AUDIO_IMPACT_SPEED_MAX: f32 = 3.0;    // Full volume at or above this speed
+const AUDIO_AMBIENT_VOLUME: f32 = 0.5;      // Ambience sits under the music
+
+impl AudioSound {
+    pub fn category(&self) -> AudioCategory {
+        match self {
+            AudioSound::MenuClick => AudioCategory::Interface,
+            _ => AudioCategory::Effects,
+        }
+    }
+}
+
+impl AudioHandler {
+    pub fn new() -> Self {
+        AudioHandler {
+            sounds: HashMap::new(),
+            music_track: None,
+        }
+    }
+}
+
+// Helper: audio_handler_music
+fn audio_handler_music_track(level: i32) -> &'static str {
+    match level {
+        1..=9 => "audio/music/front_nine.wav",
+        10..=18 => "audio/music/back_nine.wav",
+        19 => "audio/music/tutorial.wav",
+        _ => "audio/music/menu.wav", // Main menu and the menu levels, 20 and up
+    }
+}
+
+pub fn audio_handler_setup(
+    asset_server: Res<AssetServer>,
+    mut audio: ResMut<AudioHandler>,
+) {
+    for (sound, path) in AUDIO_SOUNDS {
+        audio.sounds.insert(sound, asset_server.load(path));
+    }
+}
+
+pub fn audio_handler_play(
+    mut commands: Commands,
+    mut audio_cue_reader: EventReader<AudioCue>,
+    audio: Res<AudioHandler>,
+    preferences: Res<Preferences>,
+) {
+    for cue in audio_cue_reader.read() {
+        let Some(source) = audio.sounds.get(&cue.sound) else {
+            continue;
+        };
+        // GlobalVolume applies the master volume on top of this
+        let volume = preferences.category_volume(cue.sound.category()) * cue.intensity.clamp(0.0, 1.0);
+        if volume <= 0.0 {
+            continue;
+        }
+        commands.spawn(AudioBundle {
+            source: source.clone(),
+            settings: PlaybackSettings::DESPAWN.with_volume(Volume::new(volume)),
+        });
+    }
+}
+
+// Walls, the green and other balls. Sensors are handled by the scene events below.
+pub fn audio_handler_impact_listener(
+    mut collision_events: EventReader<CollisionEvent>,
+    rapier_context: Res<RapierContext>,
+    golf_balls: Query<&RapierRigidBodyHandle, With<GolfBall>>,
+    mut audio_cue_writer: EventWriter<AudioCue>,
+) {
+    for collision_event in collision_events.read() {
+        let CollisionEvent::Started(entity1, entity2, flags) = collision_event else {
+            continue;
+        };
+        if flags.contains(CollisionEventFlags::SENSOR) {
+            continue;
+        }
+        let speed = [entity1, entity2]
+            .iter()
+            .filter_map(|entity| golf_balls.get(**entity).ok())
+            .filter_map(|rb_handle| rapier_context.bodies.get(rb_handle.0))
+            .map(|rigid_body| rigid_body.linvel().norm())
+            .fold(0.0, f32::max);
+        if speed >= AUDIO_IMPACT_SPEED_MIN {
+            audio_cue_writer.send(AudioCue {
+                sound: AudioSound::Impact,
+                intensity: speed / AUDIO_IMPACT_SPEED_MAX,
+            });
+        }
+    }
+}
+
+// Putts applied on the fixed steps since the last frame, scaled by the power each bonk fired with
+pub fn audio_handler_putt_listener(
+    mut physics_handler: ResMut<PhysicsHandler>,
+    mut audio_cue_writer: EventWriter<AudioCue>,
+) {
+    for power in physics_handler.putts_drain() {
+        audio_cue_writer.send(AudioCue { sound: AudioSound::Putt, intensity: power });
+    }
+}
+
+pub fn audio_handler_scene_listener(
+    mut cup_event_reader: EventReader<SceneInstanceCupGolfBall>,
+    mut oob_event_reader: EventReader<SceneInstanceOutOfBoundGolfBall>,
+    mut hazard_event_reader: EventReader<SceneInstanceHazardGolfBall>,
+    mut audio_cue_writer: EventWriter<AudioCue>,
+) {
+    for _event in cup_event_reader.read() {
+        audio_cue_writer.send(AudioCue { sound: AudioSound::Cup, intensity: 1.0 });
+    }
+    for _event in oob_event_reader.read() {
+        audio_cue_writer.send(AudioCue { sound: AudioSound::OutOfBounds, intensity: 1.0 });
+    }
+    for event in hazard_event_reader.read() {
+        let sound = match event.hazard_type {
+            HazardType::Water => AudioSound::Splash,
+            HazardType::Lava | HazardType::Pit => AudioSound::OutOfBounds,
+        };
+        audio_cue_writer.send(AudioCue { sound, intensity: 1.0 });
+    }
+}
+
+pub fn audio_handler_music(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    game_handler: Res<GameHandler>,
+    preferences: Res<Preferences>,
+    mut audio: ResMut<AudioHandler>,
+    loops: Query<Entity, With<AudioLoop>>,
+) {
+    let level = game_handler.current_level_get();
+    let track = audio_handler_music_track(level);
+    if audio.music_track == Some(track) {
+        return;
+    }
+    info!("function: audio_handler_music: Level: [{}] Track: [{}]", level, track);
+    audio.music_track = Some(track);
+    for entity in loops.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    // Loops are spawned with the master volume folded in since GlobalVolume only applies at spawn
+    commands.spawn((
+        AudioBundle {
+            source: asset_server.load(track),
+            settings: PlaybackSettings::LOOP.with_volume(Volume::new(preferences.volume * preferences.volume_music)),
+        },
+        AudioLoop(AudioCategory::Music),
+    ));
+    if (1..=19).contains(&level) {
+        commands.spawn((
+            AudioBundle {
+                source: asset_server.load(AUDIO_AMBIENT_COURSE),
+                settings: PlaybackSettings::LOOP.with_volume(Volume::new(preferences.volume * preferences.volume_effects * AUDIO_AMBIENT_VOLUME)),
+            },
+            AudioLoop(AudioCategory::Effects),
+        ));
+    }
+}
+
+pub fn audio_handler_loop_volume(
+    preferences: Res<Preferences>,
+    loops: Query<(&AudioSink, &AudioLoop)>,
+) {
+    for (sink, audio_loop) in loops.iter() {
+        let scale = match audio_loop.0 {
+            AudioCategory::Effects => AUDIO_AMBIENT_VOLUME,
+            _ => 1.0,
+        };
+        sink.set_volume(preferences.volume * preferences.category_volume(audio_loop.0) * scale);
+    }
+}
@@ -2,6 +2,7 @@
 use bevy::prelude::*;
 
 use dotenv::dotenv;
+use std::collections::HashMap;
 use std::env;
 use rusqlite::Connection;
 use uuid::Uuid;
@@ -13,11 +14,32 @@ use std::sync::Mutex;
 
 // --- Resources --- //
 use crate::{
+    Achievement,
+    AchievementHandler,
+    DailyBonk,
     DatabaseConnection,
+    HudHandler,
+    InputAction,
+    InputBinding,
+    InputMap,
     Party,
+    Preferences,
+    ScoringFormat,
+    Tournament,
+    TournamentFormat,
     UpdateIdResource,
 };
 
+use crate::preferences_handler::{
+    preferences_aim_assist_from_names,
+    preferences_aim_assist_names,
+    preferences_difficulty_from_name,
+    preferences_play_style_from_name,
+    preferences_window_mode_from_name,
+};
+use crate::level_handler::level_handler::level_handler_par_get;
+use crate::player_handler::tournament_handler::tournament_format_from_name;
+
 impl DatabaseConnection {
     pub fn new(path: &str) -> Self {
         let conn = Connection::open(path).expect("Failed to open SQLite database");
@@ -31,6 +53,869 @@ impl DatabaseConnection {
     }
 }
 
+// Helper: db_pipeline_achievements_load, db_pipeline_achievement_save
+fn db_pipeline_achievement_init_table(conn: &Connection) {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS achievement_table (
+            player_id TEXT NOT NULL,
+            achievement TEXT NOT NULL,
+            unlocked_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (player_id, achievement)
+        )",
+        [],
+    )
+    .expect("Failed to create achievement_table");
+}
+
+pub fn db_pipeline_achievements_load(
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    mut achievements: ResMut<AchievementHandler>,
+) {
+    info!("Init: db_pipeline_achievements_load:");
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_achievement_init_table(&conn);
+
+    let player_id = party.main_player_get_player_id().to_string();
+    let mut statement = conn
+        .prepare("SELECT achievement FROM achievement_table WHERE player_id = ?1")
+        .expect("Failed to prepare achievement_table query");
+    let rows = statement
+        .query_map(rusqlite::params![player_id], |row| row.get::<_, String>(0))
+        .expect("Failed to query achievement_table");
+    for name in rows.flatten() {
+        match Achievement::from_name(&name) {
+            Some(achievement) => {
+                achievements.unlock(achievement);
+            },
+            None => warn!("Skipping unknown achievement: [{}]", name),
+        }
+    }
+}
+
+pub fn db_pipeline_achievement_save(
+    db: &Res<DatabaseConnection>,
+    player_id: &Uuid,
+    achievement: Achievement,
+) {
+    info!("db_pipeline_achievement_save: [{:?}]", achievement);
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_achievement_init_table(&conn);
+    conn.execute(
+        "INSERT OR IGNORE INTO achievement_table (player_id, achievement) VALUES (?1, ?2)",
+        rusqlite::params![
+            player_id.to_string(),
+            format!("{:?}", achievement),
+        ],
+    )
+    .expect("Failed to save achievement");
+}
+
+// Helper: db_pipeline_daily_bonk_load, db_pipeline_daily_bonk_attempt_start, db_pipeline_daily_bonk_result_save
+fn db_pipeline_daily_bonk_init_table(conn: &Connection) {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS daily_bonk_table (
+            player_id TEXT NOT NULL,
+            day INTEGER NOT NULL,
+            strokes INTEGER,
+            to_par INTEGER,
+            started_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (player_id, day)
+        )",
+        [],
+    )
+    .expect("Failed to create daily_bonk_table");
+}
+
+// Helper: db_pipeline_daily_bonk_load, db_pipeline_daily_bonk_result_save
+fn db_pipeline_daily_bonk_leaderboard_query(conn: &Connection, day: i64) -> Vec<(String, i32, i32)> {
+    let mut statement = conn
+        .prepare(
+            "SELECT COALESCE(p.username, 'Unknown'), d.strokes, d.to_par
+            FROM daily_bonk_table d
+            LEFT JOIN player_table p ON p.player_id = d.player_id
+            WHERE d.day = ?1 AND d.strokes IS NOT NULL
+            ORDER BY d.strokes ASC, d.started_at ASC
+            LIMIT 10",
+        )
+        .expect("Failed to prepare daily_bonk_table query");
+    let rows = statement
+        .query_map(rusqlite::params![day], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+        .expect("Failed to query daily_bonk_table");
+    rows.flatten().collect()
+}
+
+pub fn db_pipeline_daily_bonk_load(
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    mut daily: ResMut<DailyBonk>,
+) {
+    info!("Init: db_pipeline_daily_bonk_load:");
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_daily_bonk_init_table(&conn);
+
+    daily.refresh();
+    let day = daily.day_get();
+    let attempted: Result<i64, _> = conn.query_row(
+        "SELECT day FROM daily_bonk_table WHERE player_id = ?1 AND day = ?2",
+        rusqlite::params![party.main_player_get_player_id().to_string(), day],
+        |row| row.get(0),
+    );
+    daily.scored_set(attempted.is_err());
+    daily.leaderboard_set(db_pipeline_daily_bonk_leaderboard_query(&conn, day));
+}
+
+// True when this is the player's first attempt of the day, the row is claimed as the round starts
+pub fn db_pipeline_daily_bonk_attempt_start(
+    db: &Res<DatabaseConnection>,
+    player_id: &Uuid,
+    day: i64,
+) -> bool {
+    info!("db_pipeline_daily_bonk_attempt_start: Day: [{}]", day);
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_daily_bonk_init_table(&conn);
+    let inserted = conn.execute(
+        "INSERT OR IGNORE INTO daily_bonk_table (player_id, day) VALUES (?1, ?2)",
+        rusqlite::params![player_id.to_string(), day],
+    )
+    .expect("Failed to start daily bonk attempt");
+    inserted == 1
+}
+
+// Returns the refreshed leaderboard for the day
+pub fn db_pipeline_daily_bonk_result_save(
+    db: &Res<DatabaseConnection>,
+    player_id: &Uuid,
+    day: i64,
+    strokes: i32,
+    to_par: i32,
+) -> Vec<(String, i32, i32)> {
+    info!("db_pipeline_daily_bonk_result_save: Day: [{}] Strokes: [{}] To Par: [{}]", day, strokes, to_par);
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_daily_bonk_init_table(&conn);
+    conn.execute(
+        "UPDATE daily_bonk_table SET strokes = ?1, to_par = ?2 WHERE player_id = ?3 AND day = ?4",
+        rusqlite::params![strokes, to_par, player_id.to_string(), day],
+    )
+    .expect("Failed to save daily bonk result");
+    db_pipeline_daily_bonk_leaderboard_query(&conn, day)
+}
+
+// Helper: db_pipeline_game_start, db_pipeline_game_finish, db_pipeline_tournament_game_link
+fn db_pipeline_game_init_table(conn: &Connection) {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS game_table (
+            game_id TEXT NOT NULL,
+            map_set_name TEXT NOT NULL,
+            game_started TEXT DEFAULT (CURRENT_TIMESTAMP),
+            game_finished TEXT DEFAULT (CURRENT_TIMESTAMP),
+            scoring_format TEXT NOT NULL DEFAULT 'StrokePlay',
+            CONSTRAINT game_table_game_id_pk PRIMARY KEY (game_id)
+        )",
+        [],
+    )
+    .expect("Failed to create game_table");
+    // Tables created before a column existed pick it up here, the error on an existing column is expected
+    let _ = conn.execute("ALTER TABLE game_table ADD COLUMN scoring_format TEXT NOT NULL DEFAULT 'StrokePlay'", []);
+    let _ = conn.execute("ALTER TABLE game_table ADD COLUMN tournament_id TEXT", []);
+    let _ = conn.execute("ALTER TABLE game_table ADD COLUMN tournament_round INTEGER", []);
+}
+
+pub fn db_pipeline_game_start(
+    db: &Res<DatabaseConnection>,
+    game_id: &Uuid,
+    map_set_name: &str,
+    scoring_format: ScoringFormat,
+) {
+    info!("db_pipeline_game_start: [{}] Map Set: [{}] Scoring: [{:?}]", game_id, map_set_name, scoring_format);
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_game_init_table(&conn);
+    conn.execute(
+        "INSERT OR REPLACE INTO game_table (game_id, map_set_name, scoring_format) VALUES (?1, ?2, ?3)",
+        rusqlite::params![
+            game_id.to_string(),
+            map_set_name,
+            format!("{:?}", scoring_format),
+        ],
+    )
+    .expect("Failed to save game");
+}
+
+pub fn db_pipeline_game_finish(
+    db: &Res<DatabaseConnection>,
+    game_id: &Uuid,
+) {
+    info!("db_pipeline_game_finish: [{}]", game_id);
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_game_init_table(&conn);
+    conn.execute(
+        "UPDATE game_table SET game_finished = CURRENT_TIMESTAMP WHERE game_id = ?1",
+        rusqlite::params![game_id.to_string()],
+    )
+    .expect("Failed to finish game");
+}
+
+// Helper: db_pipeline_ghost_load, db_pipeline_ghost_save
+fn db_pipeline_ghost_init_table(conn: &Connection) {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS ghost_table (
+            player_id TEXT NOT NULL,
+            hole INTEGER NOT NULL,
+            strokes INTEGER NOT NULL,
+            track TEXT NOT NULL,
+            recorded_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (player_id, hole)
+        )",
+        [],
+    )
+    .expect("Failed to create ghost_table");
+    let _ = conn.execute("ALTER TABLE ghost_table ADD COLUMN seconds REAL", []);
+}
+
+// (strokes, seconds, encoded track) of the stored best attempt, see ghost_handler_track_decode
+// Ghosts saved before the time was kept have no seconds and lose any tie
+pub fn db_pipeline_ghost_load(
+    db: &Res<DatabaseConnection>,
+    player_id: &Uuid,
+    hole: i32,
+) -> Option<(i32, f32, String)> {
+    info!("db_pipeline_ghost_load: Hole: [{}]", hole);
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_ghost_init_table(&conn);
+    conn.query_row(
+        "SELECT strokes, seconds, track FROM ghost_table WHERE player_id = ?1 AND hole = ?2",
+        rusqlite::params![player_id.to_string(), hole],
+        |row| Ok((row.get(0)?, row.get::<_, Option<f32>>(1)?.unwrap_or(f32::INFINITY), row.get(2)?)),
+    )
+    .ok()
+}
+
+pub fn db_pipeline_ghost_save(
+    db: &Res<DatabaseConnection>,
+    player_id: &Uuid,
+    hole: i32,
+    strokes: i32,
+    seconds: f32,
+    track: &str,
+) {
+    info!("db_pipeline_ghost_save: Hole: [{}] Strokes: [{}] Seconds: [{:.2}]", hole, strokes, seconds);
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_ghost_init_table(&conn);
+    conn.execute(
+        "INSERT OR REPLACE INTO ghost_table (player_id, hole, strokes, seconds, track) VALUES (?1, ?2, ?3, ?4, ?5)",
+        rusqlite::params![player_id.to_string(), hole, strokes, seconds, track],
+    )
+    .expect("Failed to save ghost");
+}
+
+// Helper: db_pipeline_input_map_load, db_pipeline_input_map_save
+fn db_pipeline_input_map_init_table(conn: &Connection) {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS input_map_table (
+            player_id TEXT NOT NULL,
+            action TEXT NOT NULL,
+            binding TEXT NOT NULL,
+            PRIMARY KEY (player_id, action)
+        )",
+        [],
+    )
+    .expect("Failed to create input_map_table");
+}
+
+pub fn db_pipeline_input_map_load(
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    mut input_map: ResMut<InputMap>,
+) {
+    info!("Init: db_pipeline_input_map_load:");
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_input_map_init_table(&conn);
+
+    let player_id = party.main_player_get_player_id().to_string();
+    let mut statement = conn
+        .prepare("SELECT action, binding FROM input_map_table WHERE player_id = ?1")
+        .expect("Failed to prepare input_map_table query");
+    let rows = statement
+        .query_map(rusqlite::params![player_id], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))
+        .expect("Failed to query input_map_table");
+    for (action, binding) in rows.flatten() {
+        // Unknown rows are left on their defaults so stale actions don't break a profile
+        match (InputAction::from_name(&action), InputBinding::from_name(&binding)) {
+            (Some(action), Some(binding)) => input_map.binding_load(action, binding),
+            _ => warn!("Skipping unknown input binding: [{}]::[{}]", action, binding),
+        }
+    }
+}
+
+pub fn db_pipeline_input_map_save(
+    db: &Res<DatabaseConnection>,
+    player_id: &Uuid,
+    action: InputAction,
+    binding: InputBinding,
+) {
+    info!("db_pipeline_input_map_save: [{:?}]::[{:?}]", action, binding);
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_input_map_init_table(&conn);
+    conn.execute(
+        "INSERT OR REPLACE INTO input_map_table (player_id, action, binding) VALUES (?1, ?2, ?3)",
+        rusqlite::params![
+            player_id.to_string(),
+            format!("{:?}", action),
+            binding.name(),
+        ],
+    )
+    .expect("Failed to save input binding");
+}
+
+// Helper: db_pipeline_preferences_load, db_pipeline_preferences_save
+fn db_pipeline_preferences_init_table(conn: &Connection) {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS preferences_table (
+            player_id TEXT PRIMARY KEY,
+            window_mode TEXT NOT NULL,
+            resolution_width INTEGER NOT NULL,
+            resolution_height INTEGER NOT NULL,
+            vsync INTEGER NOT NULL,
+            pan_sensitivity REAL NOT NULL,
+            orbit_sensitivity REAL NOT NULL,
+            zoom_sensitivity REAL NOT NULL,
+            volume REAL NOT NULL,
+            volume_music REAL NOT NULL DEFAULT 0.6,
+            volume_effects REAL NOT NULL DEFAULT 1.0,
+            volume_interface REAL NOT NULL DEFAULT 0.8,
+            aim_assist TEXT NOT NULL,
+            play_style TEXT NOT NULL,
+            flyover INTEGER NOT NULL DEFAULT 1,
+            ball_trails INTEGER NOT NULL DEFAULT 1,
+            camera_follow_lag REAL NOT NULL DEFAULT 0.25,
+            camera_follow_cup_distance REAL NOT NULL DEFAULT 0.3,
+            difficulty TEXT NOT NULL DEFAULT 'Medium'
+        )",
+        [],
+    )
+    .expect("Failed to create preferences_table");
+    // Tables created before a column existed pick it up here, the error on an existing column is expected
+    let _ = conn.execute("ALTER TABLE preferences_table ADD COLUMN flyover INTEGER NOT NULL DEFAULT 1", []);
+    let _ = conn.execute("ALTER TABLE preferences_table ADD COLUMN ball_trails INTEGER NOT NULL DEFAULT 1", []);
+    let _ = conn.execute("ALTER TABLE preferences_table ADD COLUMN volume_music REAL NOT NULL DEFAULT 0.6", []);
+    let _ = conn.execute("ALTER TABLE preferences_table ADD COLUMN volume_effects REAL NOT NULL DEFAULT 1.0", []);
+    let _ = conn.execute("ALTER TABLE preferences_table ADD COLUMN volume_interface REAL NOT NULL DEFAULT 0.8", []);
+    let _ = conn.execute("ALTER TABLE preferences_table ADD COLUMN camera_follow_lag REAL NOT NULL DEFAULT 0.25", []);
+    let _ = conn.execute("ALTER TABLE preferences_table ADD COLUMN camera_follow_cup_distance REAL NOT NULL DEFAULT 0.3", []);
+    let _ = conn.execute("ALTER TABLE preferences_table ADD COLUMN difficulty TEXT NOT NULL DEFAULT 'Medium'", []);
+}
+
+pub fn db_pipeline_preferences_load(
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    mut preferences: ResMut<Preferences>,
+) {
+    info!("Init: db_pipeline_preferences_load:");
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_preferences_init_table(&conn);
+
+    let player_id = party.main_player_get_player_id().to_string();
+    let stored = conn.query_row(
+        "SELECT window_mode, resolution_width, resolution_height, vsync, pan_sensitivity, orbit_sensitivity, zoom_sensitivity, volume, aim_assist, play_style, flyover, ball_trails, volume_music, volume_effects, volume_interface, camera_follow_lag, camera_follow_cup_distance, difficulty
+            FROM preferences_table WHERE player_id = ?1",
+        rusqlite::params![player_id],
+        |row| {
+            Ok(Preferences {
+                window_mode: preferences_window_mode_from_name(&row.get::<_, String>(0)?),
+                resolution: (row.get(1)?, row.get(2)?),
+                vsync: row.get(3)?,
+                pan_sensitivity: row.get(4)?,
+                orbit_sensitivity: row.get(5)?,
+                zoom_sensitivity: row.get(6)?,
+                volume: row.get(7)?,
+                aim_assist: preferences_aim_assist_from_names(&row.get::<_, String>(8)?),
+                play_style: preferences_play_style_from_name(&row.get::<_, String>(9)?),
+                flyover: row.get(10)?,
+                ball_trails: row.get(11)?,
+                volume_music: row.get(12)?,
+                volume_effects: row.get(13)?,
+                volume_interface: row.get(14)?,
+                camera_follow_lag: row.get(15)?,
+                camera_follow_cup_distance: row.get(16)?,
+                difficulty: preferences_difficulty_from_name(&row.get::<_, String>(17)?),
+            })
+        },
+    );
+    match stored {
+        Ok(stored) => *preferences = stored,
+        Err(_) => info!("No stored preferences, using defaults"),
+    }
+}
+
+pub fn db_pipeline_preferences_save(
+    db: &Res<DatabaseConnection>,
+    player_id: &Uuid,
+    preferences: &Preferences,
+) {
+    info!("db_pipeline_preferences_save: [{:?}]", preferences);
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_preferences_init_table(&conn);
+    conn.execute(
+        "INSERT OR REPLACE INTO preferences_table
+            (player_id, window_mode, resolution_width, resolution_height, vsync, pan_sensitivity, orbit_sensitivity, zoom_sensitivity, volume, aim_assist, play_style, flyover, ball_trails, volume_music, volume_effects, volume_interface, camera_follow_lag, camera_follow_cup_distance, difficulty)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19)",
+        rusqlite::params![
+            player_id.to_string(),
+            format!("{:?}", preferences.window_mode),
+            preferences.resolution.0,
+            preferences.resolution.1,
+            preferences.vsync,
+            preferences.pan_sensitivity,
+            preferences.orbit_sensitivity,
+            preferences.zoom_sensitivity,
+            preferences.volume,
+            preferences_aim_assist_names(&preferences.aim_assist),
+            format!("{:?}", preferences.play_style),
+            preferences.flyover,
+            preferences.ball_trails,
+            preferences.volume_music,
+            preferences.volume_effects,
+            preferences.volume_interface,
+            preferences.camera_follow_lag,
+            preferences.camera_follow_cup_distance,
+            format!("{:?}", preferences.difficulty),
+        ],
+    )
+    .expect("Failed to save preferences");
+}
+
+pub fn db_pipeline_player_golf_ball_load(
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+) {
+    info!("Init: db_pipeline_player_golf_ball_load:");
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+
+    let player_id = party.main_player_get_player_id().to_string();
+    let stored: Result<Option<u32>, _> = conn.query_row(
+        "SELECT golf_ball FROM player_table WHERE player_id = ?1",
+        rusqlite::params![player_id],
+        |row| row.get(0),
+    );
+    match stored {
+        Ok(Some(golf_ball)) => party.main_player_set_golf_ball(golf_ball),
+        _ => info!("No stored golf ball, using default"),
+    }
+}
+
+pub fn db_pipeline_player_golf_ball_save(
+    db: &Res<DatabaseConnection>,
+    player_id: &Uuid,
+    golf_ball: u32,
+) {
+    info!("db_pipeline_player_golf_ball_save: [{}]", golf_ball);
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    conn.execute(
+        "UPDATE player_table SET golf_ball = ?1 WHERE player_id = ?2",
+        rusqlite::params![golf_ball, player_id.to_string()],
+    )
+    .expect("Failed to save golf ball");
+}
+
+pub fn db_pipeline_player_username_load(
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    mut hud: ResMut<HudHandler>,
+) {
+    info!("Init: db_pipeline_player_username_load:");
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+
+    let player_id = party.main_player_get_player_id().to_string();
+    let stored: Result<String, _> = conn.query_row(
+        "SELECT username FROM player_table WHERE player_id = ?1",
+        rusqlite::params![player_id],
+        |row| row.get(0),
+    );
+    match stored {
+        Ok(username) => hud.main_player_name_set(username),
+        Err(_) => info!("No stored username, using default"),
+    }
+}
+
+pub fn db_pipeline_player_putter_load(
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+) {
+    info!("Init: db_pipeline_player_putter_load:");
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+
+    let player_id = party.main_player_get_player_id().to_string();
+    let stored: Result<Option<u32>, _> = conn.query_row(
+        "SELECT putter FROM player_table WHERE player_id = ?1",
+        rusqlite::params![player_id],
+        |row| row.get(0),
+    );
+    match stored {
+        Ok(Some(putter)) => party.main_player_set_putter(putter),
+        _ => info!("No stored putter, using default"),
+    }
+}
+
+pub fn db_pipeline_player_putter_save(
+    db: &Res<DatabaseConnection>,
+    player_id: &Uuid,
+    putter: u32,
+) {
+    info!("db_pipeline_player_putter_save: [{}]", putter);
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    conn.execute(
+        "UPDATE player_table SET putter = ?1 WHERE player_id = ?2",
+        rusqlite::params![putter, player_id.to_string()],
+    )
+    .expect("Failed to save putter");
+}
+
+// Helper: db_pipeline_map_set_id_get, db_pipeline_record_handicap_rounds_load
+fn db_pipeline_map_set_init_table(conn: &Connection) {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS map_set_table (
+            map_set_id TEXT(32) NOT NULL,
+            map_set_name TEXT(180) NOT NULL,
+            created TEXT DEFAULT (CURRENT_TIMESTAMP) NOT NULL,
+            last_updated TEXT DEFAULT (CURRENT_TIMESTAMP) NOT NULL,
+            hole_range_start INTEGER NOT NULL,
+            CONSTRAINT map_set_table_map_set_id_pk PRIMARY KEY (map_set_id)
+        )",
+        [],
+    )
+    .expect("Failed to create map_set_table");
+}
+
+// map_set_table's id for a local map set, its row is added the first time a round is saved on it
+fn db_pipeline_map_set_id_get(conn: &Connection, map_set_name: &str, hole_range_start: i32) -> String {
+    db_pipeline_map_set_init_table(conn);
+    let stored = conn.query_row(
+        "SELECT map_set_id FROM map_set_table WHERE map_set_name = ?1",
+        rusqlite::params![map_set_name],
+        |row| row.get::<_, String>(0),
+    );
+    if let Ok(map_set_id) = stored {
+        return map_set_id;
+    }
+    let map_set_id = Uuid::now_v7().to_string();
+    conn.execute(
+        "INSERT INTO map_set_table (map_set_id, map_set_name, hole_range_start) VALUES (?1, ?2, ?3)",
+        rusqlite::params![map_set_id, map_set_name, hole_range_start],
+    )
+    .expect("Failed to save map set");
+    map_set_id
+}
+
+// Helper: db_pipeline_record_save, db_pipeline_record_handicap_rounds_load
+fn db_pipeline_record_init_table(conn: &Connection) {
+    let holes: Vec<String> = (1..=18).map(|hole| format!("par_{hole} INTEGER, score_hole_{hole} INTEGER DEFAULT (NULL)")).collect();
+    conn.execute(
+        &format!(
+            "CREATE TABLE IF NOT EXISTS record_table (
+                record_id TEXT NOT NULL,
+                player_id TEXT NOT NULL,
+                game_id TEXT NOT NULL,
+                map_set_id TEXT NOT NULL,
+                {},
+                CONSTRAINT record_table_record_id_pk PRIMARY KEY (record_id)
+            )",
+            holes.join(",\n                "),
+        ),
+        [],
+    )
+    .expect("Failed to create record_table");
+}
+
+// One row per player per finished game, holes that weren't played stay NULL
+pub fn db_pipeline_record_save(
+    db: &Res<DatabaseConnection>,
+    game_id: &Uuid,
+    map_set_name: &str,
+    player_id: &Uuid,
+    score: &[i32; 18],
+) {
+    info!("db_pipeline_record_save: Game: [{}] Player: [{}]", game_id, player_id);
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_record_init_table(&conn);
+    let hole_range_start = score.iter().position(|strokes| *strokes > 0).map_or(1, |index| index as i32 + 1);
+    let map_set_id = db_pipeline_map_set_id_get(&conn, map_set_name, hole_range_start);
+    let mut columns = vec![String::from("record_id"), String::from("player_id"), String::from("game_id"), String::from("map_set_id")];
+    let mut values = vec![
+        rusqlite::types::Value::Text(Uuid::now_v7().to_string()),
+        rusqlite::types::Value::Text(player_id.to_string()),
+        rusqlite::types::Value::Text(game_id.to_string()),
+        rusqlite::types::Value::Text(map_set_id),
+    ];
+    for (index, strokes) in score.iter().enumerate().filter(|(_, strokes)| **strokes > 0) {
+        let hole = index as i32 + 1;
+        columns.push(format!("par_{}", hole));
+        values.push(rusqlite::types::Value::Integer(level_handler_par_get(hole) as i64));
+        columns.push(format!("score_hole_{}", hole));
+        values.push(rusqlite::types::Value::Integer(*strokes as i64));
+    }
+    let placeholders: Vec<String> = (1..=values.len()).map(|index| format!("?{}", index)).collect();
+    conn.execute(
+        &format!("INSERT INTO record_table ({}) VALUES ({})", columns.join(", "), placeholders.join(", ")),
+        rusqlite::params_from_iter(values.iter()),
+    )
+    .expect("Failed to save record");
+}
+
+// (strokes over par, holes played, map set name) for every round the player has on record, newest first
+pub fn db_pipeline_record_handicap_rounds_load(
+    db: &Res<DatabaseConnection>,
+    player_id: &Uuid,
+) -> Vec<(i32, i32, String)> {
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_record_init_table(&conn);
+    db_pipeline_map_set_init_table(&conn);
+    let over_par: Vec<String> = (1..=18).map(|hole| format!("COALESCE(score_hole_{hole} - par_{hole}, 0)")).collect();
+    let holes: Vec<String> = (1..=18).map(|hole| format!("(score_hole_{hole} IS NOT NULL AND par_{hole} IS NOT NULL)")).collect();
+    // record_id is a v7 uuid, so it sorts by when the round was saved.
+    // Rows saved before map_set_table was filled in hold the map set's name in place of its id.
+    let mut statement = conn
+        .prepare(&format!(
+            "SELECT {}, {}, COALESCE(map_set_table.map_set_name, record_table.map_set_id)
+                FROM record_table LEFT JOIN map_set_table ON map_set_table.map_set_id = record_table.map_set_id
+                WHERE record_table.player_id = ?1 ORDER BY record_table.record_id DESC",
+            over_par.join(" + "),
+            holes.join(" + "),
+        ))
+        .expect("Failed to prepare record_table query");
+    let rows = statement
+        .query_map(rusqlite::params![player_id.to_string()], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+        .expect("Failed to query record_table");
+    rows.flatten().collect()
+}
+
+// Helper: db_pipeline_time_trial_load, db_pipeline_time_trial_split_save
+fn db_pipeline_time_trial_init_table(conn: &Connection) {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS time_trial_table (
+            player_id TEXT NOT NULL,
+            hole INTEGER NOT NULL,
+            split REAL NOT NULL,
+            strokes INTEGER NOT NULL,
+            set_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (player_id, hole)
+        )",
+        [],
+    )
+    .expect("Failed to create time_trial_table");
+}
+
+// Personal best split per hole
+pub fn db_pipeline_time_trial_load(
+    db: &Res<DatabaseConnection>,
+    player_id: &Uuid,
+) -> HashMap<i32, f32> {
+    info!("db_pipeline_time_trial_load:");
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_time_trial_init_table(&conn);
+    let mut statement = conn
+        .prepare("SELECT hole, split FROM time_trial_table WHERE player_id = ?1")
+        .expect("Failed to prepare time_trial_table query");
+    let rows = statement
+        .query_map(rusqlite::params![player_id.to_string()], |row| Ok((row.get::<_, i32>(0)?, row.get::<_, f64>(1)? as f32)))
+        .expect("Failed to query time_trial_table");
+    rows.flatten().collect()
+}
+
+// True when the split beat the stored personal best, slower splits leave the row alone
+pub fn db_pipeline_time_trial_split_save(
+    db: &Res<DatabaseConnection>,
+    player_id: &Uuid,
+    hole: i32,
+    split: f32,
+    strokes: i32,
+) -> bool {
+    info!("db_pipeline_time_trial_split_save: Hole: [{}] Split: [{:.2}] Strokes: [{}]", hole, split, strokes);
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_time_trial_init_table(&conn);
+    let saved = conn.execute(
+        "INSERT INTO time_trial_table (player_id, hole, split, strokes) VALUES (?1, ?2, ?3, ?4)
+        ON CONFLICT (player_id, hole) DO UPDATE SET split = excluded.split, strokes = excluded.strokes, set_at = CURRENT_TIMESTAMP
+        WHERE excluded.split < time_trial_table.split",
+        rusqlite::params![player_id.to_string(), hole, split as f64, strokes],
+    )
+    .expect("Failed to save time trial split");
+    saved == 1
+}
+
+// Helper: db_pipeline_tournament_load, db_pipeline_tournament_start, db_pipeline_tournament_round_save, db_pipeline_tournament_finish
+fn db_pipeline_tournament_init_table(conn: &Connection) {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS tournament_table (
+            tournament_id TEXT NOT NULL,
+            format TEXT NOT NULL,
+            rounds_played INTEGER NOT NULL DEFAULT 0,
+            cut TEXT NOT NULL DEFAULT '',
+            tournament_started TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            tournament_finished TEXT,
+            PRIMARY KEY (tournament_id)
+        )",
+        [],
+    )
+    .expect("Failed to create tournament_table");
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS tournament_score_table (
+            tournament_id TEXT NOT NULL,
+            round INTEGER NOT NULL,
+            player_id TEXT NOT NULL,
+            name TEXT NOT NULL,
+            strokes INTEGER NOT NULL,
+            to_par INTEGER NOT NULL,
+            PRIMARY KEY (tournament_id, round, player_id)
+        )",
+        [],
+    )
+    .expect("Failed to create tournament_score_table");
+}
+
+// Picks the latest unfinished tournament back up so it can be continued from the local menu
+pub fn db_pipeline_tournament_load(
+    db: Res<DatabaseConnection>,
+    mut tournament: ResMut<Tournament>,
+) {
+    info!("Init: db_pipeline_tournament_load:");
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_tournament_init_table(&conn);
+
+    let unfinished: Result<(String, String, i64, String), _> = conn.query_row(
+        "SELECT tournament_id, format, rounds_played, cut FROM tournament_table
+        WHERE tournament_finished IS NULL
+        ORDER BY tournament_started DESC
+        LIMIT 1",
+        [],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+    );
+    let Ok((tournament_id, format, rounds_played, cut)) = unfinished else {
+        return;
+    };
+    let Ok(tournament_id) = Uuid::parse_str(&tournament_id) else {
+        return;
+    };
+    let cut: Vec<Uuid> = cut.split(',').filter_map(|player_id| Uuid::parse_str(player_id).ok()).collect();
+
+    let mut statement = conn
+        .prepare(
+            "SELECT round, player_id, name, strokes, to_par FROM tournament_score_table
+            WHERE tournament_id = ?1
+            ORDER BY round ASC",
+        )
+        .expect("Failed to prepare tournament_score_table query");
+    let rows = statement
+        .query_map(rusqlite::params![tournament_id.to_string()], |row| {
+            Ok((row.get::<_, i64>(0)? as usize, row.get::<_, String>(1)?, row.get(2)?, row.get(3)?, row.get(4)?))
+        })
+        .expect("Failed to query tournament_score_table");
+    let scores: Vec<(usize, Uuid, String, i32, i32)> = rows
+        .flatten()
+        .filter_map(|(round, player_id, name, strokes, to_par)| Some((round, Uuid::parse_str(&player_id).ok()?, name, strokes, to_par)))
+        .collect();
+    tournament.resume(tournament_id, tournament_format_from_name(&format), rounds_played as usize, cut, scores);
+}
+
+pub fn db_pipeline_tournament_start(
+    db: &Res<DatabaseConnection>,
+    tournament_id: &Uuid,
+    format: TournamentFormat,
+) {
+    info!("db_pipeline_tournament_start: [{}] Format: [{:?}]", tournament_id, format);
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_tournament_init_table(&conn);
+    conn.execute(
+        "INSERT INTO tournament_table (tournament_id, format) VALUES (?1, ?2)",
+        rusqlite::params![tournament_id.to_string(), format!("{:?}", format)],
+    )
+    .expect("Failed to save tournament");
+}
+
+// The round's game_table row was written as the game started, this ties it to the tournament
+pub fn db_pipeline_tournament_game_link(
+    db: &Res<DatabaseConnection>,
+    tournament_id: &Uuid,
+    round: usize,
+    game_id: &Uuid,
+) {
+    info!("db_pipeline_tournament_game_link: [{}] Round: [{}] Game: [{}]", tournament_id, round, game_id);
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_game_init_table(&conn);
+    conn.execute(
+        "UPDATE game_table SET tournament_id = ?1, tournament_round = ?2 WHERE game_id = ?3",
+        rusqlite::params![tournament_id.to_string(), round as i64, game_id.to_string()],
+    )
+    .expect("Failed to link game to tournament");
+}
+
+// scores are (player_id, name, strokes, to par), cut is everyone out of the tournament so far
+pub fn db_pipeline_tournament_round_save(
+    db: &Res<DatabaseConnection>,
+    tournament_id: &Uuid,
+    round: usize,
+    scores: &[(Uuid, String, i32, i32)],
+    cut: &[Uuid],
+) {
+    info!("db_pipeline_tournament_round_save: [{}] Round: [{}] Scores: [{}] Cut: [{}]", tournament_id, round, scores.len(), cut.len());
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_tournament_init_table(&conn);
+    for (player_id, name, strokes, to_par) in scores.iter() {
+        conn.execute(
+            "INSERT OR REPLACE INTO tournament_score_table (tournament_id, round, player_id, name, strokes, to_par) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            rusqlite::params![tournament_id.to_string(), round as i64, player_id.to_string(), name, strokes, to_par],
+        )
+        .expect("Failed to save tournament score");
+    }
+    let cut: Vec<String> = cut.iter().map(|player_id| player_id.to_string()).collect();
+    conn.execute(
+        "UPDATE tournament_table SET rounds_played = ?1, cut = ?2 WHERE tournament_id = ?3",
+        rusqlite::params![round as i64, cut.join(","), tournament_id.to_string()],
+    )
+    .expect("Failed to save tournament round");
+}
+
+// Run after the last round, and when a tournament is abandoned part way
+pub fn db_pipeline_tournament_finish(
+    db: &Res<DatabaseConnection>,
+    tournament_id: &Uuid,
+) {
+    info!("db_pipeline_tournament_finish: [{}]", tournament_id);
+    let conn = db.get_connection();
+    let conn = conn.lock().unwrap(); // Lock the mutex
+    db_pipeline_tournament_init_table(&conn);
+    conn.execute(
+        "UPDATE tournament_table SET tournament_finished = CURRENT_TIMESTAMP WHERE tournament_id = ?1",
+        rusqlite::params![tournament_id.to_string()],
+    )
+    .expect("Failed to finish tournament");
+}
+
 pub fn db_pipeline_init_local_player(
     db: Res<DatabaseConnection>,
     mut party: ResMut<Party>,
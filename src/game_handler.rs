@@ -18,12 +18,18 @@ use crate::{
 use crate::{
     CheckStateGH,
     CheckStateRT,
+    DailyBonk,
     GameHandler,
     GameRecord,
+    MenuCycle,
     Party,
     RunTrigger,
+    ScoringFormat,
 };
 
+use crate::level_handler::level_handler::level_handler_state_level_for_hole;
+use crate::network_handler::network_spectator_clear;
+
 impl GameHandler {
     pub fn new() -> Self {
         GameHandler {
@@ -38,10 +44,12 @@ impl GameHandler {
             check_round_start: true,
             check_network_server_connection: false,
             check_remote_game: false,
+            check_spectator: false,
             current_level: 0,
             add_physics_attempts: 0,
             game_id: None,
             remotely_pushed_state: None,
+            scoring_format: ScoringFormat::StrokePlay,
         }
     }
 
@@ -91,6 +99,10 @@ impl GameHandler {
                 self.check_remote_game = state;
                 info!("response: remote_game: {}", self.get(CheckStateGH::RemoteGame));  
             },
+            CheckStateGH::Spectator => {
+                self.check_spectator = state;
+                info!("response: spectator: {}", self.get(CheckStateGH::Spectator));  
+            },
         }
     }
     
@@ -129,6 +141,9 @@ impl GameHandler {
             CheckStateGH::RemoteGame => {
                 self.check_remote_game
             },
+            CheckStateGH::Spectator => {
+                self.check_spectator
+            },
         }
     }
 
@@ -190,6 +205,9 @@ impl GameHandler {
             &StateMapSet::SelectAHole => {
                 self.current_level = 9;
             },
+            &StateMapSet::DailyBonk => {
+                // The first hole comes from the DailyBonk resource, see game_handler_start_daily_bonk
+            },
         }
     }
 
@@ -231,6 +249,31 @@ impl GameHandler {
         self.current_level_set(24);
     }
 
+    // Main menu or any of its pages
+    pub fn current_level_is_menu(&self) -> bool {
+        self.current_level == 0 || (20..=24).contains(&self.current_level)
+    }
+
+    pub fn current_level_is_menu_leaderboard(&self) -> bool {
+        self.current_level == 20
+    }
+
+    pub fn current_level_is_menu_local(&self) -> bool {
+        self.current_level == 21
+    }
+
+    pub fn current_level_is_menu_online(&self) -> bool {
+        self.current_level == 22
+    }
+
+    pub fn current_level_is_menu_preferences(&self) -> bool {
+        self.current_level == 23
+    }
+
+    pub fn current_level_is_menu_player(&self) -> bool {
+        self.current_level == 24
+    }
+
     // pushed state logic 
 
     pub fn pushed_state_get(&self) -> StateUpdateRef {
@@ -243,12 +286,27 @@ impl GameHandler {
     ) {
         self.remotely_pushed_state = Some(parsed_state.unwrap());
     }
+
+    // Scoring format logic
+
+    pub fn scoring_format_get(&self) -> ScoringFormat {
+        self.scoring_format
+    }
+
+    pub fn scoring_format_cycle(&mut self) -> ScoringFormat {
+        self.scoring_format = self.scoring_format.next();
+        self.scoring_format
+    }
 }
 
 impl GameRecord {
     pub fn unwrap(&self) -> (Uuid, Vec<Uuid>, Vec<[i32; 18]>) {
         (self.game_id, self.players.clone(), self.scores.clone())
     } 
+
+    pub fn scoring_format_get(&self) -> ScoringFormat {
+        self.scoring_format
+    }
 }
 
 pub fn game_handler_game_start (
@@ -257,6 +315,7 @@ pub fn game_handler_game_start (
     mut run_trigger: ResMut<RunTrigger>,
     state_game: Res<State<StateGame>>,
     state_map_set: Res<State<StateMapSet>>,
+    daily: Res<DailyBonk>,
 ) {
     info!("function: game_handler_game_start "); 
     {
@@ -292,6 +351,11 @@ pub fn game_handler_game_start (
                             next_level.set(StateLevel::Hole10);
                         },
                         StateMapSet::SelectAHole => {},
+                        StateMapSet::DailyBonk => {
+                            map_state_selected = true;
+                            game_handler.current_level_set(daily.hole_first());
+                            next_level.set(level_handler_state_level_for_hole(daily.hole_first()));
+                        },
                     };
                     if map_state_selected == true {
                         info!("level_handler_init_level_game_handler_current_level: level [{}]", game_handler.current_level_get());
@@ -330,6 +394,7 @@ pub fn game_handler_game_state_exit_routines(
             StateGame::NotInGame => {},
             StateGame::InGame => {
                 game_handler.set_target(CheckStateGH::InGame, false);
+                network_spectator_clear(&mut game_handler, &mut party);
                 run_trigger.set_target(CheckStateRT::GolfBallHandlerEndGame, true);
                 run_trigger.set_target(CheckStateRT::LevelHandlerPurgeProtocol, true);
                 next_menu_state.set(StateMenu::MenuMainMenu);
@@ -382,7 +447,7 @@ pub fn game_handler_game_state_start_routines(
                 }
                 info!("StateGame::InGame");
                 next_state_game.set(StateGame::InGame);
-                next_camera_state.set(StateCameraOrbitEntity::Ball);
+                next_camera_state.set(StateCameraOrbitEntity::Follow);
                 for mut state in pan_orbit_camera_query.iter_mut() {
                     info!("{:?}", state);
                     state.radius = 2.75;
@@ -397,6 +462,28 @@ pub fn game_handler_game_state_start_routines(
     info!("post response: game_handler_game_state_start_routines: {}", run_trigger.get(CheckStateRT::GameHandlerGameStateStartRoutines));  
 }
 
+pub fn game_handler_start_daily_bonk(
+    mut game_handler: ResMut<GameHandler>,
+    mut run_trigger: ResMut<RunTrigger>,
+    mut daily: ResMut<DailyBonk>,
+    mut next_level_state: ResMut<NextState<StateLevel>>,
+    mut next_map_set_state: ResMut<NextState<StateMapSet>>,
+) {
+    info!("function: game_handler_start_daily_bonk"); 
+    {
+        // Picks up a new course if the UTC day rolled over while the game was open
+        daily.refresh();
+        info!("Daily Bonk: Day: [{}] Holes: {:?} Stroke Cap: {:?}", daily.day_get(), daily.holes_get(), daily.stroke_cap_get());
+        game_handler.current_level_set(daily.hole_first());
+        next_level_state.set(level_handler_state_level_for_hole(daily.hole_first()));
+        next_map_set_state.set(StateMapSet::DailyBonk);
+        run_trigger.set_target(CheckStateRT::LevelHandlerInitLevelGameHandlerCurrentLevel, true);
+        run_trigger.set_target(CheckStateRT::GameHandlerGameStart, true);
+    }
+    run_trigger.set_target(CheckStateRT::GameHandlerStartDailyBonk, false);
+    info!("post response: game_handler_start_daily_bonk: [{}]", run_trigger.get(CheckStateRT::GameHandlerStartDailyBonk));  
+}
+
 pub fn game_handler_start_tutorial(
     mut game_handler: ResMut<GameHandler>,
     mut run_trigger: ResMut<RunTrigger>,
@@ -0,0 +1,249 @@
+use bevy::prelude::*;
+
+use time::OffsetDateTime;
+
+// States
+use crate::StateMapSet;
+
+// Resources
+use crate::{
+    CheckStateRT,
+    DailyBonk,
+    DailyBonkButton,
+    DailyBonkPanel,
+    DailyBonkText,
+    DatabaseConnection,
+    GameHandler,
+    MenuPage,
+    Party,
+    ResultsHandler,
+    RunTrigger,
+};
+
+use crate::split_mix_next;
+
+use crate::database_handler::{
+    db_pipeline_daily_bonk_attempt_start,
+    db_pipeline_daily_bonk_result_save,
+};
+use crate::user_interface::menu_button_handler::{
+    menu_button_bundle,
+    menu_button_text_style,
+};
+
+const DAILY_BONK_HOLE_COUNT: usize = 6;
+const DAILY_BONK_SEED_SALT: u64 = 0x4441_494C_5942_4F4E; // "DAILYBON"
+const DAILY_BONK_STROKE_CAPS: [Option<i32>; 4] = [None, Some(4), Some(5), Some(6)];
+
+impl DailyBonk {
+    pub fn new() -> Self {
+        let mut daily = DailyBonk {
+            day: i64::MIN,
+            holes: Vec::new(),
+            stroke_cap: None,
+            scored: true,
+            leaderboard: Vec::new(),
+        };
+        daily.refresh();
+        daily
+    }
+
+    // Rebuilds the course once the UTC day rolls over, true when it did
+    pub fn refresh(&mut self) -> bool {
+        let day = daily_bonk_day_today();
+        if day == self.day {
+            return false;
+        }
+        let (holes, stroke_cap) = daily_bonk_course(day);
+        info!("function: DailyBonk::refresh: Day: [{}] Holes: {:?} Stroke Cap: {:?}", day, holes, stroke_cap);
+        self.day = day;
+        self.holes = holes;
+        self.stroke_cap = stroke_cap;
+        self.scored = true;
+        self.leaderboard.clear();
+        true
+    }
+
+    pub fn day_get(&self) -> i64 {
+        self.day
+    }
+
+    pub fn holes_get(&self) -> &Vec<i32> {
+        &self.holes
+    }
+
+    pub fn hole_first(&self) -> i32 {
+        self.holes[0]
+    }
+
+    // None once the last hole of the day is done
+    pub fn hole_after(&self, hole: i32) -> Option<i32> {
+        let position = self.holes.iter().position(|daily_hole| *daily_hole == hole)?;
+        self.holes.get(position + 1).copied()
+    }
+
+    pub fn stroke_cap_get(&self) -> Option<i32> {
+        self.stroke_cap
+    }
+
+    pub fn scored_get(&self) -> bool {
+        self.scored
+    }
+
+    pub fn scored_set(&mut self, scored: bool) {
+        self.scored = scored;
+    }
+
+    pub fn leaderboard_set(&mut self, leaderboard: Vec<(String, i32, i32)>) {
+        self.leaderboard = leaderboard;
+    }
+}
+
+pub fn daily_bonk_day_today() -> i64 {
+    OffsetDateTime::now_utc().unix_timestamp().div_euclid(86_400)
+}
+
+// Same day in, same course out, on every client
+pub fn daily_bonk_course(day: i64) -> (Vec<i32>, Option<i32>) {
+    let mut state = (day as u64) ^ DAILY_BONK_SEED_SALT;
+    let mut holes: Vec<i32> = (1..=18).collect();
+    // Partial Fisher-Yates, the order drawn is the order played
+    for index in 0..DAILY_BONK_HOLE_COUNT {
+        let pick = index + (split_mix_next(&mut state) % (holes.len() - index) as u64) as usize;
+        holes.swap(index, pick);
+    }
+    holes.truncate(DAILY_BONK_HOLE_COUNT);
+    let stroke_cap = DAILY_BONK_STROKE_CAPS[(split_mix_next(&mut state) % DAILY_BONK_STROKE_CAPS.len() as u64) as usize];
+    (holes, stroke_cap)
+}
+
+// OnEnter(StateGame::InGame): the first round of the day claims the attempt, replays are practice
+pub fn daily_bonk_handler_game_start(
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    state_map_set: Res<State<StateMapSet>>,
+    mut daily: ResMut<DailyBonk>,
+) {
+    if *state_map_set.get() != StateMapSet::DailyBonk {
+        return;
+    }
+    let scored = db_pipeline_daily_bonk_attempt_start(&db, &party.main_player_get_player_id(), daily.day_get());
+    info!("function: daily_bonk_handler_game_start: Day: [{}] Scored: [{}]", daily.day_get(), scored);
+    daily.scored_set(scored);
+}
+
+// Runs on entering the results screen, once ResultsHandler holds the finished round
+pub fn daily_bonk_handler_game_end(
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    results: Res<ResultsHandler>,
+    mut daily: ResMut<DailyBonk>,
+) {
+    if results.map_set_get() != StateMapSet::DailyBonk || !daily.scored_get() {
+        return;
+    }
+    let main_player_id = party.main_player_get_player_id();
+    let Some(standing) = results.standings_get().iter().find(|standing| standing.player_id == main_player_id) else {
+        return;
+    };
+    info!("function: daily_bonk_handler_game_end: Day: [{}] Strokes: [{}] To Par: [{}]", daily.day_get(), standing.total, standing.to_par);
+    let leaderboard = db_pipeline_daily_bonk_result_save(&db, &main_player_id, daily.day_get(), standing.total, standing.to_par);
+    daily.leaderboard_set(leaderboard);
+    daily.scored_set(false);
+}
+
+pub fn daily_bonk_handler_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    let text_style = menu_button_text_style(&asset_server);
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(24.0),
+                    top: Val::Px(24.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(12.0),
+                    padding: UiRect::all(Val::Px(12.0)),
+                    ..default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.6).into(),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            DailyBonkPanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn((TextBundle::from_section("", text_style.clone()), DailyBonkText));
+            parent
+                .spawn((menu_button_bundle(), DailyBonkButton))
+                .with_children(|button| {
+                    button.spawn(TextBundle::from_section("Play The Daily Bonk", text_style.clone()));
+                });
+        });
+}
+
+// Shown on the leaderboard menu level
+pub fn daily_bonk_handler_update_panel(
+    mut daily: ResMut<DailyBonk>,
+    game_handler: Res<GameHandler>,
+    mut panel: Query<&mut Visibility, With<DailyBonkPanel>>,
+    mut panel_text: Query<&mut Text, With<DailyBonkText>>,
+) {
+    let on_menu_leaderboard = MenuPage::Leaderboard.shown(&game_handler);
+    for mut visibility in panel.iter_mut() {
+        let shown = if on_menu_leaderboard { Visibility::Visible } else { Visibility::Hidden };
+        if *visibility != shown {
+            *visibility = shown;
+        }
+    }
+    if !on_menu_leaderboard {
+        return;
+    }
+    // Only the day changing matters here, the attempt is checked against the table when the round starts
+    if daily.bypass_change_detection().refresh() {
+        daily.set_changed();
+    }
+    if !daily.is_changed() {
+        return;
+    }
+    let holes: Vec<String> = daily.holes_get().iter().map(|hole| hole.to_string()).collect();
+    let mut lines = vec![
+        String::from("The Daily Bonk"),
+        format!("Holes: {}", holes.join(" ")),
+        match daily.stroke_cap_get() {
+            Some(stroke_cap) => format!("Stroke cap: {}", stroke_cap),
+            None => String::from("Stroke cap: None"),
+        },
+        String::from(if daily.scored_get() { "Today's attempt: Available" } else { "Today's attempt: Used, replays are practice" }),
+        String::new(),
+    ];
+    if daily.leaderboard.is_empty() {
+        lines.push(String::from("No scores yet today"));
+    }
+    for (index, (username, strokes, to_par)) in daily.leaderboard.iter().enumerate() {
+        let to_par = match to_par {
+            0 => String::from("E"),
+            to_par if *to_par > 0 => format!("+{}", to_par),
+            to_par => to_par.to_string(),
+        };
+        lines.push(format!("{:>2}. {:<16}{:>4}{:>5}", index + 1, username, strokes, to_par));
+    }
+    for mut text in panel_text.iter_mut() {
+        text.sections[0].value = lines.join("\n");
+    }
+}
+
+pub fn daily_bonk_handler_button(
+    mut run_trigger: ResMut<RunTrigger>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<DailyBonkButton>)>,
+) {
+    for interaction in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            info!("function: daily_bonk_handler_button");
+            run_trigger.set_target(CheckStateRT::GameHandlerStartDailyBonk, true);
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use bevy::prelude::*;
+
+// States
+use crate::StateGame;
+
+// Resources
+use crate::{
+    DatabaseConnection,
+    GameHandler,
+    GhostBall,
+    GhostHandler,
+    GolfBall,
+    MulliganTaken,
+    Party,
+    SceneInstanceCupGolfBall,
+};
+
+use crate::database_handler::{
+    db_pipeline_ghost_load,
+    db_pipeline_ghost_save,
+};
+
+const GHOST_SAMPLE_INTERVAL: f32 = 0.05; // Seconds between track samples, playback steps at the same rate
+const GHOST_RADIUS: f32 = 0.022; // Matches the golf ball collider
+const GHOST_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.35);
+
+impl GhostHandler {
+    pub fn new() -> Self {
+        GhostHandler {
+            level: 0,
+            best: None,
+            recording: Vec::new(),
+            record_clock: 0.0,
+            record_seconds: 0.0,
+            playback_index: 0,
+            playback_clock: 0.0,
+        }
+    }
+
+    pub fn level_get(&self) -> i32 {
+        self.level
+    }
+
+    fn level_set(&mut self, level: i32) {
+        self.level = level;
+        self.best = None;
+        self.recording.clear();
+        self.record_clock = 0.0;
+        self.record_seconds = 0.0;
+        self.playback_index = 0;
+        self.playback_clock = 0.0;
+    }
+}
+
+// Stored as "stroke,x,y,z;..." in ghost_table.track
+pub fn ghost_handler_track_encode(track: &[(i32, Vec3)]) -> String {
+    track
+        .iter()
+        .map(|(stroke, position)| format!("{},{:.3},{:.3},{:.3}", stroke, position.x, position.y, position.z))
+        .collect::<Vec<String>>()
+        .join(";")
+}
+
+// Samples that don't parse are dropped rather than failing the whole track
+pub fn ghost_handler_track_decode(track: &str) -> Vec<(i32, Vec3)> {
+    track
+        .split(';')
+        .filter_map(|sample| {
+            let mut fields = sample.split(',');
+            let stroke = fields.next()?.parse().ok()?;
+            let x = fields.next()?.parse().ok()?;
+            let y = fields.next()?.parse().ok()?;
+            let z = fields.next()?.parse().ok()?;
+            Some((stroke, Vec3::new(x, y, z)))
+        })
+        .collect()
+}
+
+// Ghosts are for solo rounds on the course holes, a new hole loads that hole's best track
+pub fn ghost_handler_hole_start(
+    mut commands: Commands,
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    game_handler: Res<GameHandler>,
+    state_game: Res<State<StateGame>>,
+    mut ghost: ResMut<GhostHandler>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    ghost_balls: Query<Entity, With<GhostBall>>,
+) {
+    let current_level = game_handler.current_level_get();
+    let solo_hole = *state_game.get() == StateGame::InGame && party.party_size() == 1 && (1..=18).contains(&current_level);
+    let level = if solo_hole { current_level } else { 0 };
+    if level == ghost.level_get() {
+        return;
+    }
+    for entity in ghost_balls.iter() {
+        commands.entity(entity).despawn();
+    }
+    ghost.level_set(level);
+    if level == 0 {
+        return;
+    }
+
+    ghost.best = db_pipeline_ghost_load(&db, &party.main_player_get_player_id(), level)
+        .map(|(strokes, seconds, track)| (strokes, seconds, ghost_handler_track_decode(&track)))
+        .filter(|(_, _, track)| !track.is_empty());
+    let Some((strokes, _, track)) = &ghost.best else {
+        return;
+    };
+    info!("function: ghost_handler_hole_start: Hole: [{}] Strokes: [{}] Samples: [{}]", level, strokes, track.len());
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Sphere::new(GHOST_RADIUS)),
+            material: materials.add(StandardMaterial {
+                base_color: GHOST_COLOR,
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            }),
+            transform: Transform::from_translation(track[0].1),
+            ..default()
+        },
+        GhostBall,
+        Name::new("ghost_ball"),
+    ));
+}
+
+pub fn ghost_handler_record(
+    time: Res<Time>,
+    party: Res<Party>,
+    mut ghost: ResMut<GhostHandler>,
+    golf_balls: Query<(&GolfBall, &Transform)>,
+) {
+    if ghost.level_get() == 0 || party.active_player_get_hole_completion_state() {
+        return;
+    }
+    let main_player_id = party.main_player_get_player_id();
+    let Some((golf_ball, transform)) = golf_balls.iter().find(|(golf_ball, _)| golf_ball.0.uuid == main_player_id) else {
+        return;
+    };
+    if transform.translation == Vec3::ZERO { // Not on the tee yet
+        return;
+    }
+    let strokes = party.player_get_bonks_level(main_player_id, ghost.level_get() as usize);
+    ghost.record_clock += time.delta_seconds();
+    ghost.record_seconds += time.delta_seconds();
+
+    // A resting ball only needs the one sample where it stopped
+    let moved = ghost.recording.last().map(|(_, position)| *position != transform.translation).unwrap_or(true);
+    if moved && (ghost.recording.is_empty() || ghost.record_clock >= GHOST_SAMPLE_INTERVAL) {
+        ghost.record_clock = 0.0;
+        ghost.recording.push((strokes, transform.translation));
+    }
+}
+
+// Fewer strokes makes a new best, a tie goes to whichever attempt was quicker
+pub fn ghost_handler_hole_listener(
+    mut cup_event_reader: EventReader<SceneInstanceCupGolfBall>,
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    mut ghost: ResMut<GhostHandler>,
+) {
+    for event in cup_event_reader.read() {
+        if event.id != party.main_player_get_player_id() || event.level != ghost.level_get() {
+            continue;
+        }
+        let strokes = party.player_get_bonks_level(event.id, event.level as usize);
+        let seconds = ghost.record_seconds;
+        let track = std::mem::take(&mut ghost.recording);
+        let better = match &ghost.best {
+            Some((best_strokes, best_seconds, _)) => strokes < *best_strokes || strokes == *best_strokes && seconds < *best_seconds,
+            None => true,
+        };
+        info!("function: ghost_handler_hole_listener: Hole: [{}] Strokes: [{}] Seconds: [{:.2}] New Best: [{}]", event.level, strokes, seconds, better);
+        if better && !track.is_empty() {
+            db_pipeline_ghost_save(&db, &event.id, event.level, strokes, seconds, &ghost_handler_track_encode(&track));
+            ghost.best = Some((strokes, seconds, track));
+        }
+    }
+}
+
+// A mulligan puts the main player's shot back, so the recording and the ghost go back to the stroke before it
+pub fn ghost_handler_mulligan_listener(
+    mut mulligan_event_reader: EventReader<MulliganTaken>,
+    party: Res<Party>,
+    mut ghost: ResMut<GhostHandler>,
+) {
+    for event in mulligan_event_reader.read() {
+        if event.player_id != party.main_player_get_player_id() || event.level != ghost.level_get() {
+            continue;
+        }
+        info!("function: ghost_handler_mulligan_listener: Hole: [{}] Back To Stroke: [{}]", event.level, event.strokes);
+        ghost.recording.retain(|(stroke, _)| *stroke <= event.strokes);
+        ghost.record_clock = 0.0;
+        ghost.playback_index = ghost
+            .best
+            .as_ref()
+            .and_then(|(_, _, track)| track.iter().rposition(|(stroke, _)| *stroke <= event.strokes))
+            .unwrap_or(0);
+        ghost.playback_clock = 0.0;
+    }
+}
+
+// The ghost plays each of its strokes when the player takes theirs, then waits where that stroke stopped
+pub fn ghost_handler_playback(
+    time: Res<Time>,
+    party: Res<Party>,
+    mut ghost: ResMut<GhostHandler>,
+    mut ghost_balls: Query<&mut Transform, With<GhostBall>>,
+) {
+    if ghost.level_get() == 0 || party.active_player_get_hole_completion_state() {
+        return;
+    }
+    let strokes = party.player_get_bonks_level(party.main_player_get_player_id(), ghost.level_get() as usize);
+    let ghost = &mut *ghost;
+    let Some((_, _, track)) = &ghost.best else {
+        return;
+    };
+    let playable = |index: usize| track.get(index).filter(|(stroke, _)| *stroke <= strokes);
+
+    ghost.playback_clock += time.delta_seconds();
+    while ghost.playback_clock >= GHOST_SAMPLE_INTERVAL {
+        if playable(ghost.playback_index + 1).is_none() {
+            ghost.playback_clock = 0.0;
+            break;
+        }
+        ghost.playback_index += 1;
+        ghost.playback_clock -= GHOST_SAMPLE_INTERVAL;
+    }
+
+    let from = track[ghost.playback_index].1;
+    let to = playable(ghost.playback_index + 1).map_or(from, |(_, position)| *position);
+    for mut transform in ghost_balls.iter_mut() {
+        transform.translation = from.lerp(to, ghost.playback_clock / GHOST_SAMPLE_INTERVAL);
+    }
+}
@@ -64,6 +64,43 @@ impl GLBStorageID {
     }
 }
 
+// Par per hole, index 0 is hole 1. par_table has no rows yet so the course defaults live here.
+const HOLE_PARS: [i32; 18] = [2, 2, 3, 3, 2, 3, 3, 4, 3, 3, 3, 4, 3, 3, 4, 3, 4, 5];
+const TUTORIAL_PAR: i32 = 3;
+
+pub fn level_handler_par_get(level: i32) -> i32 {
+    match level {
+        1..=18 => HOLE_PARS[level as usize - 1],
+        19 => TUTORIAL_PAR,
+        _ => 0,
+    }
+}
+
+// Helper: holes that aren't played in order, e.g. the Daily Bonk
+pub fn level_handler_state_level_for_hole(hole: i32) -> StateLevel {
+    match hole {
+        1 => StateLevel::Hole1,
+        2 => StateLevel::Hole2,
+        3 => StateLevel::Hole3,
+        4 => StateLevel::Hole4,
+        5 => StateLevel::Hole5,
+        6 => StateLevel::Hole6,
+        7 => StateLevel::Hole7,
+        8 => StateLevel::Hole8,
+        9 => StateLevel::Hole9,
+        10 => StateLevel::Hole10,
+        11 => StateLevel::Hole11,
+        12 => StateLevel::Hole12,
+        13 => StateLevel::Hole13,
+        14 => StateLevel::Hole14,
+        15 => StateLevel::Hole15,
+        16 => StateLevel::Hole16,
+        17 => StateLevel::Hole17,
+        18 => StateLevel::Hole18,
+        _ => StateLevel::HoleTutorial,
+    }
+}
+
 pub fn level_handler_boot_protocals(
     mut game_handler: ResMut<GameHandler>,
     mut run_trigger: ResMut<RunTrigger>,
@@ -314,6 +351,10 @@ pub fn level_handler_set_state_next_map_set(
                 next_state_map_set.set(StateMapSet::SelectAHole);
             },
             StateMapSet::SelectAHole => {
+                info!("StateMapSet::DailyBonk");
+                next_state_map_set.set(StateMapSet::DailyBonk);
+            },
+            StateMapSet::DailyBonk => {
                 info!("StateMapSet::ToBeSelected");
                 next_state_map_set.set(StateMapSet::ToBeSelected);
             },
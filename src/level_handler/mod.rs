@@ -1,2 +1,5 @@
+pub mod daily_bonk_handler;
+pub mod ghost_handler;
 pub mod level_handler;
-pub mod physics_handler;
\ No newline at end of file
+pub mod physics_handler;
+pub mod time_trial_handler;
\ No newline at end of file
@@ -20,10 +20,21 @@ use crate::{
     GLBStorageID,
     GolfBall,
     GolfBallPosition,
+    GolfBallStyle,
+    Hazard,
+    HazardHandler,
+    HazardPenaltyButton,
+    HazardPenaltyText,
+    HazardType,
     Interactable,
+    MenuColumn,
+    MenuPage,
     Party,
     PhysicsHandler,
+    Putter,
     RunTrigger,
+    SceneInstanceCupGolfBall,
+    SceneInstanceHazardGolfBall,
     SceneInstanceOutOfBoundGolfBall,
     SceneInstanceRespawnedGolfBall,
     SceneInstancePurgedGolfBalls,
@@ -32,6 +43,21 @@ use crate::{
 };
 
 use crate::level_handler::level_handler::level_handler_purge_golf_ball_all;
+use crate::user_interface::menu_button_handler::{
+    menu_button_label_set,
+    menu_button_row,
+    menu_button_text_style,
+};
+use crate::user_interface::menu_handler::update_gltf_material_color;
+use crate::user_interface::user_interface::apply_rotation_matrix_camera_yaw;
+
+pub const PHYSICS_TIMESTEP: f32 = 1.0 / 60.0;
+const HAZARD_PENALTY_MAX: i32 = 2; // Strokes, the menu cycles each hazard type up to this
+
+const GOLF_BALL_RADIUS: f32 = 0.022;
+const AIM_ASSIST_MAX_STEPS: usize = 600;     // 10 seconds of simulated roll
+const AIM_ASSIST_REST_SPEED: f32 = 0.01;
+const AIM_ASSIST_ROLL_DAMPING: f32 = 3.0 * 2.0 / 7.0; // Angular damping carried through a rolling sphere
 
 impl BonkHandler {
     pub fn new() -> Self {
@@ -41,6 +67,8 @@ impl BonkHandler {
         let cursor_origin_position_updated: bool = false;
         let cursor_bonk_position: XYMatrix = XYMatrix::new();
         let cursor_bonk_position_updated: bool = false;
+        let aim_yaw: f32 = 0.0;
+        let power_rising: bool = true;
         BonkHandler {
             direction,
             power,
@@ -48,9 +76,17 @@ impl BonkHandler {
             cursor_origin_position_updated,
             cursor_bonk_position,
             cursor_bonk_position_updated,
+            aim_yaw,
+            power_rising,
         }
     }
 
+    // Non mouse schemes aim with aim_yaw, 0.0 being straight away from the camera
+    pub fn update_direction_aim_yaw(&mut self, camera_yaw: &f32) {
+        let adjusted_xy = apply_rotation_matrix_camera_yaw(camera_yaw, self.aim_yaw.sin(), -self.aim_yaw.cos());
+        self.direction = Vec3::new(adjusted_xy.x, 0.0, adjusted_xy.y).normalize_or_zero() * (2.5 * self.power);
+    }
+
     pub fn set_cursor_updated(&mut self) {
         self.cursor_origin_position_updated = false;
         self.cursor_bonk_position_updated = false;
@@ -72,6 +108,10 @@ impl BonkHandler {
         self.cursor_origin_position_updated = true;
     }
 
+    pub fn impulse_get(&self, putter: &Putter) -> Vec3 {
+        self.direction * putter.impulse_scale(self.power)
+    }
+
     pub fn update_direction(&mut self, direction: &Vec3) {
         self.direction = *direction;
     }
@@ -81,9 +121,76 @@ impl BonkHandler {
     }
 }
 
+impl Hazard {
+    // Level files name hazards "hazard_<type>_<id>" and link them to a "drop_zone_<id>" marker
+    pub fn from_name(name: &str) -> Option<Self> {
+        let mut parts = name.strip_prefix("hazard_")?.splitn(2, '_');
+        let hazard_type = match parts.next()? {
+            "lava" => HazardType::Lava,
+            "pit" => HazardType::Pit,
+            "water" => HazardType::Water,
+            _ => return None,
+        };
+        let id = parts.next()?;
+        Some(Hazard {
+            hazard_type,
+            drop_zone: format!("drop_zone_{}", id),
+        })
+    }
+}
+
+impl HazardHandler {
+    pub fn new() -> Self {
+        HazardHandler {
+            penalty_lava: 1,
+            penalty_pit: 1,
+            penalty_water: 1,
+        }
+    }
+
+    pub fn penalty_get(&self, hazard_type: &HazardType) -> i32 {
+        match hazard_type {
+            HazardType::Lava => self.penalty_lava,
+            HazardType::Pit => self.penalty_pit,
+            HazardType::Water => self.penalty_water,
+        }
+    }
+
+    pub fn penalty_set(&mut self, hazard_type: &HazardType, penalty: i32) {
+        match hazard_type {
+            HazardType::Lava => self.penalty_lava = penalty,
+            HazardType::Pit => self.penalty_pit = penalty,
+            HazardType::Water => self.penalty_water = penalty,
+        }
+    }
+
+    // Steps 0 through HAZARD_PENALTY_MAX, then back to a free drop
+    pub fn penalty_cycle(&mut self, hazard_type: &HazardType) -> i32 {
+        let penalty = (self.penalty_get(hazard_type) + 1) % (HAZARD_PENALTY_MAX + 1);
+        self.penalty_set(hazard_type, penalty);
+        penalty
+    }
+}
+
 impl PhysicsHandler {
     pub fn new() -> Self {
-        PhysicsHandler
+        PhysicsHandler {
+            impulse_queue: Vec::new(),
+            putts: Vec::new(),
+        }
+    }
+
+    // Impulses are held until the next fixed step so a bonk never lands mid frame
+    pub fn impulse_queue_push(&mut self, entity: Entity, impulse: Vec3, power: f32) {
+        self.impulse_queue.push((entity, impulse, power));
+    }
+
+    pub fn impulse_queue_drain(&mut self) -> Vec<(Entity, Vec3, f32)> {
+        std::mem::take(&mut self.impulse_queue)
+    }
+
+    pub fn putts_drain(&mut self) -> Vec<f32> {
+        std::mem::take(&mut self.putts)
     }
 }
 
@@ -112,23 +219,14 @@ pub fn add_physics_query_and_update_scene(
                 .insert(Sensor)
                 .insert(Name::new("ground_sensor"));
 
+            // Walk the party rather than the query so placement and insertion order are stable
             let players = party.all_players_get_ids();
-            for (idx, (entity, golf_ball)) in gb_query.iter_mut().enumerate() {
-                for player in &players {
+            for (idx, player) in players.iter().enumerate() {
+                for (entity, golf_ball) in gb_query.iter_mut() {
                     if player == &golf_ball.0.uuid {
-                        let collider = Collider::ball(0.022);
                         commands
                             .entity(entity)
-                            .insert(collider)
-                            .insert(RigidBody::Dynamic)
-                            .insert(Damping {
-                                angular_damping: 3.0,
-                                ..default()
-                            })
-                            .insert(ExternalImpulse::default())
-                            .insert(ColliderMassProperties::Density(1.0))
-                            .insert(GravityScale(1.0))
-                            .insert(Ccd::enabled())
+                            .insert(golf_ball_physics_bundle())
                             .insert(TransformBundle::from(Transform::from_xyz(0.05 * (idx as f32), 0.0, 0.0)))
                             .insert(Name::new(format!("golf_ball_{}", player.to_string())));
                         info!("Built Golf Ball: [{}]", format!("golf_ball_{}", player.to_string()));
@@ -189,6 +287,21 @@ pub fn add_physics_query_and_update_scene(
                         .insert(collider);
                     info!("Built Cannon...");
                 }
+                if let Some(hazard) = Hazard::from_name(name.as_str()) {
+                    let mesh = meshes.get(&mesh_handle.clone()).unwrap();
+                    // Convex hull so the sensor has a volume for the ball to enter
+                    let Some(collider) = Collider::from_bevy_mesh(mesh, &ComputedColliderShape::ConvexHull) else {
+                        warn!("Hazard Sensor: [{}] has no convex hull, the mesh is degenerate. Skipped.", name.as_str());
+                        continue;
+                    };
+                    info!("Built Hazard Sensor: [{:?}]", hazard);
+                    commands
+                        .entity(entity)
+                        .insert(collider)
+                        .insert(ActiveEvents::COLLISION_EVENTS)
+                        .insert(Sensor)
+                        .insert(hazard);
+                }
             }
         }
         run_trigger.set_target(CheckStateRT::AddPhysicsQueryAndUpdateScene, false);
@@ -199,19 +312,20 @@ pub fn add_physics_query_and_update_scene(
 pub fn bonk(
     mut run_trigger: ResMut<RunTrigger>,
     entity: Entity,
-    mut commands: Commands,
+    physics_handler: &mut ResMut<PhysicsHandler>,
     bonk: Res<BonkHandler>,
+    party: &Res<Party>,
     playstyle: Res<State<StateGamePlayStyle>>,
     game_handler: &mut ResMut<GameHandler>,
 ) {
-    let scaled_bonk = bonk.power * 0.00025;
-    info!("bonk: [{}]", scaled_bonk);
-    commands.entity(entity)
-        .insert(ExternalImpulse {
-            impulse: bonk.direction * scaled_bonk,
-            torque_impulse: Vec3::new(0.0, 0.0, 0.0),
-        }
-    );   
+    // The active player's putter shapes the power and may pull the shot off line
+    let putter = party.active_player_get_putter();
+    let level = game_handler.current_level_get();
+    let stroke = party.active_player_get_bonks_level(level as usize);
+    let wobble = putter.wobble_yaw(bonk.power, party.active_player_get_player_id(), level, stroke);
+    let impulse = Quat::from_rotation_y(wobble) * bonk.impulse_get(&putter);
+    info!("bonk: [{}]: [{:?}]", putter.name, impulse);
+    physics_handler.impulse_queue_push(entity, impulse, bonk.power);
     run_trigger.set_target(CheckStateRT::PartyHandlerActivePlayerAddBonk, true); 
     match playstyle.get() {
         StateGamePlayStyle::SetOrder => {
@@ -222,6 +336,62 @@ pub fn bonk(
     }
 }
 
+// Aim assist: rolls the shot forward with shape casts against the live rapier world.
+// Returns the predicted path, stopping at rest or once max_bounces walls have been hit.
+pub fn bonk_predict_path(
+    rapier_context: &RapierContext,
+    golf_ball: Entity,
+    start: Vec3,
+    impulse: Vec3,
+    max_bounces: Option<usize>,
+) -> Vec<Vec3> {
+    let shape = Collider::ball(GOLF_BALL_RADIUS);
+    let mass = 4.0 / 3.0 * std::f32::consts::PI * GOLF_BALL_RADIUS.powi(3); // Density 1.0
+    let filter = QueryFilter::default()
+        .exclude_rigid_body(golf_ball)
+        .exclude_sensors();
+    let options = ShapeCastOptions {
+        max_time_of_impact: PHYSICS_TIMESTEP,
+        target_distance: 0.0,
+        stop_at_penetration: false,
+        compute_impact_geometry_on_penetration: true,
+    };
+
+    let mut path: Vec<Vec3> = vec![start];
+    let mut position = start;
+    let mut velocity = impulse / mass;
+    let mut bounces: usize = 0;
+    for _ in 0..AIM_ASSIST_MAX_STEPS {
+        velocity.y -= 9.81 * PHYSICS_TIMESTEP;
+        let Some((_entity, hit)) = rapier_context.cast_shape(position, Quat::IDENTITY, velocity, &shape, options, filter) else {
+            position += velocity * PHYSICS_TIMESTEP;
+            path.push(position);
+            continue;
+        };
+        position += velocity * hit.time_of_impact;
+        if let Some(details) = hit.details {
+            // Face the contact normal against the ball, restitution is zero so the inbound speed is dropped
+            let normal = if details.normal1.dot(velocity) > 0.0 { -details.normal1 } else { details.normal1 };
+            velocity -= normal * velocity.dot(normal);
+            if normal.y < 0.7 {
+                bounces += 1;
+                path.push(position);
+                if max_bounces.is_some_and(|max| bounces >= max) {
+                    break;
+                }
+            } else {
+                velocity /= 1.0 + PHYSICS_TIMESTEP * AIM_ASSIST_ROLL_DAMPING;
+            }
+        }
+        position += velocity * (PHYSICS_TIMESTEP - hit.time_of_impact);
+        path.push(position);
+        if velocity.length() < AIM_ASSIST_REST_SPEED {
+            break;
+        }
+    }
+    path
+}
+
 pub fn bonk_step_start( // set's bonk start xy
     windows: Query<&Window>,
     mut bonk: ResMut<BonkHandler>,
@@ -250,7 +420,7 @@ pub fn bonk_step_mid( // Determines bonks power by measuring the difference betw
     party: Res<Party>,
 ) {
     for golf_ball in golf_balls.iter_mut() {
-        if golf_ball.0.uuid == party.active_player_get_player_id() {
+        if golf_ball.0.uuid == party.active_ball_get_id() {
             let mut cursor_xy: XYMatrix = XYMatrix::new();
             let Some(position) = windows.single().cursor_position() else {
                 return;
@@ -293,39 +463,53 @@ pub fn bonk_step_mid( // Determines bonks power by measuring the difference betw
 }
 
 pub fn bonk_step_end( // Fires bonk 
-    buttons: Res<ButtonInput<MouseButton>>,
     mut game_handler: ResMut<GameHandler>,
     arrow_state: ResMut<State<StateArrow>>,
     next_arrow_state: ResMut<NextState<StateArrow>>,
     bonk_res: Res<BonkHandler>,
     rapier_context: Res<RapierContext>,
     rigid_body_query: Query<(Entity, &Name, &RapierRigidBodyHandle)>,
-    commands: Commands,
+    mut physics_handler: ResMut<PhysicsHandler>,
+    party: Res<Party>,
+    run_trigger: ResMut<RunTrigger>,
+    playstyle: Res<State<StateGamePlayStyle>>,
+    golf_balls: Query<(Entity, &mut GolfBall, &Name)>,
+) {
+    bonk_fire(&mut game_handler, arrow_state, next_arrow_state, bonk_res, rapier_context, rigid_body_query, &mut physics_handler, party, run_trigger, playstyle, golf_balls);
+}
+
+// Helper: shared release step for every bonk input scheme
+pub fn bonk_fire(
+    game_handler: &mut ResMut<GameHandler>,
+    arrow_state: ResMut<State<StateArrow>>,
+    next_arrow_state: ResMut<NextState<StateArrow>>,
+    bonk_res: Res<BonkHandler>,
+    rapier_context: Res<RapierContext>,
+    rigid_body_query: Query<(Entity, &Name, &RapierRigidBodyHandle)>,
+    physics_handler: &mut ResMut<PhysicsHandler>,
     party: Res<Party>,
     run_trigger: ResMut<RunTrigger>,
     playstyle: Res<State<StateGamePlayStyle>>,
     golf_balls: Query<(Entity, &mut GolfBall, &Name)>,
 ) {
     if game_handler.get(CheckStateGH::ArrowState) {
-        toggle_arrow_state(&mut game_handler, arrow_state, next_arrow_state);
+        toggle_arrow_state(game_handler, arrow_state, next_arrow_state);
     }
 
     let mut target_entity: Option<Entity> = None;
-    let player = party.active_player_get_player_id();
+    let player = party.active_ball_get_id();
     for (entity, golf_ball, _) in golf_balls.iter() {
-        if buttons.just_released(MouseButton::Right) {
-            if player == golf_ball.0.uuid{
-                let owned_bonk_power = bonk_res.power.clone();
-                if owned_bonk_power != 0.0 {
-                    target_entity = Some(entity);
-                }
-            };
-        }
+        if player == golf_ball.0.uuid{
+            let owned_bonk_power = bonk_res.power.clone();
+            if owned_bonk_power != 0.0 {
+                target_entity = Some(entity);
+            }
+        };
     }
 
     if target_entity.is_some() {
-        if golf_ball_is_asleep(rapier_context, rigid_body_query, golf_balls, &mut game_handler) {
-            bonk(run_trigger, target_entity.unwrap(), commands, bonk_res.into(), playstyle, &mut game_handler);
+        if golf_ball_is_asleep(rapier_context, rigid_body_query, golf_balls, game_handler) {
+            bonk(run_trigger, target_entity.unwrap(), physics_handler, bonk_res, &party, playstyle, game_handler);
         }
     }
 }
@@ -335,12 +519,18 @@ pub fn collision_events_listener(
     mut collision_events: EventReader<CollisionEvent>,
     mut party: ResMut<Party>,
     mut respawn_event_writer: EventWriter<SceneInstanceOutOfBoundGolfBall>,
+    mut hazard_event_writer: EventWriter<SceneInstanceHazardGolfBall>,
+    mut cup_event_writer: EventWriter<SceneInstanceCupGolfBall>,
     mut run_trigger: ResMut<RunTrigger>,
     mut game_handler: ResMut<GameHandler>,
+    hazard_handler: Res<HazardHandler>,
     golf_balls: Query<(Entity, &GolfBall)>,
     scene_meshes: Query<(Entity, &Name)>,
+    hazards: Query<&Hazard>,
+    drop_zones: Query<(&Name, &GlobalTransform)>,
 ) {
     let mut out_of_bounds = false;
+    let mut hazard_hits: Vec<(Entity, Uuid, Vec3, Hazard)> = Vec::new();
     for collision_event in collision_events.read() {
         match collision_event {
             CollisionEvent::Started(entity1, entity2, _flags) => {
@@ -355,6 +545,10 @@ pub fn collision_events_listener(
                                         info!("1: Cups baby!!!!!");
                                         info!("1: Golf Ball: [{:?}]", golf_ball.0);
                                         party.player_set_hole_completion_state(golf_ball.0.uuid, true);
+                                        cup_event_writer.send(SceneInstanceCupGolfBall {
+                                            id: golf_ball.0.uuid,
+                                            level: game_handler.current_level_get(),
+                                        });
                                         commands.entity(golf_ball_ent).despawn();
                                         run_trigger.set_target(CheckStateRT::StartMovementListenerTurnHandlerSetTurnNext, false);
                                         run_trigger.set_target(CheckStateRT::TurnHandlerSetTurnNext, true);
@@ -364,7 +558,12 @@ pub fn collision_events_listener(
                                         info!("2: Golf Ball: [{:?}]", golf_ball.0);
                                         out_of_bounds = true;
                                     },
-                                    _ => {},
+                                    _ => {
+                                        if let Ok(hazard) = hazards.get(entity) {
+                                            info!("Hazard: [{:?}] Golf Ball: [{:?}]", hazard, golf_ball.0);
+                                            hazard_hits.push((golf_ball_ent, golf_ball.0.uuid, golf_ball.0.last_position, hazard.clone()));
+                                        }
+                                    },
                                 }
                             }
                         }
@@ -377,6 +576,10 @@ pub fn collision_events_listener(
                                         info!("2: Cups baby!!!!!");
                                         info!("1: Golf Ball: [{:?}]", golf_ball.0);
                                         party.player_set_hole_completion_state(golf_ball.0.uuid, true);
+                                        cup_event_writer.send(SceneInstanceCupGolfBall {
+                                            id: golf_ball.0.uuid,
+                                            level: game_handler.current_level_get(),
+                                        });
                                         commands.entity(golf_ball_ent).despawn();
                                         run_trigger.set_target(CheckStateRT::StartMovementListenerTurnHandlerSetTurnNext, false);
                                         run_trigger.set_target(CheckStateRT::TurnHandlerSetTurnNext, true);
@@ -386,7 +589,12 @@ pub fn collision_events_listener(
                                         info!("2: Golf Ball: [{:?}]", golf_ball.0);
                                         out_of_bounds = true;
                                     },
-                                    _ => {},
+                                    _ => {
+                                        if let Ok(hazard) = hazards.get(entity) {
+                                            info!("Hazard: [{:?}] Golf Ball: [{:?}]", hazard, golf_ball.0);
+                                            hazard_hits.push((golf_ball_ent, golf_ball.0.uuid, golf_ball.0.last_position, hazard.clone()));
+                                        }
+                                    },
                                 }
                             }
                         }
@@ -399,6 +607,8 @@ pub fn collision_events_listener(
             }
         }
     }
+    // Balls already despawned for a reset this frame, each is only put back once
+    let mut reset: Vec<Entity> = Vec::new();
     if out_of_bounds == true {
         game_handler.set_target(CheckStateGH::GolfBallsBonkTrigger, false);
         let mut info_vec: Vec<(Uuid, Vec3)> = Vec::new();
@@ -408,6 +618,7 @@ pub fn collision_events_listener(
         };
         for (golf_ball_ent, _) in golf_balls.iter() {
             commands.entity(golf_ball_ent).despawn();
+            reset.push(golf_ball_ent);
         };
 
         info!("out_of_bounds: respawn_event_writer[InfoVec] \n {:?}", info_vec);
@@ -416,6 +627,29 @@ pub fn collision_events_listener(
             info_vec: info_vec,
         });        
     };
+    // Hazards are handled apart from out of bounds, skipping any ball it already reset.
+    // A ball touching two hazard sensors at once only takes the first, one penalty and one drop.
+    for (golf_ball_ent, id, last_position, hazard) in hazard_hits {
+        if reset.contains(&golf_ball_ent) {
+            continue;
+        }
+        reset.push(golf_ball_ent);
+        // Fall back to the last resting spot if the level is missing the linked drop zone
+        let mut drop_zone = last_position;
+        for (name, transform) in drop_zones.iter() {
+            if name.as_str() == hazard.drop_zone {
+                drop_zone = transform.translation();
+                break;
+            }
+        }
+        commands.entity(golf_ball_ent).despawn();
+        hazard_event_writer.send(SceneInstanceHazardGolfBall {
+            id,
+            hazard_type: hazard.hazard_type.clone(),
+            drop_zone,
+            penalty: hazard_handler.penalty_get(&hazard.hazard_type),
+        });
+    }
 }
 
 // Helper function for ^^^add_physics_query_and_update_scene^^^
@@ -466,7 +700,7 @@ pub fn golf_ball_handler_update_locations_post_bonk(
     {
         if game_handler.get(CheckStateGH::GolfBallsBonkTrigger) {
             info!("golf_ball_handler_update_locations_post_bonk: ");
-            let player_id = party.active_player_get_player_id();
+            let player_id = party.active_ball_get_id();
             for (mut golf_ball, transform) in gb_query.iter_mut() {
                 info!("golf_ball before: [{:?}]", golf_ball.0);
                 if golf_ball.0.uuid == player_id {
@@ -496,6 +730,102 @@ pub fn golf_ball_handler_end_game(
     info!("post response: golf_ball_handler_end_game: {}", run_trigger.get(CheckStateRT::GolfBallHandlerEndGame));  
 }
 
+pub fn golf_ball_handler_hazard_golf_ball(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    glb_storage: Res<GLBStorageID>,
+    party: Res<Party>,
+    mut hazard_event_reader: EventReader<SceneInstanceHazardGolfBall>,
+    mut asset_event_writer: EventWriter<SceneInstanceRespawnedGolfBall>,
+    mut game_handler: ResMut<GameHandler>,
+) {
+    for event in hazard_event_reader.read() {
+        info!("Golf Ball Hazard: [{:?}]", event);
+        party.player_add_penalty(event.id, game_handler.current_level_get() as usize, event.penalty);
+        golf_ball_handler_respawn_golf_ball_uuid(&mut commands, &asset_server, &glb_storage, &event.id, &event.drop_zone, &mut asset_event_writer, &mut game_handler);
+    }
+}
+
+pub fn hazard_handler_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    columns: Query<(Entity, &MenuColumn)>,
+) {
+    let text_style = menu_button_text_style(&asset_server);
+    let buttons = [HazardType::Water, HazardType::Lava, HazardType::Pit]
+        .into_iter()
+        .map(|hazard_type| (HazardPenaltyButton(hazard_type.clone()), HazardPenaltyText(hazard_type)))
+        .collect();
+    menu_button_row(&mut commands, &columns, MenuPage::Local, &text_style, buttons);
+}
+
+// On the local menu, one button per hazard type
+pub fn hazard_handler_update_buttons(
+    game_handler: Res<GameHandler>,
+    hazard_handler: Res<HazardHandler>,
+    mut button_text: Query<(&mut Text, &HazardPenaltyText)>,
+) {
+    if !MenuPage::Local.shown(&game_handler) {
+        return;
+    }
+    for (text, hazard_text) in button_text.iter_mut() {
+        let label = format!("{:?}: +{}", hazard_text.0, hazard_handler.penalty_get(&hazard_text.0));
+        menu_button_label_set(text, &label);
+    }
+}
+
+pub fn hazard_handler_button(
+    mut hazard_handler: ResMut<HazardHandler>,
+    buttons: Query<(&Interaction, &HazardPenaltyButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            let penalty = hazard_handler.penalty_cycle(&button.0);
+            info!("function: hazard_handler_button: [{:?}]: [{}]", button.0, penalty);
+        }
+    }
+}
+
+// Tints each spawned ball with its owner's color, retried every frame until the glb's meshes exist
+pub fn golf_ball_handler_apply_style(
+    mut commands: Commands,
+    party: Res<Party>,
+    golf_balls: Query<(Entity, &GolfBall), Without<GolfBallStyle>>,
+    children_query: Query<&Children>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    material_query: Query<&Handle<StandardMaterial>>,
+) {
+    for (entity, golf_ball) in golf_balls.iter() {
+        let Some(color) = party.player_get_ball_material(golf_ball.0.uuid) else {
+            continue;
+        };
+        if update_gltf_material_color(&mut commands, vec![(entity, color)], &children_query, &mut materials, &material_query) {
+            info!("golf_ball_handler_apply_style: [{}]: [{:?}]", golf_ball.0.uuid, color);
+            commands.entity(entity).insert(GolfBallStyle {
+                color,
+                trail: Vec::new(),
+            });
+        }
+    }
+}
+
+// Shared by the initial spawn and respawns so every ball simulates identically
+pub fn golf_ball_physics_bundle() -> impl Bundle {
+    (
+        Collider::ball(0.022),
+        RigidBody::Dynamic,
+        Damping {
+            angular_damping: 3.0,
+            ..default()
+        },
+        ExternalImpulse::default(),
+        ActiveEvents::COLLISION_EVENTS, // Wall and green hits for audio_handler_impact_listener
+        ColliderMassProperties::Density(1.0),
+        GravityScale(1.0),
+        Ccd::enabled(),
+    )
+}
+
 // Helper: golf_ball_handler_spawn_golf_balls_for_party_members
 fn golf_ball_handler_init_golf_ball_uuid(
     commands: &mut Commands,
@@ -638,7 +968,7 @@ pub fn golf_ball_handler_spawn_golf_balls_for_party_members(
 ) {
     info!("function: golf_ball_handler_spawn_golf_balls_for_party_members"); 
     {
-        for player in party.all_players_get_ids().iter() {
+        for player in party.ball_owners_get().iter() {
             info!("Building Golf Ball for player: [{:?}]", &player);
             golf_ball_handler_init_golf_ball_uuid(
                 &mut commands,
@@ -718,10 +1048,10 @@ pub fn golf_ball_is_asleep(
 }
 
 pub fn performance_physics_setup(mut rapier_config: ResMut<RapierConfiguration>) {
-    // Set fixed timestep mode
+    // Set fixed timestep mode, rapier runs in FixedUpdate so dt must match Time<Fixed>
     rapier_config.timestep_mode = TimestepMode::Fixed {
-        dt: 1.0 / 60.0,       // Physics update rate
-        substeps: 12,          // Number of physics steps per frame
+        dt: PHYSICS_TIMESTEP,  // Physics update rate
+        substeps: 12,          // Number of physics steps per fixed tick
     };
 
     // Enable/disable physics systems
@@ -732,7 +1062,24 @@ pub fn performance_physics_setup(mut rapier_config: ResMut<RapierConfiguration>)
     rapier_config.gravity = Vec3::new(0.0, -9.81, 0.0); // Standard gravity
 }
 
-fn toggle_arrow_state(
+pub fn physics_handler_apply_impulses(
+    mut commands: Commands,
+    mut physics_handler: ResMut<PhysicsHandler>,
+) {
+    for (entity, impulse, power) in physics_handler.impulse_queue_drain() {
+        info!("physics_handler_apply_impulses: [{:?}]::[{:?}]", entity, impulse);
+        // Kept for audio_handler_putt_listener, so the putt is heard for the step the impulse lands on
+        physics_handler.putts.push(power);
+        if let Some(mut entity_commands) = commands.get_entity(entity) {
+            entity_commands.insert(ExternalImpulse {
+                impulse,
+                torque_impulse: Vec3::new(0.0, 0.0, 0.0),
+            });
+        }
+    }
+}
+
+pub fn toggle_arrow_state(
     game_handler: &mut ResMut<GameHandler>,
     state: ResMut<State<StateArrow>>,
     mut next_state: ResMut<NextState<StateArrow>>,
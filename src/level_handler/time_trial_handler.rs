@@ -0,0 +1,199 @@
+use bevy::prelude::*;
+
+use std::collections::HashMap;
+
+// States
+use crate::{
+    StateGame,
+    StateMapSet,
+};
+
+// Resources
+use crate::{
+    DatabaseConnection,
+    GameHandler,
+    GolfBall,
+    MenuColumn,
+    MenuPage,
+    Party,
+    SceneInstanceCupGolfBall,
+    TimeTrial,
+    TimeTrialButton,
+    TimeTrialText,
+};
+
+use crate::database_handler::{
+    db_pipeline_time_trial_load,
+    db_pipeline_time_trial_split_save,
+};
+use crate::user_interface::menu_button_handler::{
+    menu_button_label_set,
+    menu_button_row,
+    menu_button_text_style,
+};
+
+pub const TIME_TRIAL_STROKE_PENALTY: f32 = 2.0; // Seconds added to a split per stroke
+
+impl TimeTrial {
+    pub fn new() -> Self {
+        TimeTrial {
+            enabled: false,
+            running: false,
+            hole_clock: 0.0,
+            splits: Vec::new(),
+            personal_bests: HashMap::new(),
+        }
+    }
+
+    pub fn enabled_get(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn enabled_toggle(&mut self) -> bool {
+        self.enabled = !self.enabled;
+        self.enabled
+    }
+
+    pub fn running_get(&self) -> bool {
+        self.running
+    }
+
+    // What the split would be if the ball dropped right now
+    pub fn split_live(&self, strokes: i32) -> f32 {
+        self.hole_clock + strokes as f32 * TIME_TRIAL_STROKE_PENALTY
+    }
+
+    pub fn delta_get(&self, hole: i32, split: f32) -> Option<f32> {
+        self.personal_bests.get(&hole).map(|personal_best| split - personal_best)
+    }
+
+    // Lines for the results screen, one per hole then the total
+    pub fn summary_get(&self) -> Vec<String> {
+        let mut lines = vec![format!("Time Trial, {}s per stroke", TIME_TRIAL_STROKE_PENALTY)];
+        for (hole, split, delta) in self.splits.iter() {
+            let delta = match delta {
+                Some(delta) if *delta < 0.0 => format!("{}  New PB", time_trial_format_delta(*delta)),
+                Some(delta) => time_trial_format_delta(*delta),
+                None => String::from("New PB"),
+            };
+            lines.push(format!("Hole {:>2}  {:>8}  {}", hole, time_trial_format(*split), delta));
+        }
+        let total: f32 = self.splits.iter().map(|(_, split, _)| split).sum();
+        lines.push(format!("Total    {:>8}", time_trial_format(total)));
+        lines
+    }
+}
+
+// m:ss.s
+pub fn time_trial_format(seconds: f32) -> String {
+    let tenths = (seconds.max(0.0) * 10.0).round() as i32;
+    format!("{}:{:02}.{}", tenths / 600, tenths / 10 % 60, tenths % 10)
+}
+
+pub fn time_trial_format_delta(delta: f32) -> String {
+    format!("{}{:.1}", if delta < 0.0 { "-" } else { "+" }, delta.abs())
+}
+
+// OnEnter(StateGame::InGame): only a solo party on a regular map set races the clock
+pub fn time_trial_handler_game_start(
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    state_map_set: Res<State<StateMapSet>>,
+    mut time_trial: ResMut<TimeTrial>,
+) {
+    let map_set_timed = !matches!(state_map_set.get(), StateMapSet::Tutorial | StateMapSet::DailyBonk);
+    time_trial.running = time_trial.enabled && party.party_size() == 1 && map_set_timed;
+    time_trial.hole_clock = 0.0;
+    time_trial.splits.clear();
+    info!("function: time_trial_handler_game_start: Running: [{}]", time_trial.running);
+    if time_trial.running {
+        time_trial.personal_bests = db_pipeline_time_trial_load(&db, &party.main_player_get_player_id());
+    }
+}
+
+// The clock holds while the next hole loads, it starts again once the ball is down
+pub fn time_trial_handler_tick(
+    time: Res<Time>,
+    party: Res<Party>,
+    state_game: Res<State<StateGame>>,
+    mut time_trial: ResMut<TimeTrial>,
+    golf_balls: Query<&GolfBall>,
+) {
+    if !time_trial.running || *state_game.get() != StateGame::InGame {
+        return;
+    }
+    if golf_balls.is_empty() || party.active_player_get_hole_completion_state() {
+        return;
+    }
+    time_trial.hole_clock += time.delta_seconds();
+}
+
+pub fn time_trial_handler_hole_listener(
+    mut cup_event_reader: EventReader<SceneInstanceCupGolfBall>,
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    mut time_trial: ResMut<TimeTrial>,
+) {
+    for event in cup_event_reader.read() {
+        if !time_trial.running || !(1..=18).contains(&event.level) {
+            continue;
+        }
+        let strokes = party.player_get_bonks_level(event.id, event.level as usize);
+        let split = time_trial.split_live(strokes);
+        let delta = time_trial.delta_get(event.level, split);
+        info!("function: time_trial_handler_hole_listener: Hole: [{}] Split: [{:.2}] Delta: [{:?}]", event.level, split, delta);
+        if db_pipeline_time_trial_split_save(&db, &event.id, event.level, split, strokes) {
+            time_trial.personal_bests.insert(event.level, split);
+        }
+        time_trial.splits.push((event.level, split, delta));
+        time_trial.hole_clock = 0.0;
+    }
+}
+
+// Runs on entering the results screen, the splits were already handed to ResultsHandler
+pub fn time_trial_handler_game_end(
+    mut time_trial: ResMut<TimeTrial>,
+) {
+    time_trial.running = false;
+}
+
+pub fn time_trial_handler_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    columns: Query<(Entity, &MenuColumn)>,
+) {
+    let text_style = menu_button_text_style(&asset_server);
+    menu_button_row(&mut commands, &columns, MenuPage::Local, &text_style, vec![(TimeTrialButton, TimeTrialText)]);
+}
+
+// On the local menu, above the team and scoring buttons
+pub fn time_trial_handler_update_button(
+    game_handler: Res<GameHandler>,
+    party: Res<Party>,
+    time_trial: Res<TimeTrial>,
+    mut button_text: Query<&mut Text, With<TimeTrialText>>,
+) {
+    if !MenuPage::Local.shown(&game_handler) {
+        return;
+    }
+    let label = match (time_trial.enabled_get(), party.party_size()) {
+        (false, _) => "Time Trial: Off",
+        (true, 1) => "Time Trial: On",
+        (true, _) => "Time Trial: On (solo only)",
+    };
+    for text in button_text.iter_mut() {
+        menu_button_label_set(text, label);
+    }
+}
+
+pub fn time_trial_handler_button(
+    mut time_trial: ResMut<TimeTrial>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<TimeTrialButton>)>,
+) {
+    for interaction in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            let enabled = time_trial.enabled_toggle();
+            info!("function: time_trial_handler_button: [{}]", enabled);
+        }
+    }
+}
@@ -6,19 +6,85 @@ use rusqlite::Connection;
 use serde::{Serialize, Deserialize};
 use time::OffsetDateTime;
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::Mutex;
 
 // Direct Imports
+pub mod audio_handler;
 pub mod database_handler;
 pub mod game_handler;
 pub mod network_handler;
+pub mod preferences_handler;
 
 // Directory Imports
 pub mod level_handler;
 pub mod player_handler;
 pub mod user_interface;
 
+// SplitMix64, written out so seeded rolls never depend on std's hasher or a crate version.
+// The daily bonk course and the putter wobble both draw from it, so every client rolls the same.
+pub fn split_mix_next(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Achievement {
+    BeatAi,
+    Eagle,
+    HoleInOne,
+    NoOutOfBoundsRound,
+    ParStreak,
+    UnderParRound,
+}
+
+#[derive(Resource)]
+pub struct AchievementHandler {
+    unlocked: Vec<Achievement>, // Main player's profile, achievement_table
+    streaks: HashMap<Uuid, i32>, // Consecutive holes at par or better this round
+}
+
+#[derive(Component)]
+pub struct AchievementPanel;
+
+// Each category has its own volume in Preferences, on top of the master volume
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AudioCategory {
+    Effects,
+    Interface,
+    Music,
+}
+
+#[derive(Debug, Event)]
+pub struct AudioCue {
+    pub sound: AudioSound,
+    pub intensity: f32, // 0.0..=1.0, scales the category volume
+}
+
+#[derive(Resource)]
+pub struct AudioHandler {
+    sounds: HashMap<AudioSound, Handle<AudioSource>>,
+    music_track: Option<&'static str>,
+}
+
+// Looping music and ambience, swapped out when the level changes
+#[derive(Component)]
+pub struct AudioLoop(pub AudioCategory);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AudioSound {
+    Cup,
+    Impact,
+    MenuClick,
+    OutOfBounds,
+    Putt,
+    Splash,
+}
+
 #[derive(Clone, Debug, Resource)] 
 pub struct BonkHandler {
     pub direction: Vec3,
@@ -27,11 +93,18 @@ pub struct BonkHandler {
     pub cursor_origin_position_updated: bool,
     pub cursor_bonk_position: XYMatrix,
     pub cursor_bonk_position_updated: bool,
+    pub aim_yaw: f32,
+    pub power_rising: bool,
 }
 
 #[derive(Debug, Resource)]
 pub struct CameraHandler {
     current_coords: Vec3,
+    radius_min: f32,
+    collision_radius: f32,
+    follow_lag: f32,
+    follow_cup_distance: f32,
+    follow_auto_cup: bool,
 }
 
 #[derive(Asset, Component, TypePath)]
@@ -52,6 +125,7 @@ pub enum CheckStateGH {
     RoundStart,
     NetworkServerConnection,
     RemoteGame,
+    Spectator,
 }/*
 CheckStateGH::AllSleeping
 CheckStateGH::ArrowState
@@ -64,6 +138,7 @@ CheckStateGH::InGame
 CheckStateGH::RoundStart
 CheckStateGH::NetworkServerConnection
 CheckStateGH::RemoteGame
+CheckStateGH::Spectator
 */
 
 pub enum CheckStatePH {
@@ -74,12 +149,14 @@ CheckStatePH::EnvironmentPurged
 CheckStatePH::GolfBallsPurged
 */
 
+#[derive(Clone, Copy, Debug)]
 pub enum CheckStateRT {
     AddPhysicsQueryAndUpdateScene,
     CameraHandlerCycleStateCamera,
     GameHandlerGameStart,
     GameHandlerGameStateExitRoutines,
     GameHandlerGameStateStartRoutines,
+    GameHandlerStartDailyBonk,
     GameHandlerStartLocalBackNine,
     GameHandlerStartLocalFrontNine,
     GameHandlerStartLocalSelectAHole,
@@ -118,6 +195,7 @@ CheckStateRT::CameraHandlerCycleStateCamera
 CheckStateRT::GameHandlerGameStart
 CheckStateRT::GameHandlerGameStateExitRoutines
 CheckStateRT::GameHandlerGameStateStartRoutines
+CheckStateRT::GameHandlerStartDailyBonk
 CheckStateRT::GameHandlerStartLocalBackNine
 CheckStateRT::GameHandlerStartLocalFrontNine
 CheckStateRT::GameHandlerStartLocalSelectAHole
@@ -169,19 +247,78 @@ impl ClientProtocol {
     pub fn init_player_connection(&self) -> String {
         String::from("InitPlayerConnection")
     }
+
+    pub fn init_spectator_connection(&self) -> String {
+        String::from("InitSpectatorConnection")
+    }
 }
 
+// The Daily Bonk: holes and modifiers come from the UTC day alone, so every client agrees offline
+#[derive(Resource)]
+pub struct DailyBonk {
+    day: i64, // Days since the unix epoch, UTC
+    holes: Vec<i32>,
+    stroke_cap: Option<i32>,
+    scored: bool, // False once the main player's attempt for the day is used, later runs are practice
+    leaderboard: Vec<(String, i32, i32)>, // (username, strokes, to_par) for the day
+}
+
+#[derive(Component)]
+pub struct DailyBonkButton;
+
+#[derive(Component)]
+pub struct DailyBonkPanel;
+
+#[derive(Component)]
+pub struct DailyBonkText;
+
 #[derive(Resource)]
 pub struct DatabaseConnection{
     pub conn: Arc<Mutex<Connection>>,
 }
 
+// Picked on the local menu's difficulty boards, how hard the AI plays
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Medium,
+    Hard,
+}
+
 #[derive(Resource)]
 pub struct HeartbeatTimer(pub Timer);
 
+#[derive(Resource)]
+pub struct HudHandler {
+    scorecard_visible: bool,
+    developer_panels: bool, // Shows the easy_vec_ui debug dump
+    main_player_name: String,
+}
+
+#[derive(Component)]
+pub struct HudRoot;
+
+#[derive(Component)]
+pub struct HudScorecard;
+
+#[derive(Component)]
+pub struct HudStatus;
+
 #[derive(Asset, Clone, Component, Debug, TypePath)]
 pub struct Interactable; 
 
+#[derive(Resource)]
+pub struct FlyoverHandler {
+    curve: Option<CubicCurve<Vec3>>,
+    look_from: Vec3,
+    look_to: Vec3,
+    elapsed: f32,
+    duration: f32,
+    pending: bool,
+    resume_turn: bool,
+}
+
 #[derive(Resource)]
 pub struct GameHandler {
     check_all_sleeping: bool,
@@ -195,10 +332,12 @@ pub struct GameHandler {
     check_round_start: bool,
     check_network_server_connection: bool,
     check_remote_game: bool,
+    check_spectator: bool, // Joining online games to watch, picked in the online menu before connecting
     current_level: i32,
     add_physics_attempts: i32,
     game_id: Option<Uuid>,
     remotely_pushed_state: Option<StateUpdateRef>,
+    scoring_format: ScoringFormat, // Picked in the local menu, locked in when the game starts
 }
 
 #[derive(Clone, Resource)]
@@ -206,6 +345,23 @@ pub struct GameRecord{
     game_id: Uuid,
     players: Vec<Uuid>,
     scores: Vec<[i32; 18]>,
+    scoring_format: ScoringFormat,
+}
+
+// Translucent replay of the main player's best attempt at the hole, it has no collider
+#[derive(Component)]
+pub struct GhostBall;
+
+// Tracks are (stroke, position) samples taken at a fixed interval while the ball moves
+#[derive(Resource)]
+pub struct GhostHandler {
+    level: i32, // The hole the best track and recording belong to, 0 when off
+    best: Option<(i32, f32, Vec<(i32, Vec3)>)>, // (strokes, seconds, track) of the best previous attempt
+    recording: Vec<(i32, Vec3)>,
+    record_clock: f32,
+    record_seconds: f32, // Time on the hole so far, a mulligan doesn't take it back
+    playback_index: usize,
+    playback_clock: f32,
 }
 
 #[derive(Clone, Debug, Resource)]
@@ -224,9 +380,147 @@ pub struct GolfBallPosition {
     pub sleeping: bool,
 }
 
+// Inserted once the owner's color has been applied to the spawned scene, carries the trail behind it
+#[derive(Component, Debug)]
+pub struct GolfBallStyle {
+    pub color: Color,
+    pub trail: Vec<Vec3>,
+}
+
 #[derive(Component)]
 pub struct Ground;
 
+#[derive(Component)]
+pub struct HandicapButton;
+
+// Handicaps come from each profile's recent rounds in record_table, see handicap_handler_from_rounds
+#[derive(Resource)]
+pub struct HandicapHandler {
+    enabled: bool, // Picked on the local menu, allowances only apply while it's on
+    handicaps: HashMap<Uuid, f32>, // Strokes over par per 18 holes, only for players with enough rounds
+    rounds: HashMap<Uuid, usize>, // Rounds on record, counted toward the minimum
+}
+
+#[derive(Component)]
+pub struct HandicapPanel;
+
+#[derive(Component)]
+pub struct HandicapPanelText;
+
+#[derive(Component)]
+pub struct HandicapText;
+
+#[derive(Clone, Component, Debug)]
+pub struct Hazard {
+    pub hazard_type: HazardType,
+    pub drop_zone: String,
+}
+
+#[derive(Component)]
+pub struct HazardPenaltyButton(pub HazardType);
+
+#[derive(Component)]
+pub struct HazardPenaltyText(pub HazardType);
+
+#[derive(Resource)]
+pub struct HazardHandler {
+    penalty_lava: i32,
+    penalty_pit: i32,
+    penalty_water: i32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HazardType {
+    Lava,
+    Pit,
+    Water,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputAction {
+    ActivePlayerAddBonk,
+    ActivePlayerHoleCompleted,
+    AddPlayerAi,
+    AddPlayerLocal,
+    AddPlayerRemote,
+    AimLeft,
+    AimRight,
+    Bonk,
+    BonkCharge,
+    CameraOrbit,
+    CameraPan,
+    CameraZoom,
+    CycleAimAssist,
+    CycleBonkInput,
+    CycleCamera,
+    CycleMapSet,
+    CyclePutter,
+    DebugGolfBallQuery,
+    DebugLastGameRecord,
+    DebugNamesQuery,
+    DebugOptionalParent,
+    DebugPartyQuery,
+    Interact,
+    Mulligan,
+    NextPlayer,
+    NextTurn,
+    Pause,
+    PhysicsRebuild,
+    RemoveAi,
+    RemoveLastPlayer,
+    SkipFlyover,
+    StartGame,
+    SyncClientState,
+    ToggleDeveloperPanels,
+    ToggleScorecard,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputBinding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+}
+
+#[derive(Resource)]
+pub struct InputMap {
+    bindings: HashMap<InputAction, InputBinding>,
+    rebind_target: Option<InputAction>,
+    rebind_selected: InputAction, // Shown on the preferences page, stepped through with the rebind arrows
+}
+
+// --- Developer Inspector, built with `--features inspector` --- //
+#[cfg(feature = "inspector")]
+#[derive(Resource)]
+pub struct Inspector {
+    entries: std::collections::VecDeque<InspectorEntry>,
+    filter: Option<InspectorCategory>,
+    visible: bool,
+    run_triggers_last: Vec<bool>,
+    bodies_sleeping: HashMap<Entity, bool>,
+    bodies_snapshot: Vec<String>,
+}
+
+#[cfg(feature = "inspector")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InspectorCategory {
+    Event,
+    Rapier,
+    RunTrigger,
+    State,
+}
+
+#[cfg(feature = "inspector")]
+#[derive(Clone, Debug)]
+pub struct InspectorEntry {
+    pub frame: u32,
+    pub category: InspectorCategory,
+    pub text: String,
+}
+
+#[cfg(feature = "inspector")]
+#[derive(Component)]
+pub struct InspectorOverlay;
+
 #[derive(Resource)]
 pub struct LeaderBoard {
     current_scores: [i32; 18],
@@ -266,6 +560,69 @@ pub struct MapSet {
     pub file_path_level_18: Option<String>,
 }
 
+// Any button on a menu page or the results screen, hover colours come from menu_button_hover
+#[derive(Component)]
+pub struct MenuButton;
+
+// Layout container for one menu page's buttons, shown while that page's level is loaded
+#[derive(Component)]
+pub struct MenuColumn(pub MenuPage);
+
+// Settings stepped through one press at a time, in the order all() lists them
+pub trait MenuCycle: Copy + PartialEq + 'static {
+    fn all() -> &'static [Self];
+
+    fn next(&self) -> Self {
+        let all = Self::all();
+        let index = all.iter().position(|value| value == self).unwrap_or(0);
+        all[(index + 1) % all.len()]
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MenuPage {
+    Leaderboard,
+    Local,
+    Online,
+    Player,
+    Preferences,
+}
+
+#[derive(Component)]
+pub struct MulliganButton;
+
+// Mulligans: the most recent shot can be taken back, a limited number of times per player per round
+#[derive(Resource)]
+pub struct MulliganHandler {
+    allowance: i32, // Per player per round, picked on the local menu. 0 is competitive play.
+    last_shot: Option<MulliganShot>,
+    rests: HashMap<Uuid, Vec3>, // Where each ball last came to rest, the spot a mulligan returns it to
+    used: HashMap<Uuid, Vec<i32>>, // Holes each player took one on, marked on the scorecard
+}
+
+// Everything a mulligan puts back, captured as the shot's stroke is counted
+#[derive(Clone, Debug)]
+pub struct MulliganShot {
+    pub player_id: Uuid,
+    pub player_index: i32, // Party::active_player, 1 indexed
+    pub ball_id: Uuid,
+    pub level: i32,
+    pub strokes: i32, // The card before the shot, so any penalty from it comes off as well
+    pub penalties: i32, // The hazard strokes in ^^^strokes^^^
+    pub position: Vec3,
+}
+
+// Sent once a mulligan has put a shot back, for anything that followed the shot as it played
+#[derive(Debug, Event)]
+pub struct MulliganTaken {
+    pub player_id: Uuid,
+    pub level: i32,
+    pub strokes: i32, // The card the shot was put back to
+}
+
+#[derive(Component)]
+pub struct MulliganText;
+
 #[derive(Debug, Event)]
 pub struct OnlineStateChange;
 
@@ -309,12 +666,6 @@ pub struct PanOrbitSettings {
     pub orbit_sensitivity: f32,
     /// Exponent per pixel of mouse motion
     pub zoom_sensitivity: f32,
-    /// Key to hold for panning
-    pub pan_key: Option<KeyCode>,
-    /// Key to hold for orbiting
-    pub orbit_key: Option<MouseButton>,
-    /// Key to hold for zooming
-    pub zoom_key: Option<KeyCode>,
     /// What action is bound to the scroll wheel?
     pub scroll_action: Option<PanOrbitAction>,
     /// For devices with a notched scroll wheel, like desktop mice
@@ -328,10 +679,16 @@ pub struct Party {
     players: Arc<Mutex<Vec<Arc<Mutex<dyn Player + Send>>>>>,
     active_player: Arc<Mutex<i32>>,
     pub ai_vec: Option<Vec<usize>>,
+    team_mode: TeamMode, // Sides are built from party order, see Party::teams_get
+    sitting_out: Vec<Uuid>, // Missed a tournament cut, no ball and no turns
+    spectators: Vec<Uuid>, // Online peers watching the game, same treatment as sitting out
 }
 
 #[derive(Resource)]
-pub struct PhysicsHandler;
+pub struct PhysicsHandler {
+    impulse_queue: Vec<(Entity, Vec3, f32)>, // Ball, impulse and the bonk power it was fired with
+    putts: Vec<f32>, // Powers of the impulses applied since audio_handler_putt_listener last ran
+}
 
 pub trait Player {
     fn new() -> Self where Self: Sized;
@@ -340,6 +697,10 @@ pub trait Player {
     fn game_completed(&mut self);
     fn next_round_prep(&mut self);
     fn add_bonk(&mut self, level: usize);
+    fn remove_bonk(&mut self, level: usize);
+    fn add_penalty(&mut self, level: usize, penalty: i32);
+    fn remove_penalty(&mut self, level: usize, penalty: i32);
+    fn get_penalties(&self) -> [i32; 18];
     fn get_bonks(&mut self, level: usize) -> i32;
     fn get_hole_completion_state(&self) -> bool;
     fn set_hole_completion_state(&mut self, hole_completion_state: bool);
@@ -347,6 +708,11 @@ pub trait Player {
     fn set_player_id(&mut self, new_id: Uuid);
     fn get_player_type(&self) -> String;
     fn get_score(&self) -> [i32; 18];
+    fn get_ball_material(&self) -> Color;
+    fn get_golf_ball(&self) -> u32;
+    fn set_golf_ball(&mut self, golf_ball: u32);
+    fn get_putter(&self) -> u32;
+    fn set_putter(&mut self, putter: u32);
 }
 
 #[derive(Clone, Resource)]
@@ -354,8 +720,11 @@ pub struct PlayerAi {
     pub player_id: Uuid,
     pub player_type: String,
 	pub hole_completion_state: bool,
+	pub golf_ball: u32, // player_table.golf_ball, index into update_current_mesh_color
+	pub putter: u32, // player_table.putter, index into the putter_handler table
 	pub ball_material: Color, // For now custom material/shaders planned
 	pub score: [i32; 18],
+	pub penalties: [i32; 18], // Hazard strokes per hole, counted on the card but never as a turn
 }
 
 #[derive(Clone, Resource)]
@@ -363,8 +732,11 @@ pub struct PlayerLocal {
     pub player_id: Uuid,
     pub player_type: String,
 	pub hole_completion_state: bool,
+	pub golf_ball: u32, // player_table.golf_ball, index into update_current_mesh_color
+	pub putter: u32, // player_table.putter, index into the putter_handler table
 	pub ball_material: Color, // For now custom material/shaders planned
 	pub score: [i32; 18],
+	pub penalties: [i32; 18], // Hazard strokes per hole, counted on the card but never as a turn
 }
 
 #[derive(Clone, Resource)]
@@ -372,21 +744,133 @@ pub struct PlayerRemote {
     pub player_id: Uuid,
     pub player_type: String,
 	pub hole_completion_state: bool,
+	pub golf_ball: u32, // player_table.golf_ball, index into update_current_mesh_color
+	pub putter: u32, // player_table.putter, index into the putter_handler table
 	pub ball_material: Color, // For now custom material/shaders planned
 	pub score: [i32; 18],
+	pub penalties: [i32; 18], // Hazard strokes per hole, counted on the card but never as a turn
+}
+
+#[derive(Clone, Debug, Resource)]
+pub struct Preferences {
+    pub window_mode: bevy::window::WindowMode,
+    pub resolution: (u32, u32),
+    pub vsync: bool,
+    pub pan_sensitivity: f32,
+    pub orbit_sensitivity: f32,
+    pub zoom_sensitivity: f32,
+    pub volume: f32,
+    pub volume_music: f32,
+    pub volume_effects: f32,
+    pub volume_interface: f32,
+    pub aim_assist: [StateAimAssist; 3], // One per Difficulty, in Easy, Medium, Hard order
+    pub play_style: StateGamePlayStyle,
+    pub flyover: bool,
+    pub ball_trails: bool,
+    pub camera_follow_lag: f32,
+    pub camera_follow_cup_distance: f32,
+    pub difficulty: Difficulty,
+}
+
+#[derive(Component)]
+pub struct PreferencesPanel;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Putter {
+    pub name: &'static str,
+    pub max_impulse: f32,
+    pub curve: PutterCurve,
+    pub wobble: f32, // Max yaw error in radians at full power
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PutterCurve {
+    EaseIn,
+    Linear,
+}
+
+#[derive(Component)]
+pub struct PutterButton;
+
+#[derive(Component)]
+pub struct PutterText;
+
 #[derive(Clone, Resource)]
 pub struct PurgeHandler {
     pub environment_purged: bool,
     pub golf_balls_purged: bool,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RebindAction {
+    Next,
+    Previous,
+    Start,
+}
+
+#[derive(Component)]
+pub struct RebindButton(pub RebindAction);
+
+#[derive(Component)]
+pub struct RebindText(pub RebindAction);
+
 #[derive(Component)]
 pub struct ResetTimer {
     pub timer: Timer,
 }
 
+#[derive(Clone, Copy, Debug)]
+pub enum ResultsAction {
+    MainMenu,
+    NewCourse,
+    PlayAgain,
+}
+
+#[derive(Component)]
+pub struct ResultsButton(pub ResultsAction);
+
+#[derive(Resource)]
+pub struct ResultsHandler {
+    map_set: StateMapSet,
+    scoring_format: ScoringFormat,
+    holes: Vec<i32>,
+    standings: Vec<ResultsStanding>,
+    scoring_summary: Vec<String>,
+    out_of_bounds: HashMap<Uuid, i32>, // Counted live, the party only keeps strokes
+}
+
+#[derive(Component)]
+pub struct ResultsRoot;
+
+#[derive(Clone, Debug)]
+pub struct ResultsStanding {
+    pub place: usize,
+    pub player_id: Uuid,
+    pub name: String,
+    pub color: Color,
+    pub score: [i32; 18],
+    pub total: i32,
+    pub to_par: i32,
+    pub points: i32, // Holes won, skins or stableford points, unused in stroke play
+    pub hole_in_ones: Vec<i32>,
+    pub out_of_bounds: i32,
+    pub allowance: i32, // Handicap strokes off the total, 0 unless handicaps are on
+}
+
+#[derive(Debug, Event)]
+pub struct SceneInstanceCupGolfBall {
+    pub id: Uuid,
+    pub level: i32,
+}
+
+#[derive(Debug, Event)]
+pub struct SceneInstanceHazardGolfBall {
+    pub id: Uuid,
+    pub hazard_type: HazardType,
+    pub drop_zone: Vec3,
+    pub penalty: i32,
+}
+
 #[derive(Debug, Event)]
 pub struct SceneInstancePurgedEnvironment {}
 
@@ -415,6 +899,27 @@ pub struct SceneInstanceSpawnedGolfBalls {
     pub entity: Entity,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScoringFormat {
+    MatchPlay,
+    Skins,
+    Stableford,
+    #[default]
+    StrokePlay,
+}
+
+#[derive(Component)]
+pub struct ScoringFormatButton;
+
+#[derive(Component)]
+pub struct ScoringFormatText;
+
+#[derive(Component)]
+pub struct SpectatorButton;
+
+#[derive(Component)]
+pub struct SpectatorText;
+
 #[derive(Component)]
 pub struct SpawnPhysicsCheckTimer {
     pub timer: Timer,
@@ -422,6 +927,14 @@ pub struct SpawnPhysicsCheckTimer {
 
 // --- State Enums --- //
 
+#[derive(States, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum StateAimAssist {
+    #[default]
+    Off,
+    FirstBounce,
+    Full,
+}
+
 #[derive(States, Clone, PartialEq, Eq, Hash, Debug, Default)]
 pub enum StateArrow {
     #[default]
@@ -429,15 +942,32 @@ pub enum StateArrow {
     DrawingArrow,
 }
 
+#[derive(States, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum StateBonkInput {
+    #[default]
+    Mouse,
+    Gamepad,
+    Keyboard,
+    PowerMeter,
+}
+
 #[derive(States, Clone, PartialEq, Eq, Hash, Debug, Default)]
 pub enum StateCameraOrbitEntity {
     #[default]
     Menu,
     Ball,
+    Follow,
     Cup,
     FreePan,
 }
 
+#[derive(States, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum StateFlyover {
+    #[default]
+    Idle,
+    Playing,
+}
+
 #[derive(States, Clone, PartialEq, Eq, Hash, Debug, Default)]
 pub enum StateGame {
     #[default]
@@ -498,6 +1028,7 @@ pub enum StateMapSet {
     FrontNine,
     BackNine,
     SelectAHole,
+    DailyBonk,
 }
 
 #[derive(States, Clone, PartialEq, Eq, Hash, Debug, Default)]
@@ -521,6 +1052,13 @@ pub struct StatePanOrbit {
     pub yaw: f32,
 }
 
+#[derive(States, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum StateResults {
+    #[default]
+    Hidden,
+    Showing,
+}
+
 #[derive(States, Clone, PartialEq, Eq, Hash, Debug, Default)]
 pub enum StateTurn {
     #[default]
@@ -562,6 +1100,7 @@ pub struct RunTrigger{
     game_handler_game_start: bool,
     game_handler_game_state_exit_routines: bool,
     game_handler_game_state_start_routines: bool,
+    game_handler_start_daily_bonk: bool,
     game_handler_start_local_back_nine: bool,
     game_handler_start_local_front_nine: bool,
     game_handler_start_local_select_a_hole: bool,
@@ -596,6 +1135,75 @@ pub struct RunTrigger{
     start_movement_listener_turn_handler_set_turn_next: bool,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TeamMode {
+    AlternateShot, // One ball per side, teammates take turns bonking it
+    BestBall, // Everyone plays their own ball, the side counts its low score on each hole
+    #[default]
+    Individual,
+    Scramble, // Everyone plays each stroke, the side moves to its best lie
+}
+
+#[derive(Component)]
+pub struct TeamModeButton;
+
+#[derive(Component)]
+pub struct TeamModeText;
+
+// Time trial: a solo player against the clock, splits are per hole with strokes folded in as seconds
+#[derive(Resource)]
+pub struct TimeTrial {
+    enabled: bool, // Picked on the local menu, only a solo party actually races the clock
+    running: bool,
+    hole_clock: f32, // Seconds on the current hole, penalties not included
+    splits: Vec<(i32, f32, Option<f32>)>, // (hole, split, delta against the personal best it raced) for this round
+    personal_bests: HashMap<i32, f32>, // Best split per hole for the main player
+}
+
+#[derive(Component)]
+pub struct TimeTrialButton;
+
+#[derive(Component)]
+pub struct TimeTrialText;
+
+// Several games played as one event, each round is an ordinary game linked through game_table.tournament_id
+#[derive(Resource)]
+pub struct Tournament {
+    format: TournamentFormat, // Picked on the local menu, fixed once the tournament starts
+    tournament_id: Option<Uuid>, // The tournament in progress, None between tournaments
+    rounds_played: usize,
+    playing: bool, // The game in progress is the tournament's next round
+    standings: Vec<TournamentStanding>, // Cumulative, kept in place order
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TournamentAction {
+    Abandon,
+    Format,
+    Play,
+}
+
+#[derive(Component)]
+pub struct TournamentButton(pub TournamentAction);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TournamentFormat {
+    #[default]
+    FrontAndBack,
+    ThreeRounds, // Front nine, back nine, then the whole course
+}
+
+#[derive(Clone, Debug)]
+pub struct TournamentStanding {
+    pub player_id: Uuid,
+    pub name: String,
+    pub rounds: Vec<(usize, i32, i32)>, // (round, strokes, to par) for each round played
+    pub cut: bool, // Missed the cut, sits out the rounds after it
+}
+
+#[derive(Component)]
+pub struct TournamentText(pub TournamentAction);
+
 #[derive(Resource)]
 pub struct UpdateIdResource {
     pub update_id: Option<Uuid>,
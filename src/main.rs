@@ -1,9 +1,5 @@
 // --- Internal Bevy Plugins --- //
-use bevy::{input::common_conditions::{
-        input_just_pressed, 
-        input_just_released,
-        input_pressed,
-    }, prelude::*, time::common_conditions::on_timer, utils::Duration, window::{
+use bevy::{prelude::*, time::common_conditions::on_timer, utils::Duration, window::{
         PresentMode, 
         // WindowMode::BorderlessFullscreen, 
         WindowTheme,
@@ -17,38 +13,59 @@ use bevy_rapier3d::prelude::*;
 
 // --- States --- //
 use minigolf::{
+    StateAimAssist,
     StateArrow, 
+    StateBonkInput,
     // StateCameraMenuTarget,
     StateCameraOrbitEntity, 
     StateEngineConnection, 
+    StateFlyover,
     StateGame, 
     StateGamePlayStyle, 
     StateLevel, 
     StateMapSet, 
     StateMenu, 
+    StateResults,
     StateTurn,
 };
 
 // --- Resources --- //
 use minigolf::{
+    AchievementHandler,
+    AudioCue,
+    AudioHandler,
     BonkHandler,
     CameraHandler,
     CheckStateGH,
     CheckStatePH,
     CheckStateRT,
     ClientProtocol,
+    DailyBonk,
     DatabaseConnection,
+    FlyoverHandler,
     GameHandler,
+    GhostHandler,
     GLBStorageID,
+    HandicapHandler,
     GolfBall,
+    HazardHandler,
+    HudHandler,
+    InputAction,
+    InputMap,
+    Preferences,
     LeaderBoard,
     HeartbeatTimer,
+    MulliganHandler,
+    MulliganTaken,
     OnlineStateChange,
     Party,
     PhysicsHandler,
     PurgeHandler,
     ResetTimer,
+    ResultsHandler,
     RunTrigger,
+    SceneInstanceCupGolfBall,
+    SceneInstanceHazardGolfBall,
     SceneInstanceOutOfBoundGolfBall,
     SceneInstancePurgedEnvironment,
     SceneInstancePurgedGolfBalls,
@@ -57,16 +74,38 @@ use minigolf::{
     SceneInstanceSpawnedGolfBalls,
     SpawnPhysicsCheckTimer,
     StatesRef,
+    TimeTrial,
+    Tournament,
     UpdateIdResource,
 };
 
 // --- User Camera World Import --- //
 use minigolf::{
-    database_handler::db_pipeline_init_local_player,
+    audio_handler::{
+        audio_handler_impact_listener,
+        audio_handler_loop_volume,
+        audio_handler_music,
+        audio_handler_play,
+        audio_handler_putt_listener,
+        audio_handler_scene_listener,
+        audio_handler_setup,
+    },
+    database_handler::{
+        db_pipeline_achievements_load,
+        db_pipeline_daily_bonk_load,
+        db_pipeline_init_local_player,
+        db_pipeline_input_map_load,
+        db_pipeline_player_golf_ball_load,
+        db_pipeline_player_putter_load,
+        db_pipeline_player_username_load,
+        db_pipeline_preferences_load,
+        db_pipeline_tournament_load,
+    },
     game_handler::{
         game_handler_game_start,
         game_handler_game_state_exit_routines,
         game_handler_game_state_start_routines,
+        game_handler_start_daily_bonk,
         game_handler_start_local_back_nine,
         game_handler_start_local_front_nine,
         game_handler_start_local_select_a_hole,
@@ -74,6 +113,20 @@ use minigolf::{
         game_handler_start_tutorial,
     },
     level_handler::{
+        daily_bonk_handler::{
+            daily_bonk_handler_button,
+            daily_bonk_handler_game_end,
+            daily_bonk_handler_game_start,
+            daily_bonk_handler_setup,
+            daily_bonk_handler_update_panel,
+        },
+        ghost_handler::{
+            ghost_handler_hole_listener,
+            ghost_handler_hole_start,
+            ghost_handler_mulligan_listener,
+            ghost_handler_playback,
+            ghost_handler_record,
+        },
         level_handler::{
             level_handler_boot_protocals,
             level_handler_init_level_game_handler_current_level,
@@ -92,21 +145,62 @@ use minigolf::{
             collision_events_listener,
 
             golf_ball_handler_update_locations_post_bonk,
+            golf_ball_handler_apply_style,
             golf_ball_handler_end_game,
+            golf_ball_handler_hazard_golf_ball,
             golf_ball_handler_respawn_golf_ball_uuid,
             golf_ball_handler_party_store_locations,
             golf_ball_handler_reset_golf_ball_locations,
             golf_ball_handler_spawn_golf_balls_for_party_members,
+            golf_ball_physics_bundle,
             golf_balls_update_sleep_status,
 
+            hazard_handler_button,
+            hazard_handler_setup,
+            hazard_handler_update_buttons,
+
             performance_physics_setup,
+            physics_handler_apply_impulses,
+            PHYSICS_TIMESTEP,
+        },
+        time_trial_handler::{
+            time_trial_handler_button,
+            time_trial_handler_game_end,
+            time_trial_handler_game_start,
+            time_trial_handler_hole_listener,
+            time_trial_handler_setup,
+            time_trial_handler_tick,
+            time_trial_handler_update_button,
         },
     },
     player_handler::{
+        achievement_handler::{
+            achievement_handler_game_listener,
+            achievement_handler_game_start,
+            achievement_handler_hole_listener,
+            achievement_handler_setup,
+            achievement_handler_update_panel,
+        },
+        handicap_handler::{
+            handicap_handler_button,
+            handicap_handler_refresh,
+            handicap_handler_setup,
+            handicap_handler_update_button,
+        },
         leader_board_handler::{
+            leader_board_game_start,
             leader_board_log_game,
             leader_board_review_last_game,
         },
+        mulligan_handler::{
+            mulligan_handler_button,
+            mulligan_handler_game_start,
+            mulligan_handler_setup,
+            mulligan_handler_shot_listener,
+            mulligan_handler_track_rests,
+            mulligan_handler_undo,
+            mulligan_handler_update_button,
+        },
         party_handler::{
             party_handler_active_player_add_bonk,
             party_handler_active_player_set_hole_completion_state_true,
@@ -118,26 +212,97 @@ use minigolf::{
             party_handler_remove_last_player,
             party_handler_remove_local_player,
         },
+        putter_handler::{
+            putter_handler_button,
+            putter_handler_cycle_active_player,
+            putter_handler_setup,
+            putter_handler_update_button,
+        },
+        scoring_handler::{
+            scoring_handler_button,
+            scoring_handler_setup,
+            scoring_handler_update_button,
+        },
+        team_handler::{
+            team_handler_button,
+            team_handler_cup_listener,
+            team_handler_scramble_best_lie,
+            team_handler_setup,
+            team_handler_update_button,
+        },
+        tournament_handler::{
+            tournament_handler_button,
+            tournament_handler_game_end,
+            tournament_handler_game_start,
+            tournament_handler_setup,
+            tournament_handler_update_button,
+        },
+    },
+    preferences_handler::{
+        preferences_handler_apply,
+        preferences_handler_setup,
+        preferences_handler_update_panel,
     },
     network_handler::{
         // auth_server_handshake,
         // heartbeat_system,
         network_get_client_state_all,
         network_get_client_state_game,
+        network_spectator_button,
+        network_spectator_game_start,
+        network_spectator_leave_menu,
+        network_spectator_setup,
+        network_spectator_update_button,
+        not_spectating,
         // receive_messages,
         // remote_state_change_monitor,
         // start_socket,
     },
     user_interface::{
+        bonk_input_handler::{
+            bonk_input_cycle_state,
+            bonk_input_gamepad,
+            bonk_input_keyboard,
+            bonk_input_power_meter,
+        },
+        input_map_handler::{
+            input_action_just_pressed,
+            input_action_just_released,
+            input_action_pressed,
+            input_map_pause_toggle,
+            input_map_rebind_button,
+            input_map_rebind_listener,
+            input_map_rebind_setup,
+            input_map_rebind_update_button,
+        },
+        hud_handler::{
+            hud_handler_setup,
+            hud_handler_toggle_developer_panels,
+            hud_handler_toggle_scorecard,
+            hud_handler_update,
+        },
+        flyover_handler::{
+            flyover_handler_level_listener,
+            flyover_handler_play,
+            flyover_handler_start,
+        },
         camera_handler::{
+            camera_handler_collision_avoidance,
             camera_handler_cycle_state_camera,
+            camera_handler_follow_turn_listener,
             // camera_handler_cycle_state_camera_menu_target,
             setup_3d_camera,
             pan_orbit_camera, 
             state_camera_orbit_entity_logic,
         },
+        menu_button_handler::{
+            menu_button_hover,
+            menu_button_setup,
+            menu_button_update_visibility,
+        },
         menu_handler::{
-            // local_party_interface_ai_material_toggle,
+            local_party_interface_difficulty_highlight,
+            local_party_interface_golf_ball_material,
             local_party_interface_visibliity_toggle,
         },
         turn_handler::{
@@ -150,9 +315,19 @@ use minigolf::{
             ray_fire,
             ray_release,
         },
+        results_handler::{
+            results_handler_buttons,
+            results_handler_game_start,
+            results_handler_out_of_bounds_listener,
+            results_handler_setup,
+            results_handler_teardown,
+        },
         user_interface::{
+            aim_assist_cycle_state,
+            aim_assist_gizmo,
             bonk_gizmo,
             easy_vec_ui,
+            golf_ball_trail_gizmo,
             updated_states_ref,
         },
     },
@@ -185,7 +360,7 @@ fn main() {
         ))
 
         // --- Additional Plugins --- //
-        .add_plugins(RapierPhysicsPlugin::<NoUserData>::default())
+        .add_plugins(RapierPhysicsPlugin::<NoUserData>::default().in_fixed_schedule())
         // .add_plugins(RapierDebugRenderPlugin::default())
         .add_plugins(BevyEasyVecUiPlugin::init("fonts/MatrixtypeDisplay-KVELZ.ttf")
             .camera_layer(-1)
@@ -196,36 +371,59 @@ fn main() {
         )
     
         // --- State Initialization --- //
+        .insert_state(StateAimAssist::Off)
         .insert_state(StateArrow::Idle)
+        .insert_state(StateBonkInput::Mouse)
         // .insert_state(StateCameraMenuTarget::Main)
         .insert_state(StateCameraOrbitEntity::Menu)
         .insert_state(StateEngineConnection::Local)
+        .insert_state(StateFlyover::Idle)
         .insert_state(StateGame::NotInGame)
         .insert_state(StateGamePlayStyle::SetOrder)
         .insert_state(StateLevel::MainMenu)
         .insert_state(StateMapSet::ToBeSelected)
         .insert_state(StateMenu::MenuMainMenu)
+        .insert_state(StateResults::Hidden)
         .insert_state(StateTurn::NotInGame)
 
         // --- Timer Initialization --- //
         .insert_resource(HeartbeatTimer(Timer::new(Duration::from_secs(5), TimerMode::Repeating)))
 
         // --- Resource Initialization --- //
+        .insert_resource(AchievementHandler::new())
+        .insert_resource(AudioHandler::new())
         .insert_resource(BonkHandler::new())
+        .insert_resource(DailyBonk::new())
         .insert_resource(DatabaseConnection::new("game_data.db"))
         .insert_resource(CameraHandler::new())
         .insert_resource(ClientProtocol::new())
+        .insert_resource(FlyoverHandler::new())
         .insert_resource(GameHandler::new())
+        .insert_resource(GhostHandler::new())
         .insert_resource(GLBStorageID::new())
+        .insert_resource(HandicapHandler::new())
+        .insert_resource(HazardHandler::new())
+        .insert_resource(HudHandler::new())
+        .insert_resource(InputMap::new())
+        .insert_resource(Preferences::new())
         .insert_resource(LeaderBoard::new()) 
+        .insert_resource(MulliganHandler::new())
         .insert_resource(Party::new())
         .insert_resource(PhysicsHandler::new())
+        .insert_resource(Time::<Fixed>::from_seconds(PHYSICS_TIMESTEP as f64))
         .insert_resource(PurgeHandler::new())
+        .insert_resource(ResultsHandler::new())
         .insert_resource(RunTrigger::new())
         .insert_resource(StatesRef::new())
+        .insert_resource(TimeTrial::new())
+        .insert_resource(Tournament::new())
         .insert_resource(UpdateIdResource { update_id: None })
 
         // --- Event Initialization --- //
+        .add_event::<AudioCue>()
+        .add_event::<MulliganTaken>()
+        .add_event::<SceneInstanceCupGolfBall>()
+        .add_event::<SceneInstanceHazardGolfBall>()
         .add_event::<SceneInstanceOutOfBoundGolfBall>()
         .add_event::<SceneInstancePurgedEnvironment>()
         .add_event::<SceneInstancePurgedGolfBalls>()
@@ -237,8 +435,24 @@ fn main() {
         // --- Startup Systems Initialization --- //
         .add_systems(Startup, level_handler_boot_protocals)
         .add_systems(Startup, setup_3d_camera)
+        .add_systems(Startup, hud_handler_setup)
+        .add_systems(Startup, achievement_handler_setup)
+        .add_systems(Startup, daily_bonk_handler_setup)
+        .add_systems(Startup, preferences_handler_setup)
+        .add_systems(Startup, (menu_button_setup, scoring_handler_setup, team_handler_setup, time_trial_handler_setup, mulligan_handler_setup, tournament_handler_setup, handicap_handler_setup, network_spectator_setup, input_map_rebind_setup, putter_handler_setup, hazard_handler_setup).chain())
+        .add_systems(Startup, audio_handler_setup)
         .add_systems(Startup, db_pipeline_init_local_player)
+        .add_systems(Startup, db_pipeline_input_map_load.after(db_pipeline_init_local_player))
+        .add_systems(Startup, db_pipeline_player_golf_ball_load.after(db_pipeline_init_local_player))
+        .add_systems(Startup, db_pipeline_player_putter_load.after(db_pipeline_init_local_player))
+        .add_systems(Startup, db_pipeline_achievements_load.after(db_pipeline_init_local_player))
+        .add_systems(Startup, db_pipeline_daily_bonk_load.after(db_pipeline_init_local_player))
+        .add_systems(Startup, db_pipeline_tournament_load)
+        .add_systems(Startup, handicap_handler_refresh.after(db_pipeline_init_local_player))
+        .add_systems(Startup, db_pipeline_player_username_load.after(db_pipeline_init_local_player))
+        .add_systems(Startup, db_pipeline_preferences_load.after(db_pipeline_init_local_player))
         .add_systems(Startup, performance_physics_setup)
+        .add_systems(FixedUpdate, physics_handler_apply_impulses.before(PhysicsSet::SyncBackend))
 
         // // Network //
         // .add_systems(Startup, start_socket)
@@ -250,21 +464,40 @@ fn main() {
         // .add_systems(Update, remote_state_change_monitor)
 
         // Physics //
-        .add_systems(Update, bonk_step_start.run_if(input_just_pressed(MouseButton::Right)))
-        .add_systems(Update, bonk_step_mid.run_if(input_pressed(MouseButton::Right)))
-        .add_systems(Update, bonk_step_end.run_if(input_just_released(MouseButton::Right)))
+        .add_systems(Update, bonk_step_start.run_if(in_state(StateFlyover::Idle)).run_if(not_spectating).run_if(in_state(StateBonkInput::Mouse)).run_if(input_action_just_pressed(InputAction::Bonk)))
+        .add_systems(Update, bonk_step_mid.run_if(in_state(StateFlyover::Idle)).run_if(not_spectating).run_if(in_state(StateBonkInput::Mouse)).run_if(input_action_pressed(InputAction::Bonk)))
+        .add_systems(Update, bonk_step_end.run_if(in_state(StateFlyover::Idle)).run_if(not_spectating).run_if(in_state(StateBonkInput::Mouse)).run_if(input_action_just_released(InputAction::Bonk)))
+        .add_systems(Update, bonk_input_gamepad.run_if(in_state(StateFlyover::Idle)).run_if(not_spectating).run_if(in_state(StateBonkInput::Gamepad)))
+        .add_systems(Update, bonk_input_keyboard.run_if(in_state(StateFlyover::Idle)).run_if(not_spectating).run_if(in_state(StateBonkInput::Keyboard)))
+        .add_systems(Update, bonk_input_power_meter.run_if(in_state(StateFlyover::Idle)).run_if(not_spectating).run_if(in_state(StateBonkInput::PowerMeter)))
+        .add_systems(Update, bonk_input_cycle_state.run_if(input_action_just_pressed(InputAction::CycleBonkInput)))
         .add_systems(Update, collision_events_listener)
 
         // Camera //
         .add_systems(Update, state_camera_orbit_entity_logic)
-        .add_systems(Update, pan_orbit_camera)
+        .add_systems(Update, camera_handler_follow_turn_listener)
+        .add_systems(Update, pan_orbit_camera.run_if(in_state(StateFlyover::Idle)))
+        .add_systems(Update, camera_handler_collision_avoidance.after(pan_orbit_camera).run_if(in_state(StateFlyover::Idle)))
+        .add_systems(Update, flyover_handler_level_listener)
+        .add_systems(Update, flyover_handler_start.after(flyover_handler_level_listener).run_if(|flyover: Res<FlyoverHandler>|flyover.pending_get()))
+        .add_systems(Update, flyover_handler_play.run_if(in_state(StateFlyover::Playing)))
 
         // User Interface //
         .add_systems(Update, draw_cursor)
-        .add_systems(Update, ray_fire.run_if(input_just_pressed(MouseButton::Left)))
-        .add_systems(Update, ray_release.run_if(input_just_released(MouseButton::Left)))
+        .add_systems(Update, ray_fire.run_if(input_action_just_pressed(InputAction::Interact)))
+        .add_systems(Update, ray_release.run_if(in_state(StateResults::Hidden)).run_if(input_action_just_released(InputAction::Interact)))
         .add_systems(Update, bonk_gizmo.run_if(in_state(StateArrow::DrawingArrow)))
-        .add_systems(Update, easy_vec_ui)
+        .add_systems(Update, aim_assist_gizmo.after(bonk_gizmo).run_if(in_state(StateArrow::DrawingArrow)))
+        .add_systems(Update, golf_ball_handler_apply_style)
+        .add_systems(Update, golf_ball_trail_gizmo)
+        .add_systems(Update, putter_handler_cycle_active_player.run_if(not_spectating).run_if(input_action_just_pressed(InputAction::CyclePutter)))
+        .add_systems(Update, putter_handler_update_button)
+        .add_systems(Update, putter_handler_button)
+        .add_systems(Update, aim_assist_cycle_state.run_if(input_action_just_pressed(InputAction::CycleAimAssist)))
+        .add_systems(Update, easy_vec_ui.run_if(|hud: Res<HudHandler>|hud.developer_panels_get()))
+        .add_systems(Update, hud_handler_update)
+        .add_systems(Update, hud_handler_toggle_scorecard.run_if(input_action_just_pressed(InputAction::ToggleScorecard)))
+        .add_systems(Update, hud_handler_toggle_developer_panels.run_if(input_action_just_pressed(InputAction::ToggleDeveloperPanels)))
 
         // Run Trigger Systems //
         .add_systems(Update, add_physics_query_and_update_scene.run_if(|run_trigger: Res<RunTrigger>|run_trigger.get(CheckStateRT::AddPhysicsQueryAndUpdateScene)))
@@ -275,6 +508,7 @@ fn main() {
         .add_systems(Update, game_handler_game_state_exit_routines.run_if(|run_trigger: Res<RunTrigger>|run_trigger.get(CheckStateRT::GameHandlerGameStateExitRoutines)))
         .add_systems(Update, game_handler_game_state_start_routines.run_if(|run_trigger: Res<RunTrigger>|run_trigger.get(CheckStateRT::GameHandlerGameStateStartRoutines)))
 
+        .add_systems(Update, game_handler_start_daily_bonk.run_if(|run_trigger: Res<RunTrigger>|run_trigger.get(CheckStateRT::GameHandlerStartDailyBonk)))
         .add_systems(Update, game_handler_start_local_back_nine.run_if(|run_trigger: Res<RunTrigger>|run_trigger.get(CheckStateRT::GameHandlerStartLocalBackNine)))
         .add_systems(Update, game_handler_start_local_front_nine.run_if(|run_trigger: Res<RunTrigger>|run_trigger.get(CheckStateRT::GameHandlerStartLocalFrontNine)))
         .add_systems(Update, game_handler_start_local_select_a_hole.run_if(|run_trigger: Res<RunTrigger>|run_trigger.get(CheckStateRT::GameHandlerStartLocalSelectAHole)))
@@ -312,18 +546,81 @@ fn main() {
         .add_systems(Update, party_handler_remove_last_player.run_if(|run_trigger: Res<RunTrigger>|run_trigger.get(CheckStateRT::PartyHandlerRemoveLastPlayer)))
         .add_systems(Update, party_handler_remove_local_player.run_if(|run_trigger: Res<RunTrigger>|run_trigger.get(CheckStateRT::PartyHandlerRemoveLocalPlayer)))
 
+        .add_systems(OnEnter(StateGame::InGame), achievement_handler_game_start)
+        .add_systems(OnEnter(StateGame::InGame), results_handler_game_start)
+        .add_systems(OnEnter(StateGame::InGame), daily_bonk_handler_game_start)
+        .add_systems(OnEnter(StateGame::InGame), leader_board_game_start)
+        .add_systems(OnEnter(StateGame::InGame), time_trial_handler_game_start)
+        .add_systems(OnEnter(StateGame::InGame), mulligan_handler_game_start)
+        .add_systems(OnEnter(StateGame::InGame), handicap_handler_refresh)
+        .add_systems(OnEnter(StateGame::InGame), network_spectator_game_start)
+        .add_systems(OnEnter(StateGame::InGame), tournament_handler_game_start.after(leader_board_game_start))
+        .add_systems(OnEnter(StateResults::Showing), achievement_handler_game_listener)
+        .add_systems(OnEnter(StateResults::Showing), daily_bonk_handler_game_end)
+        .add_systems(OnEnter(StateResults::Showing), time_trial_handler_game_end)
+        .add_systems(OnEnter(StateResults::Showing), tournament_handler_game_end.before(results_handler_setup))
+        .add_systems(OnEnter(StateResults::Showing), results_handler_setup)
+        .add_systems(OnExit(StateResults::Showing), results_handler_teardown)
+        .add_systems(Update, results_handler_buttons.run_if(in_state(StateResults::Showing)))
+        .add_systems(Update, results_handler_out_of_bounds_listener)
+        .add_systems(Update, achievement_handler_hole_listener)
+        .add_systems(Update, menu_button_update_visibility)
+        .add_systems(Update, menu_button_hover)
+        .add_systems(Update, achievement_handler_update_panel)
+        .add_systems(Update, daily_bonk_handler_update_panel)
+        .add_systems(Update, daily_bonk_handler_button)
+        .add_systems(Update, scoring_handler_update_button)
+        .add_systems(Update, scoring_handler_button)
+        .add_systems(Update, team_handler_update_button)
+        .add_systems(Update, team_handler_button)
+        .add_systems(Update, team_handler_cup_listener)
+        .add_systems(Update, time_trial_handler_update_button)
+        .add_systems(Update, time_trial_handler_button)
+        .add_systems(Update, time_trial_handler_tick)
+        .add_systems(Update, time_trial_handler_hole_listener)
+        .add_systems(Update, mulligan_handler_update_button)
+        .add_systems(Update, mulligan_handler_button)
+        .add_systems(Update, tournament_handler_update_button)
+        .add_systems(Update, tournament_handler_button)
+        .add_systems(Update, handicap_handler_update_button)
+        .add_systems(Update, handicap_handler_button)
+        .add_systems(Update, network_spectator_update_button)
+        .add_systems(Update, network_spectator_button)
+        .add_systems(Update, network_spectator_leave_menu)
+        .add_systems(Update, mulligan_handler_track_rests.after(golf_balls_update_sleep_status))
+        .add_systems(Update, mulligan_handler_shot_listener.before(party_handler_active_player_add_bonk).run_if(|run_trigger: Res<RunTrigger>|run_trigger.get(CheckStateRT::PartyHandlerActivePlayerAddBonk)))
+        .add_systems(Update, mulligan_handler_undo.run_if(not_spectating).run_if(input_action_just_pressed(InputAction::Mulligan)))
+        .add_systems(Update, ghost_handler_hole_start)
+        .add_systems(Update, ghost_handler_record.after(ghost_handler_hole_start))
+        .add_systems(Update, ghost_handler_playback.after(ghost_handler_hole_start))
+        .add_systems(Update, ghost_handler_hole_listener)
+        .add_systems(Update, ghost_handler_mulligan_listener.before(ghost_handler_record))
+        .add_systems(Update, audio_handler_impact_listener)
+        .add_systems(Update, audio_handler_scene_listener)
+        .add_systems(Update, audio_handler_putt_listener)
+        .add_systems(Update, audio_handler_play.after(audio_handler_impact_listener).after(audio_handler_putt_listener).after(audio_handler_scene_listener))
+        .add_systems(Update, audio_handler_music)
+        .add_systems(Update, audio_handler_loop_volume.run_if(resource_changed::<Preferences>))
+
         .add_systems(Update, turn_handler_end_game.run_if(|run_trigger: Res<RunTrigger>|run_trigger.get(CheckStateRT::TurnHandlerEndGame)))
         .add_systems(Update, turn_handler_next_round_prep.run_if(|run_trigger: Res<RunTrigger>|run_trigger.get(CheckStateRT::TurnHandlerNextRoundPrep)))
+        .add_systems(Update, team_handler_scramble_best_lie.before(turn_handler_set_turn_next))
         .add_systems(Update, turn_handler_set_turn_next.run_if(|run_trigger: Res<RunTrigger>|run_trigger.get(CheckStateRT::TurnHandlerSetTurnNext)))
 
         .add_systems(Update, start_movement_listener_turn_handler_set_turn_next.run_if(|run_trigger: Res<RunTrigger>|run_trigger.get(CheckStateRT::StartMovementListenerTurnHandlerSetTurnNext)))
 
         .add_systems(Update, temp_interface)
-        .add_systems(Update, debug_with_optional_parent.run_if(input_just_pressed(KeyCode::KeyT)))
-        .add_systems(Update, last_game_record.run_if(input_just_pressed(KeyCode::KeyY)))
-        .add_systems(Update, golf_ball_query.run_if(input_just_pressed(KeyCode::KeyU)))
-        .add_systems(Update, debug_names_query.run_if(input_just_pressed(KeyCode::KeyO)))
-        .add_systems(Update, party_query.run_if(input_just_pressed(KeyCode::KeyP)))
+        .add_systems(Update, debug_with_optional_parent.run_if(input_action_just_pressed(InputAction::DebugOptionalParent)))
+        .add_systems(Update, last_game_record.run_if(input_action_just_pressed(InputAction::DebugLastGameRecord)))
+        .add_systems(Update, golf_ball_query.run_if(input_action_just_pressed(InputAction::DebugGolfBallQuery)))
+        .add_systems(Update, debug_names_query.run_if(input_action_just_pressed(InputAction::DebugNamesQuery)))
+        .add_systems(Update, party_query.run_if(input_action_just_pressed(InputAction::DebugPartyQuery)))
+        .add_systems(Update, input_map_pause_toggle.before(input_map_rebind_listener).run_if(input_action_just_pressed(InputAction::Pause)))
+        .add_systems(Update, input_map_rebind_listener.before(input_map_rebind_button))
+        .add_systems(Update, input_map_rebind_update_button)
+        .add_systems(Update, input_map_rebind_button)
+        .add_systems(Update, preferences_handler_apply.run_if(resource_changed::<Preferences>))
+        .add_systems(Update, preferences_handler_update_panel)
         .add_systems(Update, level_init_spawn_physics_check_timer_listener)
         .add_systems(Update, listening_function_local_all_sleeping)
         .add_systems(Update, listening_function_local_add_physics
@@ -334,8 +631,12 @@ fn main() {
         .add_systems(Update, listening_function_purge_events)
         .add_systems(Update, listening_function_spawned_environment_events)
         .add_systems(Update, listening_function_spawned_golf_ball_events)
-        // .add_systems(Update, local_party_interface_ai_material_toggle)
+        .add_systems(Update, local_party_interface_difficulty_highlight)
+        .add_systems(Update, local_party_interface_golf_ball_material)
         .add_systems(Update, local_party_interface_visibliity_toggle)
+        .add_systems(Update, golf_ball_handler_hazard_golf_ball)
+        .add_systems(Update, hazard_handler_update_buttons)
+        .add_systems(Update, hazard_handler_button)
         .add_systems(Update, golf_ball_handler_respawn_golf_ball)
         .add_systems(Update, golf_ball_handler_respawn_timer_listener)
         .add_systems(Update, golf_ball_handler_update_locations_while_in_game)
@@ -343,6 +644,10 @@ fn main() {
         .add_systems(Update, |mut party: ResMut<Party>|party.update_ai_index_vec())
         .add_systems(Update, updated_states_ref);
 
+    // Developer inspector: F4 toggle, F5 filter, F6 export
+    #[cfg(feature = "inspector")]
+    app.add_plugins(minigolf::user_interface::inspector_handler::InspectorPlugin);
+
     app.run();
 }
 
@@ -528,19 +833,9 @@ fn listening_function_local_respawn_add_physics(
             if golf_ball.0.uuid == id {
                 transform.translation = point;
                 golf_ball.0.last_position = point;
-                let collider = Collider::ball(0.022);
                 commands
                     .entity(entity)
-                    .insert(collider)
-                    .insert(RigidBody::Dynamic)
-                    .insert(Damping {
-                        angular_damping: 3.0,
-                        ..default()
-                    })
-                    .insert(ExternalImpulse::default())
-                    .insert(ColliderMassProperties::Density(1.0))
-                    .insert(GravityScale(1.0))
-                    .insert(Ccd::enabled())
+                    .insert(golf_ball_physics_bundle())
                     .insert(Name::new(format!("golf_ball_{}", id.to_string())));
                     // .insert(TransformBundle::from(Transform::from_xyz(golf_ball.0.last_position.x, golf_ball.0.last_position.y, golf_ball.0.last_position.y)));
             }
@@ -632,10 +927,17 @@ fn start_movement_listener_turn_handler_set_turn_next(
 fn temp_interface(
     mut run_trigger: ResMut<RunTrigger>,
     keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    input_map: Res<InputMap>,
     state_game: Res<State<StateGame>>,
+    game_handler: Res<GameHandler>,
 ) {
-    if keys.just_released(KeyCode::KeyA) { // should trigger with new turn
-        info!("just_released: KeyA");  
+    if input_map.rebind_target_get().is_some() { // Keys belong to the rebind listener until it's done
+        return;
+    }
+    let spectating = game_handler.get(CheckStateGH::Spectator); // Watching only, turns and strokes aren't theirs to move on
+    if !spectating && input_map.just_released(InputAction::ActivePlayerHoleCompleted, &keys, &mouse) { // should trigger with new turn
+        info!("just_released: ActivePlayerHoleCompleted");  
         match state_game.get() {
             StateGame::NotInGame => {},
             StateGame::InGame => {
@@ -643,8 +945,8 @@ fn temp_interface(
             },
         };
     };
-    if keys.just_released(KeyCode::KeyB) {
-        info!("just_released: KeyB");
+    if !spectating && input_map.just_released(InputAction::ActivePlayerAddBonk, &keys, &mouse) {
+        info!("just_released: ActivePlayerAddBonk");
         match state_game.get() {
             StateGame::NotInGame => {},
             StateGame::InGame => {
@@ -652,16 +954,16 @@ fn temp_interface(
             },
         };
     };
-    if keys.just_released(KeyCode::KeyC) {
-        info!("just_released: KeyC");
+    if input_map.just_released(InputAction::CycleCamera, &keys, &mouse) {
+        info!("just_released: CycleCamera");
         run_trigger.set_target(CheckStateRT::CameraHandlerCycleStateCamera, true);
     };
-    if keys.just_released(KeyCode::KeyI) {
-        info!("just_released: KeyI");
+    if input_map.just_released(InputAction::PhysicsRebuild, &keys, &mouse) {
+        info!("just_released: PhysicsRebuild");
         run_trigger.set_target(CheckStateRT::AddPhysicsQueryAndUpdateScene, true);
     };
-    if keys.just_released(KeyCode::KeyM) {
-        info!("just_released: KeyM");  
+    if input_map.just_released(InputAction::CycleMapSet, &keys, &mouse) {
+        info!("just_released: CycleMapSet");  
         match state_game.get() {
             StateGame::InGame => {},
             StateGame::NotInGame => {
@@ -669,8 +971,8 @@ fn temp_interface(
             },
         };
     };
-    if keys.just_released(KeyCode::KeyN) {
-        info!("just_released: KeyN");  
+    if !spectating && input_map.just_released(InputAction::NextTurn, &keys, &mouse) {
+        info!("just_released: NextTurn");  
         match state_game.get() {
             StateGame::NotInGame => {},
             StateGame::InGame => {
@@ -678,8 +980,8 @@ fn temp_interface(
             },
         };
     };
-    if keys.just_released(KeyCode::KeyP) {
-        info!("just_released: KeyP");  
+    if !spectating && input_map.just_released(InputAction::NextPlayer, &keys, &mouse) {
+        info!("just_released: NextPlayer");  
         match state_game.get() {
             StateGame::NotInGame => {},
             StateGame::InGame => {
@@ -687,12 +989,12 @@ fn temp_interface(
             },
         };
     };
-    if keys.just_released(KeyCode::KeyQ) {
-        info!("just_released: KeyQ");  
+    if input_map.just_released(InputAction::SyncClientState, &keys, &mouse) {
+        info!("just_released: SyncClientState");  
         run_trigger.set_target(CheckStateRT::NetworkGetClientStateAll, true);
     };
-    if keys.just_released(KeyCode::KeyS) {
-        info!("just_released: KeyS");  
+    if input_map.just_released(InputAction::StartGame, &keys, &mouse) {
+        info!("just_released: StartGame");  
         match state_game.get() {
             StateGame::InGame => {},
             StateGame::NotInGame => {
@@ -701,8 +1003,8 @@ fn temp_interface(
             },
         };
     };
-    if keys.just_released(KeyCode::Numpad1) {
-        info!("just_released: Numpad1");  
+    if input_map.just_released(InputAction::RemoveLastPlayer, &keys, &mouse) {
+        info!("just_released: RemoveLastPlayer");  
         match state_game.get() {
             StateGame::InGame => {},
             StateGame::NotInGame => {
@@ -710,8 +1012,8 @@ fn temp_interface(
             },
         };
     };
-    if keys.just_released(KeyCode::Numpad3) {
-        info!("just_released: Numpad3");  
+    if input_map.just_released(InputAction::RemoveAi, &keys, &mouse) {
+        info!("just_released: RemoveAi");  
         match state_game.get() {
             StateGame::InGame => {},
             StateGame::NotInGame => {
@@ -719,8 +1021,8 @@ fn temp_interface(
             },
         };
     };
-    if keys.just_released(KeyCode::Numpad7) {
-        info!("just_released: Numpad7");  
+    if input_map.just_released(InputAction::AddPlayerLocal, &keys, &mouse) {
+        info!("just_released: AddPlayerLocal");  
         match state_game.get() {
             StateGame::InGame => {},
             StateGame::NotInGame => {
@@ -728,8 +1030,8 @@ fn temp_interface(
             },
         };
     };
-    if keys.just_released(KeyCode::Numpad8) {
-        info!("just_released: Numpad8");  
+    if input_map.just_released(InputAction::AddPlayerRemote, &keys, &mouse) {
+        info!("just_released: AddPlayerRemote");  
         match state_game.get() {
             StateGame::InGame => {},
             StateGame::NotInGame => {
@@ -737,8 +1039,8 @@ fn temp_interface(
             },
         };
     };
-    if keys.just_released(KeyCode::Numpad9) {
-        info!("just_released: Numpad9");   
+    if input_map.just_released(InputAction::AddPlayerAi, &keys, &mouse) {
+        info!("just_released: AddPlayerAi");   
         match state_game.get() {
             StateGame::InGame => {},
             StateGame::NotInGame => {
@@ -28,20 +28,30 @@ use crate::{
     GameHandler,
     CheckStateGH,
     HeartbeatTimer,
+    MenuColumn,
+    MenuPage,
     OnlineStateChange,
     PacketAllStates,
     PacketHeartbeat,
     Party,
     RunTrigger,
+    SpectatorButton,
+    SpectatorText,
     UpdateIdResource,
 };
 
 use crate::database_handler::db_pipeline_sync_local_player;
+use crate::user_interface::menu_button_handler::{
+    menu_button_label_set,
+    menu_button_row,
+    menu_button_text_style,
+};
 
 pub fn auth_server_handshake(
     db: Res<DatabaseConnection>,
     mut socket: ResMut<MatchboxSocket<SingleChannel>>,
     client_protocol: Res<ClientProtocol>,
+    game_handler: Res<GameHandler>,
 ) {
     let conn = db.get_connection();
     let conn = conn.lock().unwrap(); // Lock the mutex
@@ -65,9 +75,15 @@ pub fn auth_server_handshake(
 
     let player_info = format!("{}, {}, {}", player_info_tuple.0, player_info_tuple.1, player_info_tuple.2);
 
+    // The server keeps spectators out of the turn order and drops any gameplay they send
+    let init_connection = match game_handler.get(CheckStateGH::Spectator) {
+        true => client_protocol.init_spectator_connection(),
+        false => client_protocol.init_player_connection(),
+    };
+
     let message = format!{
         "({}, ({}))",
-        client_protocol.init_player_connection(),
+        init_connection,
         player_info,
     };
 
@@ -287,7 +303,10 @@ pub fn remote_state_change_monitor(
             },
             StateUpdateRef::StateCameraOrbitEntity(state_camera_orbit_entity) => {
                 info!("StateCameraOrbitEntity: {:?}", state_camera_orbit_entity);
-                next_state_camera_orbit_entity.set(state_camera_orbit_entity);
+                // Spectators pick their own view
+                if !game_handler.get(CheckStateGH::Spectator) {
+                    next_state_camera_orbit_entity.set(state_camera_orbit_entity);
+                }
             },
         }
     }
@@ -387,6 +406,35 @@ pub fn server_parse_message(
                         online_event_handler.send(OnlineStateChange);
                     }
                 },
+                "InitSpectatorConnection" => {
+                    // Handle InitSpectatorConnection command, same as a player but the main player never gets a turn
+                    if !game_handler.get(CheckStateGH::NetworkServerConnection) {
+                        let main_player_id = party.main_player_get_player_id();
+                        party.spectator_add(main_player_id);
+                        let parsed_state = Some(StateUpdateRef::StateEngineConnection(
+                            StateEngineConnection::Online,
+                        ));
+                        info!("Parsed state update: {:?}", parsed_state);
+                        game_handler.pushed_state_set(parsed_state);
+                        online_event_handler.send(OnlineStateChange);
+                    }
+                },
+                "SpectatorJoined" => {
+                    if let Some(uuid_str) = caps.get(4).map(|m| m.as_str()) {
+                        if let Ok(parsed_uuid) = Uuid::parse_str(uuid_str) {
+                            info!("spectator joined: {:?}", parsed_uuid);
+                            party.spectator_add(parsed_uuid);
+                        }
+                    }
+                },
+                "SpectatorLeft" => {
+                    if let Some(uuid_str) = caps.get(4).map(|m| m.as_str()) {
+                        if let Ok(parsed_uuid) = Uuid::parse_str(uuid_str) {
+                            info!("spectator left: {:?}", parsed_uuid);
+                            party.spectator_remove(parsed_uuid);
+                        }
+                    }
+                },
                 "RunTrigger" => {
                     if let Some(trigger) = caps.get(4).map(|m| m.as_str()) {
                         info!("run_trigger: {:?}", trigger);
@@ -395,7 +443,9 @@ pub fn server_parse_message(
                                 run_trigger.set_target(CheckStateRT::AddPhysicsQueryAndUpdateScene, true);
                             },
                             "camera_handler_cycle_state_camera" => {
-                                run_trigger.set_target(CheckStateRT::CameraHandlerCycleStateCamera, true);                                
+                                if !game_handler.get(CheckStateGH::Spectator) {
+                                    run_trigger.set_target(CheckStateRT::CameraHandlerCycleStateCamera, true);                                
+                                }
                             },
                             "game_handler_game_start" => {
                                 run_trigger.set_target(CheckStateRT::GameHandlerGameStart, true);                                
@@ -524,4 +574,85 @@ pub fn server_parse_message(
 pub fn start_socket(mut commands: Commands) {
     let socket = MatchboxSocket::new_reliable("ws://localhost:3536/minigolf");
     commands.insert_resource(socket);
+}
+
+// Helper: the role only holds for the online session it was picked for
+pub fn network_spectator_clear(game_handler: &mut GameHandler, party: &mut Party) {
+    if game_handler.get(CheckStateGH::Spectator) {
+        game_handler.set_target(CheckStateGH::Spectator, false);
+    }
+    let main_player_id = party.main_player_get_player_id();
+    party.spectator_remove(main_player_id);
+}
+
+// Run condition: input that would move the game on, kept from spectators
+pub fn not_spectating(game_handler: Res<GameHandler>) -> bool {
+    !game_handler.get(CheckStateGH::Spectator)
+}
+
+// Back out of the online menu to another menu page
+pub fn network_spectator_leave_menu(
+    mut game_handler: ResMut<GameHandler>,
+    mut party: ResMut<Party>,
+    mut on_menu_online: Local<bool>,
+) {
+    let shown = MenuPage::Online.shown(&game_handler);
+    if *on_menu_online && !shown && game_handler.current_level_is_menu() {
+        network_spectator_clear(&mut game_handler, &mut party);
+    }
+    *on_menu_online = shown;
+}
+
+// A local game never has spectators, whatever was picked on the online menu
+pub fn network_spectator_game_start(
+    mut game_handler: ResMut<GameHandler>,
+    mut party: ResMut<Party>,
+) {
+    if !game_handler.get(CheckStateGH::RemoteGame) {
+        network_spectator_clear(&mut game_handler, &mut party);
+    }
+}
+
+pub fn network_spectator_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    columns: Query<(Entity, &MenuColumn)>,
+) {
+    let text_style = menu_button_text_style(&asset_server);
+    menu_button_row(&mut commands, &columns, MenuPage::Online, &text_style, vec![(SpectatorButton, SpectatorText)]);
+}
+
+// The role goes out with the handshake, so it's locked in once the server has answered
+pub fn network_spectator_update_button(
+    game_handler: Res<GameHandler>,
+    mut button_text: Query<&mut Text, With<SpectatorText>>,
+) {
+    if !MenuPage::Online.shown(&game_handler) {
+        return;
+    }
+    let role = match game_handler.get(CheckStateGH::Spectator) {
+        true => "Spectator",
+        false => "Player",
+    };
+    let label = match game_handler.get(CheckStateGH::NetworkServerConnection) {
+        true => format!("Joined as: {}", role),
+        false => format!("Join as: {}", role),
+    };
+    for text in button_text.iter_mut() {
+        menu_button_label_set(text, &label);
+    }
+}
+
+pub fn network_spectator_button(
+    mut game_handler: ResMut<GameHandler>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<SpectatorButton>)>,
+) {
+    for interaction in buttons.iter() {
+        if *interaction != Interaction::Pressed || game_handler.get(CheckStateGH::NetworkServerConnection) {
+            continue;
+        }
+        let spectator = !game_handler.get(CheckStateGH::Spectator);
+        game_handler.set_target(CheckStateGH::Spectator, spectator);
+        info!("function: network_spectator_button: [{}]", spectator);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,238 @@
+use bevy::prelude::*;
+
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+// Resources
+use crate::{
+    Achievement,
+    AchievementHandler,
+    AchievementPanel,
+    DatabaseConnection,
+    Difficulty,
+    GameHandler,
+    MenuPage,
+    Party,
+    Preferences,
+    ResultsHandler,
+    SceneInstanceCupGolfBall,
+};
+
+use crate::database_handler::db_pipeline_achievement_save;
+use crate::level_handler::level_handler::level_handler_par_get;
+use crate::user_interface::menu_button_handler::menu_button_text_style;
+
+const ACHIEVEMENT_STREAK_LENGTH: i32 = 3;
+const ACHIEVEMENT_UNLOCKED_COLOR: Color = Color::srgb(1.0, 0.84, 0.3);
+const ACHIEVEMENT_LOCKED_COLOR: Color = Color::srgb(0.45, 0.45, 0.45);
+
+impl Achievement {
+    pub fn all() -> [Achievement; 6] {
+        [
+            Achievement::HoleInOne,
+            Achievement::Eagle,
+            Achievement::ParStreak,
+            Achievement::UnderParRound,
+            Achievement::NoOutOfBoundsRound,
+            Achievement::BeatAi,
+        ]
+    }
+
+    // Stored by variant name, e.g. "HoleInOne"
+    pub fn from_name(name: &str) -> Option<Achievement> {
+        Achievement::all().into_iter().find(|achievement| format!("{:?}", achievement) == name)
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Achievement::BeatAi => "Man Over Machine",
+            Achievement::Eagle => "Eagle Eye",
+            Achievement::HoleInOne => "Hole In One",
+            Achievement::NoOutOfBoundsRound => "Stay In Bounds",
+            Achievement::ParStreak => "Steady Hands",
+            Achievement::UnderParRound => "Under Par",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Achievement::BeatAi => "Finish a round ahead of every AI player on Hard",
+            Achievement::Eagle => "Sink a ball two or more under par",
+            Achievement::HoleInOne => "Sink a ball in one bonk",
+            Achievement::NoOutOfBoundsRound => "Finish a round without going out of bounds",
+            Achievement::ParStreak => "Make par or better on three holes in a row",
+            Achievement::UnderParRound => "Finish a round under par",
+        }
+    }
+}
+
+impl AchievementHandler {
+    pub fn new() -> Self {
+        AchievementHandler {
+            unlocked: Vec::new(),
+            streaks: HashMap::new(),
+        }
+    }
+
+    pub fn is_unlocked(&self, achievement: Achievement) -> bool {
+        self.unlocked.contains(&achievement)
+    }
+
+    // True only the first time, so callers know when to persist
+    pub fn unlock(&mut self, achievement: Achievement) -> bool {
+        if self.is_unlocked(achievement) {
+            return false;
+        }
+        self.unlocked.push(achievement);
+        true
+    }
+
+    pub fn streaks_clear(&mut self) {
+        self.streaks.clear();
+    }
+}
+
+// Helper: anyone in the party can earn one, only the main player's profile keeps it
+fn achievement_handler_award(
+    achievements: &mut AchievementHandler,
+    db: &Res<DatabaseConnection>,
+    party: &Party,
+    player_id: Uuid,
+    achievement: Achievement,
+) {
+    info!("function: achievement_handler_award: [{:?}] Player: [{:?}]", achievement, player_id);
+    if player_id == party.main_player_get_player_id() && achievements.unlock(achievement) {
+        db_pipeline_achievement_save(db, &player_id, achievement);
+    }
+}
+
+pub fn achievement_handler_game_start(
+    mut achievements: ResMut<AchievementHandler>,
+) {
+    achievements.streaks_clear();
+}
+
+pub fn achievement_handler_hole_listener(
+    mut cup_event_reader: EventReader<SceneInstanceCupGolfBall>,
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    mut achievements: ResMut<AchievementHandler>,
+) {
+    for event in cup_event_reader.read() {
+        // The tutorial hole doesn't count
+        if !(1..=18).contains(&event.level) {
+            continue;
+        }
+        let (ids, scores) = party.all_players_get_ids_and_scores();
+        let Some(player_index) = ids.iter().position(|id| *id == event.id) else {
+            continue;
+        };
+        let strokes = scores[player_index][event.level as usize - 1];
+        let par = level_handler_par_get(event.level);
+        info!("function: achievement_handler_hole_listener: Player: [{:?}] Strokes: [{}] Par: [{}]", event.id, strokes, par);
+
+        if strokes == 1 {
+            achievement_handler_award(&mut achievements, &db, &party, event.id, Achievement::HoleInOne);
+        }
+        if strokes <= par - 2 {
+            achievement_handler_award(&mut achievements, &db, &party, event.id, Achievement::Eagle);
+        }
+        let streak = achievements.streaks.entry(event.id).or_insert(0);
+        *streak = if strokes <= par { *streak + 1 } else { 0 };
+        if *streak >= ACHIEVEMENT_STREAK_LENGTH {
+            achievement_handler_award(&mut achievements, &db, &party, event.id, Achievement::ParStreak);
+        }
+    }
+}
+
+// Runs on entering the results screen, once ResultsHandler holds the finished round
+pub fn achievement_handler_game_listener(
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    preferences: Res<Preferences>,
+    results: Res<ResultsHandler>,
+    mut achievements: ResMut<AchievementHandler>,
+) {
+    info!("function: achievement_handler_game_listener");
+    // Round achievements need more than a single hole
+    if results.holes_get().len() < 2 {
+        return;
+    }
+    let ai_ids: Vec<Uuid> = party
+        .all_players_get_ids_and_types()
+        .into_iter()
+        .filter(|(_, player_type)| player_type.as_str() == "PlayerAi")
+        .map(|(player_id, _)| player_id)
+        .collect();
+    // Only a Hard AI is worth beating
+    let best_ai_total = results.standings_get()
+        .iter()
+        .filter(|standing| preferences.difficulty == Difficulty::Hard && ai_ids.contains(&standing.player_id))
+        .map(|standing| standing.total)
+        .min();
+
+    for standing in results.standings_get().iter() {
+        if standing.total == 0 || ai_ids.contains(&standing.player_id) {
+            continue;
+        }
+        if standing.to_par < 0 {
+            achievement_handler_award(&mut achievements, &db, &party, standing.player_id, Achievement::UnderParRound);
+        }
+        if standing.out_of_bounds == 0 {
+            achievement_handler_award(&mut achievements, &db, &party, standing.player_id, Achievement::NoOutOfBoundsRound);
+        }
+        if best_ai_total.is_some_and(|best_ai_total| standing.total < best_ai_total) {
+            achievement_handler_award(&mut achievements, &db, &party, standing.player_id, Achievement::BeatAi);
+        }
+    }
+}
+
+pub fn achievement_handler_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands.spawn((
+        TextBundle::from_section("", TextStyle { color: ACHIEVEMENT_LOCKED_COLOR, ..menu_button_text_style(&asset_server) })
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(24.0),
+                top: Val::Px(24.0),
+                ..default()
+            })
+            .with_background_color(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        AchievementPanel,
+    ))
+    .insert(Visibility::Hidden);
+}
+
+// Shown on the player menu level
+pub fn achievement_handler_update_panel(
+    achievements: Res<AchievementHandler>,
+    game_handler: Res<GameHandler>,
+    mut panel: Query<(&mut Text, &mut Visibility), With<AchievementPanel>>,
+) {
+    let on_menu_player = MenuPage::Player.shown(&game_handler);
+    for (mut text, mut visibility) in panel.iter_mut() {
+        let shown = if on_menu_player { Visibility::Visible } else { Visibility::Hidden };
+        if *visibility != shown {
+            *visibility = shown;
+        }
+        if !on_menu_player || !achievements.is_changed() && text.sections.len() > 1 {
+            continue;
+        }
+        let style = text.sections[0].style.clone();
+        let mut sections = vec![TextSection::new(
+            format!("Achievements {}/{}\n", achievements.unlocked.len(), Achievement::all().len()),
+            TextStyle { color: ACHIEVEMENT_UNLOCKED_COLOR, ..style.clone() },
+        )];
+        for achievement in Achievement::all() {
+            let unlocked = achievements.is_unlocked(achievement);
+            sections.push(TextSection::new(
+                format!("{} {:<18}{}\n", if unlocked { "[x]" } else { "[ ]" }, achievement.title(), achievement.description()),
+                TextStyle { color: if unlocked { ACHIEVEMENT_UNLOCKED_COLOR } else { ACHIEVEMENT_LOCKED_COLOR }, ..style.clone() },
+            ));
+        }
+        text.sections = sections;
+    }
+}
@@ -0,0 +1,214 @@
+use bevy::prelude::*;
+
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+// States
+use crate::StateMapSet;
+
+// Resources
+use crate::{
+    DatabaseConnection,
+    GameHandler,
+    HandicapButton,
+    HandicapHandler,
+    HandicapPanel,
+    HandicapPanelText,
+    HandicapText,
+    MenuColumn,
+    MenuPage,
+    Party,
+};
+
+use crate::database_handler::db_pipeline_record_handicap_rounds_load;
+use crate::user_interface::menu_button_handler::{
+    menu_button_label_set,
+    menu_button_row,
+    menu_button_text_style,
+};
+
+const HANDICAP_RECENT_ROUNDS: usize = 10;
+const HANDICAP_BEST_ROUNDS: usize = 4; // Of the recent ones, so one bad day doesn't count against you
+const HANDICAP_MIN_ROUNDS: usize = 3;
+const HANDICAP_MIN_HOLES: i32 = 9; // Shorter rounds swing too far once they're scaled up to 18 holes
+
+impl HandicapHandler {
+    pub fn new() -> Self {
+        HandicapHandler {
+            enabled: false,
+            handicaps: HashMap::new(),
+            rounds: HashMap::new(),
+        }
+    }
+
+    pub fn enabled_get(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn enabled_toggle(&mut self) -> bool {
+        self.enabled = !self.enabled;
+        self.enabled
+    }
+
+    pub fn handicap_get(&self, player_id: Uuid) -> Option<f32> {
+        self.handicaps.get(&player_id).copied()
+    }
+
+    pub fn rounds_get(&self, player_id: Uuid) -> usize {
+        self.rounds.get(&player_id).copied().unwrap_or(0)
+    }
+
+    // Recomputed from record_table, the rounds a player has on record decide if they get one yet
+    pub fn refresh(&mut self, db: &Res<DatabaseConnection>, player_id: Uuid) {
+        let rounds = db_pipeline_record_handicap_rounds_load(db, &player_id);
+        self.rounds.insert(player_id, handicap_handler_differentials(&rounds).len());
+        self.handicap_set(player_id, handicap_handler_from_rounds(&rounds));
+    }
+
+    pub fn handicap_set(&mut self, player_id: Uuid, handicap: Option<f32>) {
+        match handicap {
+            Some(handicap) => self.handicaps.insert(player_id, handicap),
+            None => self.handicaps.remove(&player_id),
+        };
+    }
+
+    // Strokes each player gets against the lowest handicap in the field, scaled to the holes played.
+    // Players without a handicap get no strokes and don't set the lowest mark.
+    pub fn allowances_get(&self, player_ids: &[Uuid], holes: usize) -> HashMap<Uuid, i32> {
+        let handicaps: Vec<(Uuid, f32)> = player_ids.iter().filter_map(|player_id| Some((*player_id, self.handicap_get(*player_id)?))).collect();
+        let Some(lowest) = handicaps.iter().map(|(_, handicap)| *handicap).min_by(f32::total_cmp) else {
+            return HashMap::new();
+        };
+        handicaps
+            .into_iter()
+            .map(|(player_id, handicap)| (player_id, ((handicap - lowest) * holes as f32 / 18.0).round() as i32))
+            .filter(|(_, allowance)| *allowance > 0)
+            .collect()
+    }
+}
+
+// Strokes over par per 18 holes for the most recent rounds that count, newest first.
+// Short rounds and the Daily Bonk's random draw of holes under a stroke cap don't.
+fn handicap_handler_differentials(rounds: &[(i32, i32, String)]) -> Vec<f32> {
+    let daily_bonk = format!("{:?}", StateMapSet::DailyBonk);
+    rounds
+        .iter()
+        .filter(|(_, holes, map_set)| *holes >= HANDICAP_MIN_HOLES && *map_set != daily_bonk)
+        .take(HANDICAP_RECENT_ROUNDS)
+        .map(|(over_par, holes, _)| *over_par as f32 * 18.0 / *holes as f32)
+        .collect()
+}
+
+// Average of the best recent differentials, to one decimal. Rounds are (strokes over par, holes played, map set), newest first.
+pub fn handicap_handler_from_rounds(rounds: &[(i32, i32, String)]) -> Option<f32> {
+    let mut differentials = handicap_handler_differentials(rounds);
+    if differentials.len() < HANDICAP_MIN_ROUNDS {
+        return None;
+    }
+    differentials.sort_by(f32::total_cmp);
+    let best = &differentials[..differentials.len().min(HANDICAP_BEST_ROUNDS)];
+    let average = best.iter().sum::<f32>() / best.len() as f32;
+    Some((average * 10.0).round() / 10.0)
+}
+
+pub fn handicap_handler_format(handicap: f32) -> String {
+    match handicap {
+        handicap if handicap < 0.0 => format!("+{:.1}", handicap.abs()), // Better than par, written the golf way
+        handicap => format!("{:.1}", handicap),
+    }
+}
+
+// Startup for the main player, and OnEnter(StateGame::InGame) for whoever is in the party
+pub fn handicap_handler_refresh(
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    mut handicap: ResMut<HandicapHandler>,
+) {
+    for player_id in party.all_players_get_ids() {
+        handicap.refresh(&db, player_id);
+    }
+    info!("function: handicap_handler_refresh: {:?}", handicap.handicaps);
+}
+
+pub fn handicap_handler_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    columns: Query<(Entity, &MenuColumn)>,
+) {
+    let text_style = menu_button_text_style(&asset_server);
+    menu_button_row(&mut commands, &columns, MenuPage::Local, &text_style, vec![(HandicapButton, HandicapText)]);
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(24.0),
+                    top: Val::Px(24.0),
+                    padding: UiRect::all(Val::Px(12.0)),
+                    ..default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.6).into(),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            HandicapPanel,
+        ))
+        .with_children(|panel| {
+            panel.spawn((TextBundle::from_section("", text_style), HandicapPanelText));
+        });
+}
+
+// The toggle sits on the local menu page, the main player's handicap on the player menu page
+pub fn handicap_handler_update_button(
+    game_handler: Res<GameHandler>,
+    party: Res<Party>,
+    handicap: Res<HandicapHandler>,
+    mut button_text: Query<&mut Text, (With<HandicapText>, Without<HandicapPanelText>)>,
+    mut panel: Query<&mut Visibility, With<HandicapPanel>>,
+    mut panel_text: Query<&mut Text, (With<HandicapPanelText>, Without<HandicapText>)>,
+) {
+    let on_menu_player = MenuPage::Player.shown(&game_handler);
+    for mut visibility in panel.iter_mut() {
+        let shown = if on_menu_player { Visibility::Visible } else { Visibility::Hidden };
+        if *visibility != shown {
+            *visibility = shown;
+        }
+    }
+    if MenuPage::Local.shown(&game_handler) {
+        let label = if handicap.enabled_get() { "Handicaps: On (net stroke play)" } else { "Handicaps: Off" };
+        for text in button_text.iter_mut() {
+            menu_button_label_set(text, label);
+        }
+    }
+    if on_menu_player {
+        let main_player_id = party.main_player_get_player_id();
+        let label = match handicap.handicap_get(main_player_id) {
+            Some(value) => format!(
+                "Handicap: {}\nBest {} of your last {} rounds",
+                handicap_handler_format(value),
+                HANDICAP_BEST_ROUNDS.min(handicap.rounds_get(main_player_id)),
+                handicap.rounds_get(main_player_id),
+            ),
+            None => format!(
+                "Handicap: None yet\nPlay {} more rounds to get one",
+                HANDICAP_MIN_ROUNDS.saturating_sub(handicap.rounds_get(main_player_id)),
+            ),
+        };
+        for text in panel_text.iter_mut() {
+            menu_button_label_set(text, &label);
+        }
+    }
+}
+
+pub fn handicap_handler_button(
+    mut handicap: ResMut<HandicapHandler>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<HandicapButton>)>,
+) {
+    for interaction in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            let enabled = handicap.enabled_toggle();
+            info!("function: handicap_handler_button: [{}]", enabled);
+        }
+    }
+}
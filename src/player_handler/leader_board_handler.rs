@@ -1,12 +1,24 @@
 use bevy::prelude::*;
 
+// States
+use crate::StateMapSet;
+
 use crate::{
     CheckStateRT,
+    DatabaseConnection,
     GameHandler, 
     GameRecord, 
+    HandicapHandler,
     LeaderBoard, 
     Party, 
     RunTrigger,
+    TeamMode,
+};
+
+use crate::database_handler::{
+    db_pipeline_game_finish,
+    db_pipeline_game_start,
+    db_pipeline_record_save,
 };
 
 impl LeaderBoard {
@@ -29,6 +41,7 @@ impl LeaderBoard {
             game_id,
             players,
             scores,
+            scoring_format: game_handler.scoring_format_get(),
         };
         self.past_games.push(record);
         self.reset_current_scores();
@@ -52,6 +65,7 @@ impl LeaderBoard {
         info!("Review Game Record:");
         let (game_id, players, scores) = record.unwrap();
         info!("game_id: {:?}", game_id);
+        info!("scoring_format: {:?}", record.scoring_format_get());
         for i in 0..players.len() {
             let result = format!(
                 "Player: {:?}, Score: {:?}",
@@ -63,14 +77,41 @@ impl LeaderBoard {
     }
 }
 
+// The game_table row is written as the game starts so game_started holds, the format is fixed from here on
+pub fn leader_board_game_start(
+    db: Res<DatabaseConnection>,
+    mut game_handler: ResMut<GameHandler>,
+    state_map_set: Res<State<StateMapSet>>,
+) {
+    info!("function: leader_board_game_start"); 
+    let game_id = game_handler.game_id_get();
+    db_pipeline_game_start(&db, &game_id, &format!("{:?}", state_map_set.get()), game_handler.scoring_format_get());
+}
+
 pub fn leader_board_log_game(
     mut run_trigger: ResMut<RunTrigger>,
     mut leader_board: ResMut<LeaderBoard>,
-    game_handler: ResMut<GameHandler>,
+    db: Res<DatabaseConnection>,
+    mut game_handler: ResMut<GameHandler>,
     party: ResMut<Party>,
+    state_map_set: Res<State<StateMapSet>>,
+    mut handicap: ResMut<HandicapHandler>,
 ) {
     info!("function: leader_board_log_game"); 
     {
+        let game_id = game_handler.game_id_get();
+        db_pipeline_game_finish(&db, &game_id);
+
+        // Handicap history: only rounds played on your own ball
+        let counts_for_handicap = *state_map_set.get() != StateMapSet::Tutorial && party.team_mode_get() == TeamMode::Individual;
+        let (players, scores) = party.all_players_get_ids_and_scores();
+        for (player_id, score) in players.iter().zip(scores.iter()) {
+            if !counts_for_handicap || score.iter().all(|strokes| *strokes == 0) {
+                continue;
+            }
+            db_pipeline_record_save(&db, &game_id, &format!("{:?}", state_map_set.get()), player_id, score);
+            handicap.refresh(&db, *player_id);
+        }
         leader_board.log_game(game_handler, party); 
     }
     run_trigger.set_target(CheckStateRT::LeaderBoardLogGame, false);
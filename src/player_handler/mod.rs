@@ -1,3 +1,10 @@
+pub mod achievement_handler;
+pub mod handicap_handler;
 pub mod leader_board_handler;
+pub mod mulligan_handler;
 pub mod party_handler;
-pub mod player_handler;
\ No newline at end of file
+pub mod player_handler;
+pub mod putter_handler;
+pub mod scoring_handler;
+pub mod team_handler;
+pub mod tournament_handler;
\ No newline at end of file
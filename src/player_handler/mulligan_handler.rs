@@ -0,0 +1,211 @@
+use bevy::prelude::*;
+
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+// States
+use crate::{
+    StateGame,
+    StateMapSet,
+};
+
+// Resources
+use crate::{
+    CheckStateGH,
+    CheckStateRT,
+    GameHandler,
+    GolfBall,
+    HudHandler,
+    MenuColumn,
+    MenuPage,
+    MulliganButton,
+    MulliganHandler,
+    MulliganShot,
+    MulliganTaken,
+    MulliganText,
+    Party,
+    RunTrigger,
+};
+
+use crate::user_interface::menu_button_handler::{
+    menu_button_label_set,
+    menu_button_row,
+    menu_button_text_style,
+};
+
+const MULLIGAN_ALLOWANCE_MAX: i32 = 3;
+
+impl MulliganHandler {
+    pub fn new() -> Self {
+        MulliganHandler {
+            allowance: 0,
+            last_shot: None,
+            rests: HashMap::new(),
+            used: HashMap::new(),
+        }
+    }
+
+    pub fn allowance_get(&self) -> i32 {
+        self.allowance
+    }
+
+    pub fn allowance_cycle(&mut self) -> i32 {
+        self.allowance = (self.allowance + 1) % (MULLIGAN_ALLOWANCE_MAX + 1);
+        self.allowance
+    }
+
+    pub fn remaining_get(&self, player_id: Uuid) -> i32 {
+        let used = self.used.get(&player_id).map_or(0, |holes| holes.len() as i32);
+        (self.allowance - used).max(0)
+    }
+
+    pub fn used_on(&self, player_id: Uuid, hole: i32) -> bool {
+        self.used.get(&player_id).is_some_and(|holes| holes.contains(&hole))
+    }
+
+    // Lines for the results screen, one per player who took any
+    pub fn summary_get(&self, party: &Party, hud: &HudHandler) -> Vec<String> {
+        let mut lines: Vec<String> = Vec::new();
+        for (player_index, (player_id, player_type)) in party.all_players_get_ids_and_types().iter().enumerate() {
+            let Some(holes) = self.used.get(player_id).filter(|holes| !holes.is_empty()) else {
+                continue;
+            };
+            let holes: Vec<String> = holes.iter().map(|hole| hole.to_string()).collect();
+            lines.push(format!("Mulligans: {} on hole {}", hud.player_name_get(player_index, player_type), holes.join(", ")));
+        }
+        lines
+    }
+}
+
+pub fn mulligan_handler_game_start(
+    mut mulligan: ResMut<MulliganHandler>,
+) {
+    mulligan.last_shot = None;
+    mulligan.rests.clear();
+    mulligan.used.clear();
+}
+
+// last_position is overwritten once the turn moves on, so keep where every ball last sat still
+pub fn mulligan_handler_track_rests(
+    mut mulligan: ResMut<MulliganHandler>,
+    golf_balls: Query<(&GolfBall, &Transform)>,
+) {
+    for (golf_ball, transform) in golf_balls.iter() {
+        if golf_ball.0.sleeping && mulligan.rests.get(&golf_ball.0.uuid) != Some(&transform.translation) {
+            mulligan.rests.insert(golf_ball.0.uuid, transform.translation);
+        }
+    }
+}
+
+// Runs with PartyHandlerActivePlayerAddBonk, before the stroke is counted
+pub fn mulligan_handler_shot_listener(
+    game_handler: Res<GameHandler>,
+    party: Res<Party>,
+    mut mulligan: ResMut<MulliganHandler>,
+) {
+    let ball_id = party.active_ball_get_id();
+    let level = game_handler.current_level_get();
+    mulligan.last_shot = match (game_handler.get(CheckStateGH::RemoteGame), mulligan.rests.get(&ball_id)) {
+        (false, Some(position)) => Some(MulliganShot {
+            player_id: party.active_player_get_player_id(),
+            player_index: party.active_player_get_index(),
+            ball_id,
+            level,
+            strokes: party.active_player_get_bonks_level(level as usize),
+            penalties: party.player_get_penalties_level(party.active_player_get_player_id(), level as usize),
+            position: *position,
+        }),
+        _ => None,
+    };
+}
+
+// Puts the last shot back once it has settled: ball, card and turn
+pub fn mulligan_handler_undo(
+    run_trigger: Res<RunTrigger>,
+    game_handler: Res<GameHandler>,
+    state_game: Res<State<StateGame>>,
+    state_map_set: Res<State<StateMapSet>>,
+    mut party: ResMut<Party>,
+    mut mulligan: ResMut<MulliganHandler>,
+    mut mulligan_event_writer: EventWriter<MulliganTaken>,
+    mut golf_balls: Query<(&mut GolfBall, &mut Transform)>,
+) {
+    // The Daily Bonk is a scored attempt, so it always plays competitive
+    if *state_game.get() != StateGame::InGame || *state_map_set.get() == StateMapSet::DailyBonk {
+        return;
+    }
+    let Some(shot) = mulligan.last_shot.clone() else {
+        info!("function: mulligan_handler_undo: No shot to take back");
+        return;
+    };
+    if shot.level != game_handler.current_level_get() || mulligan.remaining_get(shot.player_id) == 0 {
+        info!("function: mulligan_handler_undo: None left for [{:?}]", shot.player_id);
+        return;
+    }
+    // Still rolling, or the turn change hasn't landed yet
+    let settling = run_trigger.get(CheckStateRT::StartMovementListenerTurnHandlerSetTurnNext) || run_trigger.get(CheckStateRT::TurnHandlerSetTurnNext);
+    if settling || golf_balls.iter().any(|(golf_ball, _)| !golf_ball.0.sleeping) {
+        return;
+    }
+    // A holed or picked up ball is gone, that shot stands
+    let Some((mut golf_ball, mut transform)) = golf_balls.iter_mut().find(|(golf_ball, _)| golf_ball.0.uuid == shot.ball_id) else {
+        return;
+    };
+
+    info!("function: mulligan_handler_undo: Player: [{:?}] Hole: [{}] Back To: [{:?}]", shot.player_id, shot.level, shot.position);
+    transform.translation = shot.position;
+    golf_ball.0.position = shot.position;
+    golf_ball.0.last_position = shot.position;
+    let penalties = party.player_get_penalties_level(shot.player_id, shot.level as usize) - shot.penalties;
+    let strokes = party.player_get_bonks_level(shot.player_id, shot.level as usize) - shot.strokes - penalties;
+    party.player_remove_bonks(shot.player_id, shot.level as usize, strokes);
+    party.player_remove_penalties(shot.player_id, shot.level as usize, penalties);
+    party.active_player_set(shot.player_index);
+    mulligan.used.entry(shot.player_id).or_default().push(shot.level);
+    mulligan.last_shot = None;
+    mulligan_event_writer.send(MulliganTaken {
+        player_id: shot.player_id,
+        level: shot.level,
+        strokes: shot.strokes,
+    });
+}
+
+pub fn mulligan_handler_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    columns: Query<(Entity, &MenuColumn)>,
+) {
+    let text_style = menu_button_text_style(&asset_server);
+    menu_button_row(&mut commands, &columns, MenuPage::Local, &text_style, vec![(MulliganButton, MulliganText)]);
+}
+
+// On the local menu, with the other round rules
+pub fn mulligan_handler_update_button(
+    game_handler: Res<GameHandler>,
+    mulligan: Res<MulliganHandler>,
+    mut button_text: Query<&mut Text, With<MulliganText>>,
+) {
+    if !MenuPage::Local.shown(&game_handler) {
+        return;
+    }
+    let label = match mulligan.allowance_get() {
+        0 => String::from("Mulligans: None (competitive)"),
+        allowance => format!("Mulligans: {} per player", allowance),
+    };
+    for text in button_text.iter_mut() {
+        menu_button_label_set(text, &label);
+    }
+}
+
+pub fn mulligan_handler_button(
+    mut mulligan: ResMut<MulliganHandler>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<MulliganButton>)>,
+) {
+    for interaction in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            let allowance = mulligan.allowance_cycle();
+            info!("function: mulligan_handler_button: [{}]", allowance);
+        }
+    }
+}
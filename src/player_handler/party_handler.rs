@@ -12,14 +12,21 @@ use crate::StateGame;
 use crate::{
     CheckStateRT,
     GameHandler,
+    MenuCycle,
     Party,
     Player,
     PlayerAi,
     PlayerLocal,
     PlayerRemote,
+    Putter,
     RunTrigger,
+    TeamMode,
 };
 
+use crate::player_handler::putter_handler::PUTTER_COUNT;
+use crate::player_handler::team_handler::TEAM_SIZE;
+use crate::user_interface::menu_handler::GOLF_BALL_COLOR_COUNT;
+
 impl Party {
     pub fn new() -> Self {
         let players: Arc<Mutex<Vec<Arc<Mutex<dyn Player + Send>>>>> = Arc::new(Mutex::new(vec![Arc::new(Mutex::new(PlayerLocal::new()))]));
@@ -29,10 +36,25 @@ impl Party {
             players,
             active_player,
             ai_vec,
+            team_mode: TeamMode::Individual,
+            sitting_out: Vec::new(),
+            spectators: Vec::new(),
         } 
     }
 
+    pub fn active_ball_get_id(&self) -> Uuid {
+        self.ball_owner_get(self.active_player_get_player_id())
+    }
+
     pub fn active_player_add_bonk(&self, level: usize) {
+        // Alternate shot plays one ball, so the stroke goes on the whole side's card
+        if self.team_mode == TeamMode::AlternateShot {
+            for player_id in self.teammates_get(self.active_player_get_player_id()) {
+                self.player_add_bonks(player_id, level, 1);
+            }
+            info!("post function: active_player_add_bonk");
+            return;
+        }
         let active_player_index = *self.active_player.lock().unwrap(); // Get the active player index
         let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
         let player_arc = &players_lock[active_player_index as usize - 1]; // adjusted for 1 indexing // Get the active player (Arc<Mutex<Player>>)
@@ -73,6 +95,15 @@ impl Party {
         player.get_hole_completion_state()
     }
 
+    pub fn active_player_cycle_putter(&self) -> u32 {
+        let active_player_index = *self.active_player.lock().unwrap(); // Get the active player index
+        let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
+        let mut player = players_lock[active_player_index as usize - 1].lock().unwrap(); // adjusted for 1 indexing
+        let putter = (player.get_putter() + 1) % PUTTER_COUNT;
+        player.set_putter(putter);
+        putter
+    }
+
     pub fn active_player_get_index(&self) -> i32 {
         let active_player = *self.active_player.lock().unwrap();
         active_player
@@ -94,6 +125,13 @@ impl Party {
         player.get_player_type()
     }
     
+    pub fn active_player_get_putter(&self) -> Putter {
+        let active_player_index = *self.active_player.lock().unwrap(); // Get the active player index
+        let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
+        let player = players_lock[active_player_index as usize - 1].lock().unwrap(); // adjusted for 1 indexing
+        Putter::from_index(player.get_putter())
+    }
+
     pub fn active_player_get_score(&self) -> [i32; 18] {
         let players_lock = self.players.lock().unwrap();
         let active_idx_lock = self.active_player.lock().unwrap().to_owned() - 1;
@@ -106,6 +144,28 @@ impl Party {
         *active_player = target;
     }
 
+    // Round start, alternate shot picks the first side's rotation up where it left off
+    pub fn active_player_set_first(&mut self) {
+        self.active_player_set(1);
+        if self.team_mode == TeamMode::AlternateShot {
+            if let Some(team) = self.teams_get().first() {
+                let player_id = self.team_next_up(team);
+                self.active_player_set_id(player_id);
+            }
+        }
+        if self.player_sitting_out(self.active_player_get_player_id()) {
+            if let Some(player_id) = self.all_players_get_ids().into_iter().find(|id| !self.player_sitting_out(*id)) {
+                self.active_player_set_id(player_id);
+            }
+        }
+    }
+
+    fn active_player_set_id(&mut self, player_id: Uuid) {
+        if let Some(index) = self.all_players_get_ids().iter().position(|id| *id == player_id) {
+            self.active_player_set(index as i32 + 1);
+        }
+    }
+
     pub fn active_player_set_hole_completion_state(&mut self, state: bool) {
         let active_player_index = *self.active_player.lock().unwrap(); // Get the active player index
         let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
@@ -165,13 +225,29 @@ impl Party {
         for player in 0..players_lock.len() {
             let player_arc = &players_lock[player];
             let player = player_arc.lock().unwrap(); // Lock the player mutex to get a mutable reference to the player
-            if player.get_hole_completion_state() {
+            if player.get_hole_completion_state() || self.player_sitting_out(player.get_player_id()) {
                 count += 1;
             }
         }
         count
     }
 
+    // Under alternate shot the whole side plays the captain's ball
+    pub fn ball_owner_get(&self, player_id: Uuid) -> Uuid {
+        match self.team_mode {
+            TeamMode::AlternateShot => self.teammates_get(player_id)[0],
+            _ => player_id,
+        }
+    }
+
+    pub fn ball_owners_get(&self) -> Vec<Uuid> {
+        let owners: Vec<Uuid> = match self.team_mode {
+            TeamMode::AlternateShot => self.teams_get().into_iter().map(|team| team[0]).collect(),
+            _ => self.all_players_get_ids(),
+        };
+        owners.into_iter().filter(|player_id| !self.player_sitting_out(*player_id)).collect()
+    }
+
     pub fn game_completed(&mut self) {
         // First, lock the players mutex to get access to the Vec
         let players_lock = self.players.lock().unwrap();
@@ -224,6 +300,40 @@ impl Party {
         ai_index
     }
 
+    pub fn main_player_cycle_golf_ball(&self) -> u32 {
+        let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
+        let mut player = players_lock[0].lock().unwrap(); // Lock the player mutex to get a mutable reference to the player
+        let golf_ball = (player.get_golf_ball() + 1) % GOLF_BALL_COLOR_COUNT;
+        player.set_golf_ball(golf_ball);
+        golf_ball
+    }
+
+    pub fn main_player_set_golf_ball(&self, golf_ball: u32) {
+        let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
+        let mut player = players_lock[0].lock().unwrap(); // Lock the player mutex to get a mutable reference to the player
+        player.set_golf_ball(golf_ball);
+    }
+
+    pub fn main_player_cycle_putter(&self) -> u32 {
+        let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
+        let mut player = players_lock[0].lock().unwrap(); // Lock the player mutex to get a mutable reference to the player
+        let putter = (player.get_putter() + 1) % PUTTER_COUNT;
+        player.set_putter(putter);
+        putter
+    }
+
+    pub fn main_player_get_putter(&self) -> Putter {
+        let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
+        let player = players_lock[0].lock().unwrap(); // Lock the player mutex to get a reference to the player
+        Putter::from_index(player.get_putter())
+    }
+
+    pub fn main_player_set_putter(&self, putter: u32) {
+        let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
+        let mut player = players_lock[0].lock().unwrap(); // Lock the player mutex to get a mutable reference to the player
+        player.set_putter(putter);
+    }
+
     pub fn main_player_get_player_id(&self) -> Uuid {
         let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
         let player_arc = &players_lock[0]; // adjusted for 1 indexing // Get the active player (Arc<Mutex<Player>>)
@@ -236,6 +346,15 @@ impl Party {
     }
 
     pub fn next_player_set_order(&mut self) {
+        let teams = self.teams_get();
+        if self.team_mode == TeamMode::AlternateShot && !teams.is_empty() {
+            let active_player_id = self.active_player_get_player_id();
+            let team_index = teams.iter().position(|team| team.contains(&active_player_id)).unwrap_or(0);
+            let player_id = self.team_next_up(&teams[(team_index + 1) % teams.len()]);
+            self.active_player_set_id(player_id);
+            info!("post function: next_set_order_player: Alternate Shot: [{:?}]", player_id);
+            return;
+        }
         let mut active_player_index = self.active_player.lock().unwrap();
         let players_len = self.players.lock().unwrap().len() as i32;
 
@@ -262,17 +381,128 @@ impl Party {
         *party_size 
     }
 
+    fn player_add_bonks(&self, player_id: Uuid, level: usize, bonks: i32) {
+        let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
+        for player in players_lock.iter() {
+            let mut player = player.lock().unwrap(); // Lock the player mutex to get a mutable reference to the player
+            if player.get_player_id() == player_id {
+                for _ in 0..bonks {
+                    player.add_bonk(level);
+                }
+            };
+        }
+    }
+
+    pub fn player_add_penalty(&self, player_id: Uuid, level: usize, penalty: i32) {
+        let player_ids = match self.team_mode {
+            TeamMode::AlternateShot => self.teammates_get(player_id),
+            TeamMode::BestBall | TeamMode::Individual => vec![player_id],
+            TeamMode::Scramble => return, // The side just doesn't pick that lie, see team_handler_scramble_lie
+        };
+        let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
+        for player in players_lock.iter() {
+            let mut player = player.lock().unwrap(); // Lock the player mutex to get a mutable reference to the player
+            if player_ids.contains(&player.get_player_id()) {
+                player.add_penalty(level, penalty);
+            };
+        }
+    }
+
+    pub fn player_get_penalties(&self, player_id: Uuid) -> [i32; 18] {
+        let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
+        for player in players_lock.iter() {
+            let player = player.lock().unwrap(); // Lock the player mutex to get a reference to the player
+            if player.get_player_id() == player_id {
+                return player.get_penalties();
+            };
+        }
+        [0; 18]
+    }
+
+    pub fn player_get_penalties_level(&self, player_id: Uuid, level: usize) -> i32 {
+        let index_adj = (level as i32 - 1) as usize;
+        self.player_get_penalties(player_id).get(index_adj).copied().unwrap_or(0)
+    }
+
+    pub fn player_get_bonks_level(&self, player_id: Uuid, level: usize) -> i32 {
+        let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
+        for player in players_lock.iter() {
+            let mut player = player.lock().unwrap(); // Lock the player mutex to get a mutable reference to the player
+            if player.get_player_id() == player_id {
+                return player.get_bonks(level);
+            };
+        }
+        0
+    }
+
+    pub fn player_get_score(&self, player_id: Uuid) -> [i32; 18] {
+        let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
+        for player in players_lock.iter() {
+            let player = player.lock().unwrap(); // Lock the player mutex to get a mutable reference to the player
+            if player.get_player_id() == player_id {
+                return player.get_score();
+            };
+        }
+        [0; 18]
+    }
+
+    // A side finishes the hole together, except under best ball where everyone holes out their own ball
     pub fn player_set_hole_completion_state(&mut self, player_id: Uuid, state: bool) {
+        let player_ids = match self.team_mode {
+            TeamMode::BestBall => vec![player_id],
+            _ => self.teammates_get(player_id),
+        };
         let mut players = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
         for player in players.iter_mut() {
             let mut player = player.lock().unwrap(); // Lock the player mutex to get a mutable reference to the player
             let id = player.get_player_id();
-            if id == player_id {
+            if player_ids.contains(&id) {
                 player.set_hole_completion_state(state);
             };
         }
     }
 
+    // Mulligan: under alternate shot the strokes come off the whole side's card
+    pub fn player_remove_bonks(&self, player_id: Uuid, level: usize, bonks: i32) {
+        let player_ids = match self.team_mode {
+            TeamMode::AlternateShot => self.teammates_get(player_id),
+            _ => vec![player_id],
+        };
+        let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
+        for player in players_lock.iter() {
+            let mut player = player.lock().unwrap(); // Lock the player mutex to get a mutable reference to the player
+            if player_ids.contains(&player.get_player_id()) {
+                for _ in 0..bonks {
+                    player.remove_bonk(level);
+                }
+            };
+        }
+    }
+
+    // Mulligan: the shot's hazard strokes come off with it, from the same cards as its bonks
+    pub fn player_remove_penalties(&self, player_id: Uuid, level: usize, penalties: i32) {
+        let player_ids = match self.team_mode {
+            TeamMode::AlternateShot => self.teammates_get(player_id),
+            _ => vec![player_id],
+        };
+        let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
+        for player in players_lock.iter() {
+            let mut player = player.lock().unwrap(); // Lock the player mutex to get a mutable reference to the player
+            if player_ids.contains(&player.get_player_id()) {
+                player.remove_penalty(level, penalties);
+            };
+        }
+    }
+
+    // Spectators sit every game out, so turns, balls and finishing all skip them the same way
+    pub fn player_sitting_out(&self, player_id: Uuid) -> bool {
+        self.sitting_out.contains(&player_id) || self.player_spectating(player_id)
+    }
+
+    pub fn player_spectating(&self, player_id: Uuid) -> bool {
+        self.spectators.contains(&player_id)
+    }
+
     pub fn player_set_player_id(&mut self, player_idx: usize, new_id: Uuid) {
         let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
         let player_arc = &players_lock[player_idx]; // adjusted for 1 indexing // Get the active player (Arc<Mutex<Player>>)
@@ -280,6 +510,39 @@ impl Party {
         player.set_player_id(new_id);
     }
 
+    pub fn player_get_ball_material(&self, player_id: Uuid) -> Option<Color> {
+        let players_lock = self.players.lock().unwrap(); // First, lock the players mutex to get access to the Vec
+        for player in players_lock.iter() {
+            let player = player.lock().unwrap(); // Lock the player mutex to get a reference to the player
+            if player.get_player_id() == player_id {
+                return Some(player.get_ball_material());
+            };
+        }
+        None
+    }
+
+    // First color after the main player's that no one in the party is using yet
+    pub fn players_golf_ball_free(&self) -> u32 {
+        let players_lock = self.players.lock().unwrap();
+        let taken: Vec<u32> = players_lock.iter().map(|player| player.lock().unwrap().get_golf_ball()).collect();
+        let main_golf_ball = taken[0];
+        (1..GOLF_BALL_COLOR_COUNT)
+            .map(|offset| (main_golf_ball + offset) % GOLF_BALL_COLOR_COUNT)
+            .find(|golf_ball| !taken.contains(golf_ball))
+            .unwrap_or(main_golf_ball)
+    }
+
+    pub fn players_get_ball_materials(&self) -> Vec<(String, Color)> {
+        let players_lock = self.players.lock().unwrap();
+        players_lock
+            .iter()
+            .map(|player| {
+                let player = player.lock().unwrap();
+                (player.get_player_type(), player.get_ball_material())
+            })
+            .collect()
+    }
+
     pub fn players_add_player(&self, player: Arc<Mutex<dyn Player + Send>>) {
         let mut players_lock = self.players.lock().unwrap();
         if players_lock.len() < 6 {
@@ -339,6 +602,26 @@ impl Party {
         }
     }
 
+    // Tournament: players who missed the cut, cleared for any other game
+    pub fn sitting_out_set(&mut self, player_ids: Vec<Uuid>) {
+        self.sitting_out = player_ids;
+    }
+
+    // Online: peers the server reports as watching, including the main player when joined as one
+    pub fn spectator_add(&mut self, player_id: Uuid) {
+        if !self.spectators.contains(&player_id) {
+            self.spectators.push(player_id);
+        }
+    }
+
+    pub fn spectator_remove(&mut self, player_id: Uuid) {
+        self.spectators.retain(|id| *id != player_id);
+    }
+
+    pub fn spectators_get(&self) -> Vec<Uuid> {
+        self.spectators.clone()
+    }
+
     pub fn start_game(&mut self) {
         // First, lock the players mutex to get access to the Vec
         let players_lock = self.players.lock().unwrap();
@@ -350,6 +633,47 @@ impl Party {
         }
     }
 
+    pub fn team_mode_get(&self) -> TeamMode {
+        self.team_mode
+    }
+
+    pub fn team_mode_cycle(&mut self) -> TeamMode {
+        self.team_mode = self.team_mode.next();
+        self.team_mode
+    }
+
+    // Teammates take turns on the one ball, so whoever is up follows from the strokes the side has taken.
+    // Penalties are on the card but nobody played them, so they don't move the turn on.
+    fn team_next_up(&self, team: &[Uuid]) -> Uuid {
+        let card: i32 = self.player_get_score(team[0]).iter().sum();
+        let penalties: i32 = self.player_get_penalties(team[0]).iter().sum();
+        let strokes = card - penalties;
+        team[strokes as usize % team.len()]
+    }
+
+    // Scramble: the side's card takes the stroke count of whoever holed it
+    pub fn team_scores_share(&self, player_id: Uuid, level: usize) {
+        let strokes = self.player_get_bonks_level(player_id, level);
+        for teammate_id in self.teammates_get(player_id) {
+            let bonks = self.player_get_bonks_level(teammate_id, level);
+            self.player_add_bonks(teammate_id, level, strokes - bonks);
+        }
+    }
+
+    pub fn teammates_get(&self, player_id: Uuid) -> Vec<Uuid> {
+        self.teams_get().into_iter().find(|team| team.contains(&player_id)).unwrap_or_else(|| vec![player_id])
+    }
+
+    // Sides are pairs in party order, an odd player out plays on their own. Individual play is a side of one.
+    // Spectators and players who missed the cut are left out first, a side with one of them in it would never get a turn.
+    pub fn teams_get(&self) -> Vec<Vec<Uuid>> {
+        let player_ids: Vec<Uuid> = self.all_players_get_ids().into_iter().filter(|player_id| !self.player_sitting_out(*player_id)).collect();
+        match self.team_mode {
+            TeamMode::Individual => player_ids.into_iter().map(|player_id| vec![player_id]).collect(),
+            _ => player_ids.chunks(TEAM_SIZE).map(|team| team.to_vec()).collect(),
+        }
+    }
+
     pub fn update_ai_index_vec(&mut self) {
         self.ai_vec = Some(self.get_party_ai_index_vec());
     }
@@ -408,7 +732,8 @@ pub fn party_handler_cycle_active_player(
                 let players = party.players.lock().unwrap();
                 let ref_idx = (party.active_player_get_index() - 1) as usize;
                 if let Some(player) = players.get(ref_idx) {
-                    if !player.lock().unwrap().get_hole_completion_state() {
+                    let player = player.lock().unwrap();
+                    if !player.get_hole_completion_state() && !party.player_sitting_out(player.get_player_id()) {
                         break;
                     }
                 }
@@ -425,7 +750,8 @@ pub fn party_handler_new_player_ai(
 ) {
     info!("function: party_handler_new_player_ai"); 
     {
-        let new_player_ai = PlayerAi::new();
+        let mut new_player_ai = PlayerAi::new();
+        new_player_ai.set_golf_ball(party.players_golf_ball_free());
         let new_player = Arc::new(Mutex::new(new_player_ai));
         party.players_add_player(new_player);
     }
@@ -443,7 +769,8 @@ pub fn party_handler_new_player_local(
             party.players_remove_ai();
         };
         if party.get_count_party() < 6 {
-            let new_player_local = PlayerLocal::new();
+            let mut new_player_local = PlayerLocal::new();
+            new_player_local.set_golf_ball(party.players_golf_ball_free());
             let new_player = Arc::new(Mutex::new(new_player_local));
             party.players_add_player(new_player);
         };
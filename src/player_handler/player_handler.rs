@@ -10,25 +10,56 @@ use crate::{
     PlayerRemote,
 };
 
+use crate::user_interface::menu_handler::update_current_mesh_color;
+
+// Card helpers shared by the Player impls, score and penalties are both indexed by level - 1.
+// The tutorial, level 19, has no slot so anything played there is dropped.
+fn player_card_add(card: &mut [i32; 18], level: usize, strokes: i32) {
+    if let Some(slot) = level.checked_sub(1).and_then(|index| card.get_mut(index)) {
+        *slot += strokes;
+    }
+}
+
+// Mulligans take strokes back, never below zero
+fn player_card_remove(card: &mut [i32; 18], level: usize, strokes: i32) {
+    if let Some(slot) = level.checked_sub(1).and_then(|index| card.get_mut(index)) {
+        *slot = (*slot - strokes).max(0);
+    }
+}
+
+fn player_card_get(card: &[i32; 18], level: usize) -> i32 {
+    level.checked_sub(1).and_then(|index| card.get(index)).copied().unwrap_or(0)
+}
+
+// Strokes and penalties together, hole by hole
+fn player_card_total(score: &[i32; 18], penalties: &[i32; 18]) -> [i32; 18] {
+    std::array::from_fn(|hole| score[hole] + penalties[hole])
+}
+
 impl Player for PlayerAi {
     fn new() -> Self {
         PlayerAi {
             player_id: Uuid::now_v7(),
             player_type: String::from("PlayerAi"),
             hole_completion_state: false,
-            ball_material: Color::srgb(1.0, 0.0, 1.0),
+            golf_ball: 1,
+            putter: 0,
+            ball_material: update_current_mesh_color(1),
             score: [0; 18],
+            penalties: [0; 18],
         }
     }
 
     fn start_game(&mut self) {
         self.hole_completion_state = false;
         self.score = [0; 18];
+        self.penalties = [0; 18];
     }
 
     fn game_completed(&mut self) {
         self.hole_completion_state = false;
         self.score = [0; 18];
+        self.penalties = [0; 18];
     }
 
     fn hole_completed(&mut self) {
@@ -40,16 +71,27 @@ impl Player for PlayerAi {
     }
 
     fn add_bonk(&mut self, level: usize) {
-        let index_adj = (level as i32 - 1) as usize;
-        self.score[index_adj] += 1;
+        player_card_add(&mut self.score, level, 1);
+    }
+
+    fn remove_bonk(&mut self, level: usize) {
+        player_card_remove(&mut self.score, level, 1);
+    }
+
+    fn add_penalty(&mut self, level: usize, penalty: i32) {
+        player_card_add(&mut self.penalties, level, penalty);
+    }
+
+    fn remove_penalty(&mut self, level: usize, penalty: i32) {
+        player_card_remove(&mut self.penalties, level, penalty);
+    }
+
+    fn get_penalties(&self) -> [i32; 18] {
+        self.penalties
     }
 
     fn get_bonks(&mut self, level: usize) -> i32 {
-        if level == 0 {
-            return 0;
-        }
-        let index_adj = (level as i32 - 1) as usize;
-        self.score[index_adj]
+        player_card_get(&self.score, level) + player_card_get(&self.penalties, level)
     }
 
     fn get_hole_completion_state(&self) -> bool {
@@ -65,7 +107,28 @@ impl Player for PlayerAi {
     }
 
     fn get_score(&self) -> [i32; 18] {
-        self.score
+        player_card_total(&self.score, &self.penalties)
+    }
+
+    fn get_ball_material(&self) -> Color {
+        self.ball_material
+    }
+
+    fn get_golf_ball(&self) -> u32 {
+        self.golf_ball
+    }
+
+    fn set_golf_ball(&mut self, golf_ball: u32) {
+        self.golf_ball = golf_ball;
+        self.ball_material = update_current_mesh_color(golf_ball);
+    }
+
+    fn get_putter(&self) -> u32 {
+        self.putter
+    }
+
+    fn set_putter(&mut self, putter: u32) {
+        self.putter = putter;
     }
 
     fn set_hole_completion_state(&mut self, hole_completion_state: bool) {
@@ -85,19 +148,24 @@ impl Player for PlayerLocal {
             player_id: Uuid::now_v7(),
             player_type: String::from("PlayerLocal"),
             hole_completion_state: false,
-            ball_material: Color::srgb(1.0, 0.0, 1.0),
+            golf_ball: 1,
+            putter: 0,
+            ball_material: update_current_mesh_color(1),
             score: [0; 18],
+            penalties: [0; 18],
         }
     }
 
     fn start_game(&mut self) {
         self.hole_completion_state = false;
         self.score = [0; 18];
+        self.penalties = [0; 18];
     }
 
     fn game_completed(&mut self) {
         self.hole_completion_state = false;
         self.score = [0; 18];
+        self.penalties = [0; 18];
     }
 
     fn hole_completed(&mut self) {
@@ -109,19 +177,27 @@ impl Player for PlayerLocal {
     }
 
     fn add_bonk(&mut self, level: usize) {
-        let index_adj = (level as i32 - 1) as usize;
-        if level == 19 { // Tutorial level
-            return;
-        }
-        self.score[index_adj] += 1;
+        player_card_add(&mut self.score, level, 1);
+    }
+
+    fn remove_bonk(&mut self, level: usize) {
+        player_card_remove(&mut self.score, level, 1);
+    }
+
+    fn add_penalty(&mut self, level: usize, penalty: i32) {
+        player_card_add(&mut self.penalties, level, penalty);
+    }
+
+    fn remove_penalty(&mut self, level: usize, penalty: i32) {
+        player_card_remove(&mut self.penalties, level, penalty);
+    }
+
+    fn get_penalties(&self) -> [i32; 18] {
+        self.penalties
     }
 
     fn get_bonks(&mut self, level: usize) -> i32 {
-        if level == 0 || level > 17 {
-            return 0;
-        }
-        let index_adj = (level as i32 - 1) as usize;
-        self.score[index_adj]
+        player_card_get(&self.score, level) + player_card_get(&self.penalties, level)
     }
 
     fn get_hole_completion_state(&self) -> bool {
@@ -145,7 +221,28 @@ impl Player for PlayerLocal {
     }
 
     fn get_score(&self) -> [i32; 18] {
-        self.score
+        player_card_total(&self.score, &self.penalties)
+    }
+
+    fn get_ball_material(&self) -> Color {
+        self.ball_material
+    }
+
+    fn get_golf_ball(&self) -> u32 {
+        self.golf_ball
+    }
+
+    fn set_golf_ball(&mut self, golf_ball: u32) {
+        self.golf_ball = golf_ball;
+        self.ball_material = update_current_mesh_color(golf_ball);
+    }
+
+    fn get_putter(&self) -> u32 {
+        self.putter
+    }
+
+    fn set_putter(&mut self, putter: u32) {
+        self.putter = putter;
     }
 }
 
@@ -157,19 +254,24 @@ impl Player for PlayerRemote {
             player_id: Uuid::now_v7(),
             player_type: String::from("PlayerRemote"),
             hole_completion_state: false,
-            ball_material: Color::srgb(1.0, 0.0, 1.0),
+            golf_ball: 1,
+            putter: 0,
+            ball_material: update_current_mesh_color(1),
             score: [0; 18],
+            penalties: [0; 18],
         }
     }
 
     fn start_game(&mut self) {
         self.hole_completion_state = false;
         self.score = [0; 18];
+        self.penalties = [0; 18];
     }
 
     fn game_completed(&mut self) {
         self.hole_completion_state = false;
         self.score = [0; 18];
+        self.penalties = [0; 18];
     }
 
     fn hole_completed(&mut self) {
@@ -181,16 +283,27 @@ impl Player for PlayerRemote {
     }
 
     fn add_bonk(&mut self, level: usize) {
-        let index_adj = (level as i32 - 1) as usize;
-        self.score[index_adj] += 1;
+        player_card_add(&mut self.score, level, 1);
+    }
+
+    fn remove_bonk(&mut self, level: usize) {
+        player_card_remove(&mut self.score, level, 1);
+    }
+
+    fn add_penalty(&mut self, level: usize, penalty: i32) {
+        player_card_add(&mut self.penalties, level, penalty);
+    }
+
+    fn remove_penalty(&mut self, level: usize, penalty: i32) {
+        player_card_remove(&mut self.penalties, level, penalty);
+    }
+
+    fn get_penalties(&self) -> [i32; 18] {
+        self.penalties
     }
 
     fn get_bonks(&mut self, level: usize) -> i32 {
-        if level == 0 {
-            return 0;
-        }
-        let index_adj = (level as i32 - 1) as usize;
-        self.score[index_adj]
+        player_card_get(&self.score, level) + player_card_get(&self.penalties, level)
     }
 
     fn get_hole_completion_state(&self) -> bool {
@@ -214,6 +327,27 @@ impl Player for PlayerRemote {
     }
 
     fn get_score(&self) -> [i32; 18] {
-        self.score
+        player_card_total(&self.score, &self.penalties)
+    }
+
+    fn get_ball_material(&self) -> Color {
+        self.ball_material
+    }
+
+    fn get_golf_ball(&self) -> u32 {
+        self.golf_ball
+    }
+
+    fn set_golf_ball(&mut self, golf_ball: u32) {
+        self.golf_ball = golf_ball;
+        self.ball_material = update_current_mesh_color(golf_ball);
+    }
+
+    fn get_putter(&self) -> u32 {
+        self.putter
+    }
+
+    fn set_putter(&mut self, putter: u32) {
+        self.putter = putter;
     }
 }
\ No newline at end of file
@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+
+use uuid::Uuid;
+
+// Resources
+use crate::{
+    DatabaseConnection,
+    GameHandler,
+    MenuColumn,
+    MenuPage,
+    Party,
+    Putter,
+    PutterButton,
+    PutterCurve,
+    PutterText,
+};
+
+use crate::database_handler::db_pipeline_player_putter_save;
+use crate::split_mix_next;
+use crate::user_interface::menu_button_handler::{
+    menu_button_label_set,
+    menu_button_row,
+    menu_button_text_style,
+};
+
+// Index 0 keeps the original bonk feel, player_table.putter stores the index
+const PUTTERS: [Putter; 4] = [
+    Putter { name: "Blade", max_impulse: 0.00025, curve: PutterCurve::Linear, wobble: 0.0 },
+    Putter { name: "Mallet", max_impulse: 0.0002, curve: PutterCurve::EaseIn, wobble: 0.0 },
+    Putter { name: "Long", max_impulse: 0.00035, curve: PutterCurve::Linear, wobble: 0.06 },
+    Putter { name: "Cannon", max_impulse: 0.00045, curve: PutterCurve::EaseIn, wobble: 0.12 },
+];
+pub const PUTTER_COUNT: u32 = PUTTERS.len() as u32;
+
+impl Putter {
+    pub fn from_index(index: u32) -> Putter {
+        PUTTERS[(index % PUTTER_COUNT) as usize]
+    }
+
+    // Maps the 0.0..=1.0 bonk power onto this putter's impulse magnitude
+    pub fn impulse_scale(&self, power: f32) -> f32 {
+        let shaped = match self.curve {
+            PutterCurve::Linear => power,
+            PutterCurve::EaseIn => power * power,
+        };
+        shaped * self.max_impulse
+    }
+
+    // Yaw error in radians, grows with power. Seeded from the shot so replays and peers land the same way.
+    pub fn wobble_yaw(&self, power: f32, player_id: Uuid, level: i32, stroke: i32) -> f32 {
+        if self.wobble == 0.0 {
+            return 0.0;
+        }
+        let (id_high, id_low) = player_id.as_u64_pair();
+        let mut state = id_high ^ id_low ^ ((level as u64) << 32) ^ (stroke as u32 as u64);
+        let unit = (split_mix_next(&mut state) % 10_000) as f32 / 10_000.0;
+        (unit * 2.0 - 1.0) * self.wobble * power
+    }
+}
+
+pub fn putter_handler_cycle_active_player(
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+) {
+    let putter = party.active_player_cycle_putter();
+    info!("function: putter_handler_cycle_active_player: [{:?}]", Putter::from_index(putter));
+    // Only the profile owner's choice is stored, guests keep theirs for the session
+    if party.active_player_get_player_id() == party.main_player_get_player_id() {
+        db_pipeline_player_putter_save(&db, &party.main_player_get_player_id(), putter);
+    }
+}
+
+pub fn putter_handler_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    columns: Query<(Entity, &MenuColumn)>,
+) {
+    let text_style = menu_button_text_style(&asset_server);
+    menu_button_row(&mut commands, &columns, MenuPage::Player, &text_style, vec![(PutterButton, PutterText)]);
+}
+
+// On the player menu, the profile owner's putter
+pub fn putter_handler_update_button(
+    game_handler: Res<GameHandler>,
+    party: Res<Party>,
+    mut button_text: Query<&mut Text, With<PutterText>>,
+) {
+    if !MenuPage::Player.shown(&game_handler) {
+        return;
+    }
+    let label = format!("Putter: {}", party.main_player_get_putter().name);
+    for text in button_text.iter_mut() {
+        menu_button_label_set(text, &label);
+    }
+}
+
+pub fn putter_handler_button(
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<PutterButton>)>,
+) {
+    for interaction in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            let putter = party.main_player_cycle_putter();
+            info!("function: putter_handler_button: [{:?}]", Putter::from_index(putter));
+            db_pipeline_player_putter_save(&db, &party.main_player_get_player_id(), putter);
+        }
+    }
+}
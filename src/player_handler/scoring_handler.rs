@@ -0,0 +1,201 @@
+use bevy::prelude::*;
+
+// Resources
+use crate::{
+    GameHandler,
+    MenuColumn,
+    MenuCycle,
+    MenuPage,
+    ResultsStanding,
+    ScoringFormat,
+    ScoringFormatButton,
+    ScoringFormatText,
+};
+
+use crate::level_handler::level_handler::level_handler_par_get;
+use crate::user_interface::menu_button_handler::{
+    menu_button_label_set,
+    menu_button_row,
+    menu_button_text_style,
+};
+
+const SCORING_STABLEFORD_PAR_POINTS: i32 = 2;
+const SCORING_MATCH_WIN_POINTS: i32 = 2; // A halved match splits them
+
+impl MenuCycle for ScoringFormat {
+    fn all() -> &'static [ScoringFormat] {
+        &[
+            ScoringFormat::StrokePlay,
+            ScoringFormat::MatchPlay,
+            ScoringFormat::Skins,
+            ScoringFormat::Stableford,
+        ]
+    }
+}
+
+impl ScoringFormat {
+    pub fn title(&self) -> &'static str {
+        match self {
+            ScoringFormat::MatchPlay => "Match Play",
+            ScoringFormat::Skins => "Skins",
+            ScoringFormat::Stableford => "Stableford",
+            ScoringFormat::StrokePlay => "Stroke Play",
+        }
+    }
+
+    pub fn points_label(&self) -> &'static str {
+        match self {
+            ScoringFormat::MatchPlay => "pts",
+            ScoringFormat::Skins => "skins",
+            ScoringFormat::Stableford => "pts",
+            ScoringFormat::StrokePlay => "",
+        }
+    }
+
+    // Lower ranks first, so the points formats are negated. Stroke play ranks on net strokes.
+    pub fn rank_key(&self, standing: &ResultsStanding) -> i32 {
+        match self {
+            ScoringFormat::StrokePlay => standing.total - standing.allowance,
+            _ => -standing.points,
+        }
+    }
+}
+
+// Helper: unplayed holes score nothing
+pub fn scoring_handler_stableford_points(strokes: i32, par: i32) -> i32 {
+    if strokes == 0 {
+        return 0;
+    }
+    (SCORING_STABLEFORD_PAR_POINTS + par - strokes).max(0)
+}
+
+// Helper: the outright low score takes the hole, a tie or an unplayed hole has no winner
+fn scoring_handler_hole_winner(scores: &[[i32; 18]], hole: i32) -> Option<usize> {
+    let strokes: Vec<i32> = scores.iter().map(|score| score.get(hole as usize - 1).copied().unwrap_or(0)).collect();
+    let low = strokes.iter().filter(|strokes| **strokes > 0).min()?;
+    let mut winners = strokes.iter().enumerate().filter(|(_, strokes)| *strokes == low);
+    match (winners.next(), winners.next()) {
+        (Some((index, _)), None) => Some(index),
+        _ => None,
+    }
+}
+
+// Helper: a side's card, each hole is its low score. Scramble and alternate shot sides already share one card.
+pub fn scoring_handler_best_ball(cards: &[[i32; 18]]) -> [i32; 18] {
+    let mut best = [0; 18];
+    for (hole_index, strokes) in best.iter_mut().enumerate() {
+        *strokes = cards.iter().map(|card| card[hole_index]).filter(|strokes| *strokes > 0).min().unwrap_or(0);
+    }
+    best
+}
+
+// Points per side in party order, plus the lines the results screen shows under the podium
+pub fn scoring_handler_score(
+    format: ScoringFormat,
+    holes: &[i32],
+    scores: &[[i32; 18]],
+    names: &[String],
+) -> (Vec<i32>, Vec<String>) {
+    let mut points = vec![0; scores.len()];
+    let mut summary: Vec<String> = Vec::new();
+    match format {
+        ScoringFormat::StrokePlay => {},
+        ScoringFormat::Stableford => {
+            for (player_index, score) in scores.iter().enumerate() {
+                points[player_index] = holes
+                    .iter()
+                    .map(|hole| scoring_handler_stableford_points(score.get(*hole as usize - 1).copied().unwrap_or(0), level_handler_par_get(*hole)))
+                    .sum();
+            }
+            summary.push(format!("{} points for par, one more per stroke under, one less per stroke over", SCORING_STABLEFORD_PAR_POINTS));
+        },
+        ScoringFormat::Skins => {
+            let mut carried = 0;
+            for hole in holes.iter() {
+                carried += 1;
+                if let Some(winner) = scoring_handler_hole_winner(scores, *hole) {
+                    points[winner] += carried;
+                    summary.push(format!("Hole {}: {} takes {} skin{}", hole, names[winner], carried, if carried == 1 { "" } else { "s" }));
+                    carried = 0;
+                }
+            }
+            if carried > 0 {
+                summary.push(format!("{} skin{} left on the table", carried, if carried == 1 { "" } else { "s" }));
+            }
+        },
+        ScoringFormat::MatchPlay => {
+            // Head to head, a bigger party plays a match against every other side
+            for first in 0..scores.len() {
+                for second in first + 1..scores.len() {
+                    let pair = [scores[first], scores[second]];
+                    let mut lead = 0;
+                    let mut closed: Option<i32> = None;
+                    for (hole_index, hole) in holes.iter().enumerate() {
+                        match scoring_handler_hole_winner(&pair, *hole) {
+                            Some(0) => lead += 1,
+                            Some(_) => lead -= 1,
+                            None => {},
+                        }
+                        let remaining = (holes.len() - hole_index - 1) as i32;
+                        if remaining > 0 && lead.abs() > remaining {
+                            closed = Some(remaining);
+                            break;
+                        }
+                    }
+                    let (winner, loser) = if lead > 0 { (first, second) } else { (second, first) };
+                    match (lead, closed) {
+                        (0, _) => {
+                            points[first] += SCORING_MATCH_WIN_POINTS / 2;
+                            points[second] += SCORING_MATCH_WIN_POINTS / 2;
+                            summary.push(format!("{} and {}: All square", names[first], names[second]));
+                        },
+                        (lead, Some(remaining)) => {
+                            points[winner] += SCORING_MATCH_WIN_POINTS;
+                            summary.push(format!("{} beats {} {}&{}", names[winner], names[loser], lead.abs(), remaining));
+                        },
+                        (lead, None) => {
+                            points[winner] += SCORING_MATCH_WIN_POINTS;
+                            summary.push(format!("{} beats {} {} up", names[winner], names[loser], lead.abs()));
+                        },
+                    }
+                }
+            }
+        },
+    }
+    (points, summary)
+}
+
+pub fn scoring_handler_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    columns: Query<(Entity, &MenuColumn)>,
+) {
+    let text_style = menu_button_text_style(&asset_server);
+    menu_button_row(&mut commands, &columns, MenuPage::Local, &text_style, vec![(ScoringFormatButton, ScoringFormatText)]);
+}
+
+// On the local menu, where the map set is picked
+pub fn scoring_handler_update_button(
+    game_handler: Res<GameHandler>,
+    mut button_text: Query<&mut Text, With<ScoringFormatText>>,
+) {
+    if !MenuPage::Local.shown(&game_handler) {
+        return;
+    }
+    let label = format!("Scoring: {}", game_handler.scoring_format_get().title());
+    for text in button_text.iter_mut() {
+        menu_button_label_set(text, &label);
+    }
+}
+
+pub fn scoring_handler_button(
+    mut game_handler: ResMut<GameHandler>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<ScoringFormatButton>)>,
+) {
+    for interaction in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            let format = game_handler.scoring_format_cycle();
+            info!("function: scoring_handler_button: [{:?}]", format);
+        }
+    }
+}
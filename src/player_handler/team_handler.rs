@@ -0,0 +1,200 @@
+use bevy::prelude::*;
+
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+// Resources
+use crate::{
+    GameHandler,
+    CheckStateRT,
+    GolfBall,
+    MenuColumn,
+    MenuCycle,
+    MenuPage,
+    MulliganTaken,
+    Party,
+    RunTrigger,
+    SceneInstanceCupGolfBall,
+    SceneInstanceHazardGolfBall,
+    TeamMode,
+    TeamModeButton,
+    TeamModeText,
+};
+
+use crate::user_interface::menu_button_handler::{
+    menu_button_label_set,
+    menu_button_row,
+    menu_button_text_style,
+};
+
+pub const TEAM_SIZE: usize = 2;
+const TEAM_SCRAMBLE_SPREAD: f32 = 0.05; // Gap between balls dropped on the same lie
+
+impl MenuCycle for TeamMode {
+    fn all() -> &'static [TeamMode] {
+        &[
+            TeamMode::Individual,
+            TeamMode::BestBall,
+            TeamMode::Scramble,
+            TeamMode::AlternateShot,
+        ]
+    }
+}
+
+impl TeamMode {
+    pub fn title(&self) -> &'static str {
+        match self {
+            TeamMode::AlternateShot => "Alternate Shot",
+            TeamMode::BestBall => "Best Ball",
+            TeamMode::Individual => "Individual",
+            TeamMode::Scramble => "Scramble",
+        }
+    }
+}
+
+// Scramble: once every teammate has played the stroke, the whole side moves to the ball nearest the cup
+pub fn team_handler_scramble_best_lie(
+    run_trigger: Res<RunTrigger>,
+    party: Res<Party>,
+    game_handler: Res<GameHandler>,
+    mut mulligan_event_reader: EventReader<MulliganTaken>,
+    mut hazard_event_reader: EventReader<SceneInstanceHazardGolfBall>,
+    mut grouped: Local<HashMap<Uuid, (i32, i32)>>,
+    mut hazarded: Local<HashMap<Uuid, (i32, i32)>>, // (level, stroke) each ball last went in a hazard on
+    mut golf_balls: Query<(&mut GolfBall, &mut Transform)>,
+    scene_meshes: Query<(&Name, &Transform), Without<GolfBall>>,
+) {
+    // Read every frame, a mulligan puts the side's stroke back so it groups again once the stroke is replayed
+    for event in mulligan_event_reader.read() {
+        let Some(team) = party.teams_get().into_iter().find(|team| team.contains(&event.player_id)) else {
+            continue;
+        };
+        if grouped.get(&team[0]).is_some_and(|(level, strokes)| *level == event.level && *strokes > event.strokes) {
+            grouped.insert(team[0], (event.level, event.strokes));
+        }
+        if hazarded.get(&event.player_id).is_some_and(|(level, strokes)| *level == event.level && *strokes > event.strokes) {
+            hazarded.remove(&event.player_id);
+        }
+    }
+    // Scramble charges no penalty, Party::player_add_penalty, so the hazard is remembered against the stroke instead
+    for event in hazard_event_reader.read() {
+        let level = game_handler.current_level_get();
+        hazarded.insert(event.id, (level, party.player_get_bonks_level(event.id, level as usize)));
+    }
+    if !run_trigger.get(CheckStateRT::TurnHandlerSetTurnNext) || party.team_mode_get() != TeamMode::Scramble {
+        return;
+    }
+    let Some(cup) = scene_meshes.iter().find(|(name, _)| name.as_str() == "cup").map(|(_, transform)| transform.translation) else {
+        return;
+    };
+    let level = game_handler.current_level_get();
+    for team in party.teams_get() {
+        if team.len() < 2 {
+            continue;
+        }
+        let strokes: Vec<i32> = team.iter().map(|player_id| party.player_get_bonks_level(*player_id, level as usize)).collect();
+        if strokes[0] == 0 || strokes.iter().any(|bonks| *bonks != strokes[0]) {
+            continue;
+        }
+        // Only once per stroke, otherwise every turn change would drop the side again
+        if grouped.get(&team[0]) == Some(&(level, strokes[0])) {
+            continue;
+        }
+        grouped.insert(team[0], (level, strokes[0]));
+
+        let lies: Vec<(Vec3, bool)> = golf_balls
+            .iter()
+            .filter(|(golf_ball, _)| team.contains(&golf_ball.0.uuid))
+            .map(|(golf_ball, transform)| (transform.translation, hazarded.get(&golf_ball.0.uuid) == Some(&(level, strokes[0]))))
+            .collect();
+        let Some(best_lie) = team_handler_scramble_lie(&lies, cup) else {
+            continue;
+        };
+        info!("function: team_handler_scramble_best_lie: Team: {:?} Stroke: [{}] Lie: [{:?}]", team, strokes[0], best_lie);
+
+        // Line the balls up across the line to the cup so none of them is stymied
+        let across = Vec3::new(cup.z - best_lie.z, 0.0, best_lie.x - cup.x).normalize_or_zero();
+        for (spot, (mut golf_ball, mut transform)) in golf_balls
+            .iter_mut()
+            .filter(|(golf_ball, _)| team.contains(&golf_ball.0.uuid))
+            .enumerate()
+        {
+            let lie = best_lie + across * TEAM_SCRAMBLE_SPREAD * spot as f32;
+            transform.translation = lie;
+            golf_ball.0.position = lie;
+            golf_ball.0.last_position = lie;
+        }
+    }
+}
+
+// Helper: team_handler_scramble_best_lie
+// (lie, went in a hazard) per ball. A hazarded ball sits on its drop zone, picking it would make the hazard a free advance.
+// Only when every ball found a hazard does the side play from the drops.
+pub fn team_handler_scramble_lie(lies: &[(Vec3, bool)], cup: Vec3) -> Option<Vec3> {
+    let nearest = |hazarded: Option<bool>| {
+        lies
+            .iter()
+            .filter(|(_, in_hazard)| hazarded.map_or(true, |hazarded| *in_hazard == hazarded))
+            .map(|(lie, _)| *lie)
+            .min_by(|a, b| a.distance(cup).total_cmp(&b.distance(cup)))
+    };
+    nearest(Some(false)).or_else(|| nearest(None))
+}
+
+pub fn team_handler_cup_listener(
+    mut commands: Commands,
+    mut cup_event_reader: EventReader<SceneInstanceCupGolfBall>,
+    party: Res<Party>,
+    golf_balls: Query<(Entity, &GolfBall)>,
+) {
+    for event in cup_event_reader.read() {
+        if party.team_mode_get() != TeamMode::Scramble {
+            continue;
+        }
+        info!("function: team_handler_cup_listener: Player: [{:?}] Level: [{}]", event.id, event.level);
+        party.team_scores_share(event.id, event.level as usize);
+        let teammates = party.teammates_get(event.id);
+        for (entity, golf_ball) in golf_balls.iter() {
+            if golf_ball.0.uuid != event.id && teammates.contains(&golf_ball.0.uuid) {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+pub fn team_handler_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    columns: Query<(Entity, &MenuColumn)>,
+) {
+    let text_style = menu_button_text_style(&asset_server);
+    menu_button_row(&mut commands, &columns, MenuPage::Local, &text_style, vec![(TeamModeButton, TeamModeText)]);
+}
+
+// On the local menu, above the scoring format
+pub fn team_handler_update_button(
+    game_handler: Res<GameHandler>,
+    party: Res<Party>,
+    mut button_text: Query<&mut Text, With<TeamModeText>>,
+) {
+    if !MenuPage::Local.shown(&game_handler) {
+        return;
+    }
+    let label = format!("Teams: {}", party.team_mode_get().title());
+    for text in button_text.iter_mut() {
+        menu_button_label_set(text, &label);
+    }
+}
+
+pub fn team_handler_button(
+    mut party: ResMut<Party>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<TeamModeButton>)>,
+) {
+    for interaction in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            let team_mode = party.team_mode_cycle();
+            info!("function: team_handler_button: [{:?}]", team_mode);
+        }
+    }
+}
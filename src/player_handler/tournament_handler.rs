@@ -0,0 +1,415 @@
+use bevy::prelude::*;
+
+use uuid::Uuid;
+
+// States
+use crate::StateMapSet;
+
+// Resources
+use crate::{
+    CheckStateGH,
+    CheckStateRT,
+    DatabaseConnection,
+    GameHandler,
+    MenuColumn,
+    MenuCycle,
+    MenuPage,
+    Party,
+    ResultsHandler,
+    ResultsStanding,
+    RunTrigger,
+    TeamMode,
+    Tournament,
+    TournamentAction,
+    TournamentButton,
+    TournamentFormat,
+    TournamentStanding,
+    TournamentText,
+};
+
+use crate::database_handler::{
+    db_pipeline_tournament_finish,
+    db_pipeline_tournament_game_link,
+    db_pipeline_tournament_round_save,
+    db_pipeline_tournament_start,
+};
+use crate::user_interface::menu_button_handler::{
+    menu_button_label_set,
+    menu_button_row,
+    menu_button_text_style,
+};
+
+const TOURNAMENT_CUT_AFTER_ROUND: usize = 1;
+const TOURNAMENT_CUT_MIN_FIELD: usize = 4; // Smaller parties all play every round
+
+impl MenuCycle for TournamentFormat {
+    fn all() -> &'static [TournamentFormat] {
+        &[
+            TournamentFormat::FrontAndBack,
+            TournamentFormat::ThreeRounds,
+        ]
+    }
+}
+
+impl TournamentFormat {
+    pub fn title(&self) -> &'static str {
+        match self {
+            TournamentFormat::FrontAndBack => "Front & Back",
+            TournamentFormat::ThreeRounds => "Three Rounds",
+        }
+    }
+
+    // One map set per round, in the order they're played
+    pub fn rounds(&self) -> Vec<StateMapSet> {
+        match self {
+            TournamentFormat::FrontAndBack => vec![StateMapSet::FrontNine, StateMapSet::BackNine],
+            TournamentFormat::ThreeRounds => vec![StateMapSet::FrontNine, StateMapSet::BackNine, StateMapSet::WholeCorse],
+        }
+    }
+}
+
+impl TournamentStanding {
+    pub fn strokes(&self) -> i32 {
+        self.rounds.iter().map(|(_, strokes, _)| strokes).sum()
+    }
+
+    pub fn to_par(&self) -> i32 {
+        self.rounds.iter().map(|(_, _, to_par)| to_par).sum()
+    }
+
+    // Anyone who joined late is ranked behind those with every round in
+    fn rank_key(&self) -> (bool, usize, i32) {
+        (self.cut, usize::MAX - self.rounds.len(), self.strokes())
+    }
+}
+
+impl Tournament {
+    pub fn new() -> Self {
+        Tournament {
+            format: TournamentFormat::FrontAndBack,
+            tournament_id: None,
+            rounds_played: 0,
+            playing: false,
+            standings: Vec::new(),
+        }
+    }
+
+    pub fn format_get(&self) -> TournamentFormat {
+        self.format
+    }
+
+    // The format is fixed while a tournament is in progress
+    pub fn format_cycle(&mut self) -> TournamentFormat {
+        if self.tournament_id.is_none() {
+            self.format = self.format.next();
+        }
+        self.format
+    }
+
+    pub fn in_progress(&self) -> bool {
+        self.tournament_id.is_some()
+    }
+
+    pub fn playing_get(&self) -> bool {
+        self.playing
+    }
+
+    pub fn rounds_played_get(&self) -> usize {
+        self.rounds_played
+    }
+
+    // None once every round is in
+    pub fn round_next(&self) -> Option<StateMapSet> {
+        self.format.rounds().get(self.rounds_played).cloned()
+    }
+
+    pub fn cut_get(&self) -> Vec<Uuid> {
+        self.standings.iter().filter(|standing| standing.cut).map(|standing| standing.player_id).collect()
+    }
+
+    pub fn standings_get(&self) -> &Vec<TournamentStanding> {
+        &self.standings
+    }
+
+    fn create(&mut self) -> Uuid {
+        let tournament_id = Uuid::now_v7();
+        self.tournament_id = Some(tournament_id);
+        self.rounds_played = 0;
+        self.standings.clear();
+        tournament_id
+    }
+
+    fn clear(&mut self) {
+        self.tournament_id = None;
+        self.rounds_played = 0;
+        self.playing = false;
+    }
+
+    // Rebuilds a stored tournament, scores are (round, player_id, name, strokes, to par)
+    pub fn resume(&mut self, tournament_id: Uuid, format: TournamentFormat, rounds_played: usize, cut: Vec<Uuid>, scores: Vec<(usize, Uuid, String, i32, i32)>) {
+        info!("function: Tournament::resume: [{}] Format: [{:?}] Rounds Played: [{}]", tournament_id, format, rounds_played);
+        self.format = format;
+        self.tournament_id = Some(tournament_id);
+        self.rounds_played = rounds_played;
+        self.playing = false;
+        self.standings.clear();
+        for (round, player_id, name, strokes, to_par) in scores {
+            self.standing_get_or_insert(player_id, name).rounds.push((round, strokes, to_par));
+        }
+        for standing in self.standings.iter_mut() {
+            standing.cut = cut.contains(&standing.player_id);
+        }
+        self.standings_sort();
+    }
+
+    fn standing_get_or_insert(&mut self, player_id: Uuid, name: String) -> &mut TournamentStanding {
+        let index = match self.standings.iter().position(|standing| standing.player_id == player_id) {
+            Some(index) => index,
+            None => {
+                self.standings.push(TournamentStanding { player_id, name, rounds: Vec::new(), cut: false });
+                self.standings.len() - 1
+            },
+        };
+        &mut self.standings[index]
+    }
+
+    fn standings_sort(&mut self) {
+        self.standings.sort_by_key(|standing| standing.rank_key());
+    }
+
+    // Adds the finished round to the cumulative standings, returns the rows to store
+    fn round_record(&mut self, standings: &[ResultsStanding]) -> Vec<(Uuid, String, i32, i32)> {
+        self.rounds_played += 1;
+        let round = self.rounds_played;
+        let mut scores: Vec<(Uuid, String, i32, i32)> = Vec::new();
+        for standing in standings.iter() {
+            let tournament_standing = self.standing_get_or_insert(standing.player_id, standing.name.clone());
+            tournament_standing.name = standing.name.clone(); // Keep up with renames between rounds
+            tournament_standing.rounds.push((round, standing.total, standing.to_par));
+            scores.push((standing.player_id, standing.name.clone(), standing.total, standing.to_par));
+        }
+        self.standings_sort();
+        scores
+    }
+
+    // Top half of the field goes through, anyone tied on the line goes with them
+    fn cut_apply(&mut self) {
+        let field: Vec<usize> = (0..self.standings.len()).filter(|index| !self.standings[*index].cut).collect();
+        let keep = field.len().div_ceil(2);
+        let Some(line) = keep.checked_sub(1).and_then(|last| field.get(last)).map(|index| self.standings[*index].rank_key()) else {
+            return;
+        };
+        for index in field {
+            if self.standings[index].rank_key() > line {
+                self.standings[index].cut = true;
+            }
+        }
+        self.standings_sort();
+    }
+
+    // Lines for the results screen, the cumulative standings after the round just played
+    pub fn summary_get(&self) -> Vec<String> {
+        let round_count = self.format.rounds().len();
+        let mut lines = vec![format!("Tournament: {}, round {} of {}", self.format.title(), self.rounds_played, round_count)];
+        let mut header = format!("{:<5}{:<20}", "", "Player");
+        for round in 1..=self.rounds_played {
+            header.push_str(&format!("{:>5}", format!("R{}", round)));
+        }
+        header.push_str(&format!("{:>7}{:>7}", "Total", "To Par"));
+        lines.push(header);
+
+        let mut place = 0;
+        for (index, standing) in self.standings.iter().enumerate() {
+            if index == 0 || standing.rank_key() != self.standings[index - 1].rank_key() {
+                place = index + 1;
+            }
+            let mut row = format!("{:<5}{:<20}", if standing.cut { String::from("MC") } else { place.to_string() }, standing.name);
+            for round in 1..=self.rounds_played {
+                let strokes = standing.rounds.iter().find(|(played, _, _)| *played == round).map(|(_, strokes, _)| *strokes);
+                row.push_str(&format!("{:>5}", strokes.map_or(String::from("-"), |strokes| strokes.to_string())));
+            }
+            let to_par = match standing.to_par() {
+                0 => String::from("E"),
+                to_par if to_par > 0 => format!("+{}", to_par),
+                to_par => to_par.to_string(),
+            };
+            row.push_str(&format!("{:>7}{:>7}", standing.strokes(), to_par));
+            lines.push(row);
+        }
+
+        match self.round_next() {
+            Some(map_set) => lines.push(format!("Next: {}, continue from the local menu", tournament_map_set_title(&map_set))),
+            None => {
+                if let Some(winner) = self.standings.first() {
+                    lines.push(format!("Tournament winner: {}", winner.name));
+                }
+            },
+        }
+        lines
+    }
+}
+
+pub fn tournament_format_from_name(name: &str) -> TournamentFormat {
+    match name {
+        "ThreeRounds" => TournamentFormat::ThreeRounds,
+        _ => TournamentFormat::FrontAndBack,
+    }
+}
+
+pub fn tournament_map_set_title(map_set: &StateMapSet) -> &'static str {
+    match map_set {
+        StateMapSet::WholeCorse => "Whole Course",
+        StateMapSet::FrontNine => "Front Nine",
+        StateMapSet::BackNine => "Back Nine",
+        _ => "Round",
+    }
+}
+
+// Helper: tournament_handler_buttons
+fn tournament_start_trigger(map_set: &StateMapSet) -> Option<CheckStateRT> {
+    match map_set {
+        StateMapSet::WholeCorse => Some(CheckStateRT::GameHandlerStartLocalWholeCorse),
+        StateMapSet::FrontNine => Some(CheckStateRT::GameHandlerStartLocalFrontNine),
+        StateMapSet::BackNine => Some(CheckStateRT::GameHandlerStartLocalBackNine),
+        _ => None,
+    }
+}
+
+// OnEnter(StateGame::InGame), after leader_board_game_start wrote the game_table row
+pub fn tournament_handler_game_start(
+    db: Res<DatabaseConnection>,
+    mut game_handler: ResMut<GameHandler>,
+    mut party: ResMut<Party>,
+    state_map_set: Res<State<StateMapSet>>,
+    mut tournament: ResMut<Tournament>,
+) {
+    // Only the round the tournament is waiting on counts, anything else is a regular game
+    let next_round = tournament.round_next();
+    tournament.playing = tournament.playing && !game_handler.get(CheckStateGH::RemoteGame) && next_round.as_ref() == Some(state_map_set.get());
+    info!("function: tournament_handler_game_start: Playing: [{}]", tournament.playing);
+    let Some(tournament_id) = tournament.tournament_id.filter(|_| tournament.playing) else {
+        party.sitting_out_set(Vec::new());
+        return;
+    };
+    let game_id = game_handler.game_id_get();
+    db_pipeline_tournament_game_link(&db, &tournament_id, tournament.rounds_played + 1, &game_id);
+    party.sitting_out_set(tournament.cut_get());
+    party.active_player_set_first();
+}
+
+// Runs on entering the results screen, before results_handler_setup so the standings make the summary
+pub fn tournament_handler_game_end(
+    db: Res<DatabaseConnection>,
+    mut party: ResMut<Party>,
+    mut results: ResMut<ResultsHandler>,
+    mut tournament: ResMut<Tournament>,
+) {
+    if !tournament.playing_get() {
+        return;
+    }
+    let Some(tournament_id) = tournament.tournament_id else {
+        return;
+    };
+    tournament.playing = false;
+    results.standings_withdraw(&tournament.cut_get());
+    let scores = tournament.round_record(results.standings_get());
+
+    // Sides share a card, so a cut only makes sense for individual play
+    let rounds_left = tournament.round_next().is_some();
+    let field = tournament.standings_get().len();
+    if tournament.rounds_played_get() == TOURNAMENT_CUT_AFTER_ROUND && rounds_left && field >= TOURNAMENT_CUT_MIN_FIELD && party.team_mode_get() == TeamMode::Individual {
+        tournament.cut_apply();
+        info!("function: tournament_handler_game_end: Cut: {:?}", tournament.cut_get());
+    }
+    db_pipeline_tournament_round_save(&db, &tournament_id, tournament.rounds_played_get(), &scores, &tournament.cut_get());
+    results.summary_extend(tournament.summary_get());
+
+    if !rounds_left {
+        info!("function: tournament_handler_game_end: [{}] Finished", tournament_id);
+        db_pipeline_tournament_finish(&db, &tournament_id);
+        tournament.clear();
+    }
+    party.sitting_out_set(Vec::new());
+}
+
+pub fn tournament_handler_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    columns: Query<(Entity, &MenuColumn)>,
+) {
+    let text_style = menu_button_text_style(&asset_server);
+    let buttons = [TournamentAction::Abandon, TournamentAction::Play, TournamentAction::Format]
+        .into_iter()
+        .map(|action| (TournamentButton(action), TournamentText(action)))
+        .collect();
+    menu_button_row(&mut commands, &columns, MenuPage::Local, &text_style, buttons);
+}
+
+// On the local menu, above the other round rules
+pub fn tournament_handler_update_button(
+    game_handler: Res<GameHandler>,
+    tournament: Res<Tournament>,
+    mut buttons: Query<(&TournamentButton, &mut Style)>,
+    mut button_text: Query<(&TournamentText, &mut Text)>,
+) {
+    if !MenuPage::Local.shown(&game_handler) {
+        return;
+    }
+    // Abandon only while there's a tournament to abandon, taken out of the row so the others close up
+    for (button, mut style) in buttons.iter_mut() {
+        let display = if button.0 != TournamentAction::Abandon || tournament.in_progress() { Display::Flex } else { Display::None };
+        if style.display != display {
+            style.display = display;
+        }
+    }
+    let round_count = tournament.format_get().rounds().len();
+    for (text, button_text) in button_text.iter_mut() {
+        let label = match (text.0, tournament.in_progress()) {
+            (TournamentAction::Abandon, _) => String::from("Abandon"),
+            (TournamentAction::Format, false) => format!("Tournament: {}", tournament.format_get().title()),
+            (TournamentAction::Format, true) => format!("Tournament: {}, round {} of {}", tournament.format_get().title(), tournament.rounds_played_get() + 1, round_count),
+            (TournamentAction::Play, false) => String::from("Start"),
+            (TournamentAction::Play, true) => String::from("Continue"),
+        };
+        menu_button_label_set(button_text, &label);
+    }
+}
+
+pub fn tournament_handler_button(
+    db: Res<DatabaseConnection>,
+    mut run_trigger: ResMut<RunTrigger>,
+    mut party: ResMut<Party>,
+    mut tournament: ResMut<Tournament>,
+    buttons: Query<(&Interaction, &TournamentButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        info!("function: tournament_handler_button: [{:?}]", button.0);
+        match button.0 {
+            TournamentAction::Abandon => {
+                if let Some(tournament_id) = tournament.tournament_id {
+                    db_pipeline_tournament_finish(&db, &tournament_id);
+                }
+                tournament.clear();
+            },
+            TournamentAction::Format => {
+                tournament.format_cycle();
+            },
+            TournamentAction::Play => {
+                if !tournament.in_progress() {
+                    let tournament_id = tournament.create();
+                    db_pipeline_tournament_start(&db, &tournament_id, tournament.format_get());
+                }
+                let Some(trigger) = tournament.round_next().as_ref().and_then(tournament_start_trigger) else {
+                    continue;
+                };
+                // Set now as well as at game start, the first hole's balls may spawn before OnEnter runs
+                party.sitting_out_set(tournament.cut_get());
+                tournament.playing = true;
+                run_trigger.set_target(trigger, true);
+            },
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use bevy::{
+    prelude::*,
+    window::{
+        PresentMode,
+        PrimaryWindow,
+        WindowMode,
+    },
+};
+
+// States
+use crate::{
+    StateAimAssist,
+    StateGamePlayStyle,
+};
+
+// Resources
+use crate::{
+    AudioCategory,
+    CameraHandler,
+    DatabaseConnection,
+    Difficulty,
+    GameHandler,
+    MenuPage,
+    PanOrbitSettings,
+    Party,
+    Preferences,
+    PreferencesPanel,
+};
+
+use crate::database_handler::db_pipeline_preferences_save;
+use crate::user_interface::menu_button_handler::{
+    menu_button_label_set,
+    menu_button_text_style,
+};
+
+const PREFERENCES_RESOLUTIONS: [(u32, u32); 4] = [
+    (1280, 720),
+    (1600, 900),
+    (1920, 1080),
+    (2560, 1440),
+];
+const PREFERENCES_SENSITIVITY_STEP: f32 = 1.25; // Multiplied/divided per click
+const PREFERENCES_VOLUME_STEP: f32 = 0.1;
+const PREFERENCES_FOLLOW_LAG_STEP: f32 = 0.05;   // Seconds
+const PREFERENCES_FOLLOW_LAG_MAX: f32 = 1.0;
+const PREFERENCES_FOLLOW_CUP_STEP: f32 = 0.1;    // World units, 0.0 turns the zoom out to the cup off
+const PREFERENCES_FOLLOW_CUP_MAX: f32 = 2.0;
+// The boards on menu_preferences.glb, left column then right, top down.
+// Stepped settings have "_down"/"_up" boards either side of theirs.
+const PREFERENCES_SETTINGS: [&str; 16] = [
+    "preferences_window_mode",
+    "preferences_resolution",
+    "preferences_vsync",
+    "preferences_pan_sensitivity",
+    "preferences_orbit_sensitivity",
+    "preferences_zoom_sensitivity",
+    "preferences_camera_follow_lag",
+    "preferences_camera_follow_cup_distance",
+    "preferences_volume",
+    "preferences_volume_music",
+    "preferences_volume_effects",
+    "preferences_volume_interface",
+    "preferences_aim_assist",
+    "preferences_play_style",
+    "preferences_flyover",
+    "preferences_ball_trails",
+];
+
+impl Preferences {
+    pub fn new() -> Self {
+        let camera_defaults = PanOrbitSettings::default();
+        let follow_defaults = CameraHandler::new();
+        Preferences {
+            window_mode: WindowMode::Windowed,
+            resolution: PREFERENCES_RESOLUTIONS[0],
+            vsync: true,
+            pan_sensitivity: camera_defaults.pan_sensitivity,
+            orbit_sensitivity: camera_defaults.orbit_sensitivity,
+            zoom_sensitivity: camera_defaults.zoom_sensitivity,
+            volume: 1.0,
+            volume_music: 0.6,
+            volume_effects: 1.0,
+            volume_interface: 0.8,
+            aim_assist: [StateAimAssist::Full, StateAimAssist::FirstBounce, StateAimAssist::Off],
+            play_style: StateGamePlayStyle::SetOrder,
+            flyover: true,
+            ball_trails: true,
+            camera_follow_lag: follow_defaults.follow_lag_get(),
+            camera_follow_cup_distance: follow_defaults.follow_cup_distance_get(),
+            difficulty: Difficulty::default(),
+        }
+    }
+
+    // Preferences menu boards are named "preferences_<setting>[_up|_down]", see ray_release
+    pub fn interact(&mut self, name: &str) -> bool {
+        match name {
+            "preferences_window_mode" => {
+                self.window_mode = match self.window_mode {
+                    WindowMode::Windowed => WindowMode::BorderlessFullscreen,
+                    WindowMode::BorderlessFullscreen => WindowMode::Fullscreen,
+                    _ => WindowMode::Windowed,
+                };
+            },
+            "preferences_resolution" => {
+                let index = PREFERENCES_RESOLUTIONS.iter().position(|resolution| *resolution == self.resolution).unwrap_or(0);
+                self.resolution = PREFERENCES_RESOLUTIONS[(index + 1) % PREFERENCES_RESOLUTIONS.len()];
+            },
+            "preferences_vsync" => {
+                self.vsync = !self.vsync;
+            },
+            "preferences_pan_sensitivity_up" => self.pan_sensitivity *= PREFERENCES_SENSITIVITY_STEP,
+            "preferences_pan_sensitivity_down" => self.pan_sensitivity /= PREFERENCES_SENSITIVITY_STEP,
+            "preferences_orbit_sensitivity_up" => self.orbit_sensitivity *= PREFERENCES_SENSITIVITY_STEP,
+            "preferences_orbit_sensitivity_down" => self.orbit_sensitivity /= PREFERENCES_SENSITIVITY_STEP,
+            "preferences_zoom_sensitivity_up" => self.zoom_sensitivity *= PREFERENCES_SENSITIVITY_STEP,
+            "preferences_zoom_sensitivity_down" => self.zoom_sensitivity /= PREFERENCES_SENSITIVITY_STEP,
+            "preferences_volume_up" => self.volume = (self.volume + PREFERENCES_VOLUME_STEP).min(1.0),
+            "preferences_volume_down" => self.volume = (self.volume - PREFERENCES_VOLUME_STEP).max(0.0),
+            "preferences_volume_music_up" => self.volume_music = (self.volume_music + PREFERENCES_VOLUME_STEP).min(1.0),
+            "preferences_volume_music_down" => self.volume_music = (self.volume_music - PREFERENCES_VOLUME_STEP).max(0.0),
+            "preferences_volume_effects_up" => self.volume_effects = (self.volume_effects + PREFERENCES_VOLUME_STEP).min(1.0),
+            "preferences_volume_effects_down" => self.volume_effects = (self.volume_effects - PREFERENCES_VOLUME_STEP).max(0.0),
+            "preferences_volume_interface_up" => self.volume_interface = (self.volume_interface + PREFERENCES_VOLUME_STEP).min(1.0),
+            "preferences_volume_interface_down" => self.volume_interface = (self.volume_interface - PREFERENCES_VOLUME_STEP).max(0.0),
+            "preferences_camera_follow_lag_up" => self.camera_follow_lag = (self.camera_follow_lag + PREFERENCES_FOLLOW_LAG_STEP).min(PREFERENCES_FOLLOW_LAG_MAX),
+            "preferences_camera_follow_lag_down" => self.camera_follow_lag = (self.camera_follow_lag - PREFERENCES_FOLLOW_LAG_STEP).max(0.0),
+            "preferences_camera_follow_cup_distance_up" => self.camera_follow_cup_distance = (self.camera_follow_cup_distance + PREFERENCES_FOLLOW_CUP_STEP).min(PREFERENCES_FOLLOW_CUP_MAX),
+            "preferences_camera_follow_cup_distance_down" => self.camera_follow_cup_distance = (self.camera_follow_cup_distance - PREFERENCES_FOLLOW_CUP_STEP).max(0.0),
+            "preferences_aim_assist" => {
+                self.aim_assist[self.difficulty as usize] = match self.aim_assist_get() {
+                    StateAimAssist::Off => StateAimAssist::FirstBounce,
+                    StateAimAssist::FirstBounce => StateAimAssist::Full,
+                    StateAimAssist::Full => StateAimAssist::Off,
+                };
+            },
+            "preferences_play_style" => {
+                self.play_style = match self.play_style {
+                    StateGamePlayStyle::SetOrder => StateGamePlayStyle::Proximity,
+                    StateGamePlayStyle::Proximity => StateGamePlayStyle::SetOrder,
+                };
+            },
+            "preferences_flyover" => {
+                self.flyover = !self.flyover;
+            },
+            "preferences_ball_trails" => {
+                self.ball_trails = !self.ball_trails;
+            },
+            _ => return false,
+        }
+        info!("Preferences: [{}] -> [{:?}]", name, self);
+        true
+    }
+
+    pub fn label(&self, name: &str) -> String {
+        let on_off = |value: bool| if value { "On" } else { "Off" };
+        match name {
+            "preferences_window_mode" => format!("Window: {:?}", self.window_mode),
+            "preferences_resolution" => format!("Resolution: {}x{}", self.resolution.0, self.resolution.1),
+            "preferences_vsync" => format!("VSync: {}", on_off(self.vsync)),
+            "preferences_pan_sensitivity" => format!("Pan sensitivity: {:.2}", self.pan_sensitivity),
+            "preferences_orbit_sensitivity" => format!("Orbit sensitivity: {:.2}", self.orbit_sensitivity),
+            "preferences_zoom_sensitivity" => format!("Zoom sensitivity: {:.2}", self.zoom_sensitivity),
+            "preferences_volume" => format!("Volume: {:.0}%", self.volume * 100.0),
+            "preferences_volume_music" => format!("Music: {:.0}%", self.volume_music * 100.0),
+            "preferences_volume_effects" => format!("Effects: {:.0}%", self.volume_effects * 100.0),
+            "preferences_volume_interface" => format!("Interface: {:.0}%", self.volume_interface * 100.0),
+            "preferences_camera_follow_lag" => format!("Camera lag: {:.2}s", self.camera_follow_lag),
+            "preferences_camera_follow_cup_distance" => match self.camera_follow_cup_distance > 0.0 {
+                true => format!("Cup framing: {:.1}", self.camera_follow_cup_distance),
+                false => String::from("Cup framing: Off"),
+            },
+            "preferences_aim_assist" => format!("Aim assist ({:?}): {:?}", self.difficulty, self.aim_assist_get()),
+            "preferences_play_style" => format!("Play style: {:?}", self.play_style),
+            "preferences_flyover" => format!("Flyover: {}", on_off(self.flyover)),
+            "preferences_ball_trails" => format!("Ball trails: {}", on_off(self.ball_trails)),
+            _ => String::new(),
+        }
+    }
+
+    // Aim assist for the difficulty currently picked, the preferences page and CycleAimAssist change only this one
+    pub fn aim_assist_get(&self) -> StateAimAssist {
+        self.aim_assist[self.difficulty as usize].clone()
+    }
+
+    pub fn category_volume(&self, category: AudioCategory) -> f32 {
+        match category {
+            AudioCategory::Effects => self.volume_effects,
+            AudioCategory::Interface => self.volume_interface,
+            AudioCategory::Music => self.volume_music,
+        }
+    }
+}
+
+// Database helpers, enums are stored by their Debug name
+pub fn preferences_aim_assist_from_name(name: &str) -> StateAimAssist {
+    match name {
+        "FirstBounce" => StateAimAssist::FirstBounce,
+        "Full" => StateAimAssist::Full,
+        _ => StateAimAssist::Off,
+    }
+}
+
+// Stored as "Easy,Medium,Hard", a single name from before the split applies to every difficulty
+pub fn preferences_aim_assist_from_names(names: &str) -> [StateAimAssist; 3] {
+    let names: Vec<&str> = names.split(',').collect();
+    std::array::from_fn(|index| preferences_aim_assist_from_name(names.get(index).unwrap_or(&names[0])))
+}
+
+pub fn preferences_aim_assist_names(aim_assist: &[StateAimAssist; 3]) -> String {
+    aim_assist.iter().map(|aim_assist| format!("{:?}", aim_assist)).collect::<Vec<String>>().join(",")
+}
+
+pub fn preferences_difficulty_from_name(name: &str) -> Difficulty {
+    match name {
+        "Easy" => Difficulty::Easy,
+        "Hard" => Difficulty::Hard,
+        _ => Difficulty::Medium,
+    }
+}
+
+pub fn preferences_play_style_from_name(name: &str) -> StateGamePlayStyle {
+    match name {
+        "Proximity" => StateGamePlayStyle::Proximity,
+        _ => StateGamePlayStyle::SetOrder,
+    }
+}
+
+pub fn preferences_window_mode_from_name(name: &str) -> WindowMode {
+    match name {
+        "BorderlessFullscreen" => WindowMode::BorderlessFullscreen,
+        "Fullscreen" => WindowMode::Fullscreen,
+        _ => WindowMode::Windowed,
+    }
+}
+
+// Pushes the stored preferences out to the window, camera, audio and game states.
+// Each group only goes out when its own settings changed, so cycling aim assist doesn't reset the window or play style.
+pub fn preferences_handler_apply(
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    preferences: Res<Preferences>,
+    mut applied: Local<Option<Preferences>>,
+    mut global_volume: ResMut<GlobalVolume>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut camera_settings: Query<&mut PanOrbitSettings>,
+    mut camera_handler: ResMut<CameraHandler>,
+    mut next_aim_assist_state: ResMut<NextState<StateAimAssist>>,
+    mut next_play_style_state: ResMut<NextState<StateGamePlayStyle>>,
+) {
+    info!("function: preferences_handler_apply: [{:?}]", *preferences);
+    // The first run is the stored row coming in at startup, everything goes out and nothing needs saving
+    let startup = applied.is_none();
+    let previous = applied.take().unwrap_or_else(|| preferences.clone());
+    if startup || previous.window_mode != preferences.window_mode || previous.resolution != preferences.resolution || previous.vsync != preferences.vsync {
+        for mut window in windows.iter_mut() {
+            window.mode = preferences.window_mode;
+            window.resolution.set(preferences.resolution.0 as f32, preferences.resolution.1 as f32);
+            window.present_mode = if preferences.vsync { PresentMode::AutoVsync } else { PresentMode::AutoNoVsync };
+        }
+    }
+    if startup || previous.pan_sensitivity != preferences.pan_sensitivity || previous.orbit_sensitivity != preferences.orbit_sensitivity || previous.zoom_sensitivity != preferences.zoom_sensitivity {
+        for mut settings in camera_settings.iter_mut() {
+            settings.pan_sensitivity = preferences.pan_sensitivity;
+            settings.orbit_sensitivity = preferences.orbit_sensitivity;
+            settings.zoom_sensitivity = preferences.zoom_sensitivity;
+        }
+    }
+    if startup || previous.camera_follow_lag != preferences.camera_follow_lag || previous.camera_follow_cup_distance != preferences.camera_follow_cup_distance {
+        camera_handler.follow_lag_set(preferences.camera_follow_lag);
+        camera_handler.follow_cup_distance_set(preferences.camera_follow_cup_distance);
+    }
+    if startup || previous.volume != preferences.volume {
+        global_volume.volume = bevy::audio::Volume::new(preferences.volume);
+    }
+    if startup || previous.aim_assist_get() != preferences.aim_assist_get() {
+        next_aim_assist_state.set(preferences.aim_assist_get());
+    }
+    if startup || previous.play_style != preferences.play_style {
+        next_play_style_state.set(preferences.play_style.clone());
+    }
+    if !startup {
+        db_pipeline_preferences_save(&db, &party.main_player_get_player_id(), &preferences);
+    }
+    *applied = Some(preferences.clone());
+}
+
+pub fn preferences_handler_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands.spawn((
+        TextBundle::from_section("", menu_button_text_style(&asset_server))
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(24.0),
+                top: Val::Px(24.0),
+                ..default()
+            })
+            .with_background_color(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        PreferencesPanel,
+    ))
+    .insert(Visibility::Hidden);
+}
+
+// Shown on the preferences menu level, the current value of every board on it
+pub fn preferences_handler_update_panel(
+    game_handler: Res<GameHandler>,
+    preferences: Res<Preferences>,
+    mut panel: Query<(&mut Text, &mut Visibility), With<PreferencesPanel>>,
+) {
+    let on_menu_preferences = MenuPage::Preferences.shown(&game_handler);
+    for (text, mut visibility) in panel.iter_mut() {
+        visibility.set_if_neq(if on_menu_preferences { Visibility::Visible } else { Visibility::Hidden });
+        if !on_menu_preferences {
+            continue;
+        }
+        let labels: Vec<String> = PREFERENCES_SETTINGS.iter().map(|name| preferences.label(name)).collect();
+        menu_button_label_set(text, &labels.join("\n"));
+    }
+}
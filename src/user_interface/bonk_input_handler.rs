@@ -0,0 +1,217 @@
+use bevy::prelude::*;
+
+use bevy_rapier3d::prelude::*;
+
+// States
+use crate::{
+    StateArrow,
+    StateBonkInput,
+    StateGamePlayStyle,
+};
+
+// Resources
+use crate::{
+    BonkHandler,
+    CameraWorld,
+    CheckStateGH,
+    GameHandler,
+    GolfBall,
+    InputAction,
+    InputMap,
+    Party,
+    PhysicsHandler,
+    RunTrigger,
+};
+
+use crate::level_handler::physics_handler::{
+    bonk_fire,
+    toggle_arrow_state,
+};
+
+const BONK_AIM_RATE: f32 = 1.5;             // Radians per second while rotating aim
+const BONK_CHARGE_RATE: f32 = 0.75;         // Power per second while holding a charge
+const BONK_METER_RATE: f32 = 1.5;           // Power per second for the oscillating meter
+const GAMEPAD_STICK_DEADZONE: f32 = 0.2;
+
+pub fn bonk_input_cycle_state(
+    bonk_input_state: Res<State<StateBonkInput>>,
+    mut next_bonk_input_state: ResMut<NextState<StateBonkInput>>,
+) {
+    match bonk_input_state.get() {
+        StateBonkInput::Mouse => {
+            info!("StateBonkInput::Gamepad");
+            next_bonk_input_state.set(StateBonkInput::Gamepad);
+        },
+        StateBonkInput::Gamepad => {
+            info!("StateBonkInput::Keyboard");
+            next_bonk_input_state.set(StateBonkInput::Keyboard);
+        },
+        StateBonkInput::Keyboard => {
+            info!("StateBonkInput::PowerMeter");
+            next_bonk_input_state.set(StateBonkInput::PowerMeter);
+        },
+        StateBonkInput::PowerMeter => {
+            info!("StateBonkInput::Mouse");
+            next_bonk_input_state.set(StateBonkInput::Mouse);
+        },
+    }
+}
+
+pub fn bonk_input_gamepad(
+    time: Res<Time>,
+    gamepads: Res<Gamepads>,
+    axes: Res<Axis<GamepadAxis>>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    camera_query: Query<&Transform, With<CameraWorld>>,
+    mut bonk_res: ResMut<BonkHandler>,
+    mut game_handler: ResMut<GameHandler>,
+    arrow_state: ResMut<State<StateArrow>>,
+    next_arrow_state: ResMut<NextState<StateArrow>>,
+    rapier_context: Res<RapierContext>,
+    rigid_body_query: Query<(Entity, &Name, &RapierRigidBodyHandle)>,
+    mut physics_handler: ResMut<PhysicsHandler>,
+    party: Res<Party>,
+    run_trigger: ResMut<RunTrigger>,
+    playstyle: Res<State<StateGamePlayStyle>>,
+    golf_balls: Query<(Entity, &mut GolfBall, &Name)>,
+) {
+    // Any connected pad can take the active player's shot for couch play
+    let mut charge_started = false;
+    let mut charging = false;
+    let mut released = false;
+    for gamepad in gamepads.iter() {
+        let stick_x = axes.get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX)).unwrap_or(0.0);
+        let stick_y = axes.get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickY)).unwrap_or(0.0);
+        if Vec2::new(stick_x, stick_y).length() > GAMEPAD_STICK_DEADZONE {
+            bonk_res.aim_yaw = stick_x.atan2(stick_y);
+        }
+        let trigger = GamepadButton::new(gamepad, GamepadButtonType::RightTrigger2);
+        charge_started |= gamepad_buttons.just_pressed(trigger);
+        charging |= gamepad_buttons.pressed(trigger);
+        released |= gamepad_buttons.just_released(trigger);
+    }
+
+    if charging {
+        bonk_res.power = (bonk_res.power + BONK_CHARGE_RATE * time.delta_seconds()).min(1.0);
+    }
+    bonk_input_update_direction(&mut bonk_res, &camera_query);
+
+    if charge_started {
+        bonk_res.power = 0.0;
+        if !game_handler.get(CheckStateGH::ArrowState) {
+            toggle_arrow_state(&mut game_handler, arrow_state, next_arrow_state);
+        }
+    } else if released {
+        bonk_fire(&mut game_handler, arrow_state, next_arrow_state, bonk_res.into(), rapier_context, rigid_body_query, &mut physics_handler, party, run_trigger, playstyle, golf_balls);
+    }
+}
+
+pub fn bonk_input_keyboard(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    input_map: Res<InputMap>,
+    camera_query: Query<&Transform, With<CameraWorld>>,
+    mut bonk_res: ResMut<BonkHandler>,
+    mut game_handler: ResMut<GameHandler>,
+    arrow_state: ResMut<State<StateArrow>>,
+    next_arrow_state: ResMut<NextState<StateArrow>>,
+    rapier_context: Res<RapierContext>,
+    rigid_body_query: Query<(Entity, &Name, &RapierRigidBodyHandle)>,
+    mut physics_handler: ResMut<PhysicsHandler>,
+    party: Res<Party>,
+    run_trigger: ResMut<RunTrigger>,
+    playstyle: Res<State<StateGamePlayStyle>>,
+    golf_balls: Query<(Entity, &mut GolfBall, &Name)>,
+) {
+    bonk_input_rotate_aim(&mut bonk_res, &input_map, &keys, &mouse, time.delta_seconds());
+    if input_map.pressed(InputAction::BonkCharge, &keys, &mouse) {
+        bonk_res.power = (bonk_res.power + BONK_CHARGE_RATE * time.delta_seconds()).min(1.0);
+    }
+    bonk_input_update_direction(&mut bonk_res, &camera_query);
+
+    if input_map.just_pressed(InputAction::BonkCharge, &keys, &mouse) {
+        bonk_res.power = 0.0;
+        if !game_handler.get(CheckStateGH::ArrowState) {
+            toggle_arrow_state(&mut game_handler, arrow_state, next_arrow_state);
+        }
+    } else if input_map.just_released(InputAction::BonkCharge, &keys, &mouse) {
+        bonk_fire(&mut game_handler, arrow_state, next_arrow_state, bonk_res.into(), rapier_context, rigid_body_query, &mut physics_handler, party, run_trigger, playstyle, golf_balls);
+    }
+}
+
+pub fn bonk_input_power_meter(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    input_map: Res<InputMap>,
+    camera_query: Query<&Transform, With<CameraWorld>>,
+    mut bonk_res: ResMut<BonkHandler>,
+    mut game_handler: ResMut<GameHandler>,
+    arrow_state: ResMut<State<StateArrow>>,
+    next_arrow_state: ResMut<NextState<StateArrow>>,
+    rapier_context: Res<RapierContext>,
+    rigid_body_query: Query<(Entity, &Name, &RapierRigidBodyHandle)>,
+    mut physics_handler: ResMut<PhysicsHandler>,
+    party: Res<Party>,
+    run_trigger: ResMut<RunTrigger>,
+    playstyle: Res<State<StateGamePlayStyle>>,
+    golf_balls: Query<(Entity, &mut GolfBall, &Name)>,
+) {
+    bonk_input_rotate_aim(&mut bonk_res, &input_map, &keys, &mouse, time.delta_seconds());
+    let meter_running = game_handler.get(CheckStateGH::ArrowState);
+    if meter_running { // Sweep the meter up and down until it's locked
+        let step = BONK_METER_RATE * time.delta_seconds();
+        if bonk_res.power_rising {
+            bonk_res.power += step;
+            if bonk_res.power >= 1.0 {
+                bonk_res.power = 1.0;
+                bonk_res.power_rising = false;
+            }
+        } else {
+            bonk_res.power -= step;
+            if bonk_res.power <= 0.0 {
+                bonk_res.power = 0.0;
+                bonk_res.power_rising = true;
+            }
+        }
+    }
+    bonk_input_update_direction(&mut bonk_res, &camera_query);
+
+    if input_map.just_pressed(InputAction::BonkCharge, &keys, &mouse) {
+        if meter_running {
+            bonk_fire(&mut game_handler, arrow_state, next_arrow_state, bonk_res.into(), rapier_context, rigid_body_query, &mut physics_handler, party, run_trigger, playstyle, golf_balls);
+        } else {
+            bonk_res.power = 0.0;
+            bonk_res.power_rising = true;
+            toggle_arrow_state(&mut game_handler, arrow_state, next_arrow_state);
+        }
+    }
+}
+
+// Helper: bonk_input_keyboard, bonk_input_power_meter
+fn bonk_input_rotate_aim(
+    bonk_res: &mut ResMut<BonkHandler>,
+    input_map: &Res<InputMap>,
+    keys: &Res<ButtonInput<KeyCode>>,
+    mouse: &Res<ButtonInput<MouseButton>>,
+    delta: f32,
+) {
+    if input_map.pressed(InputAction::AimLeft, keys, mouse) {
+        bonk_res.aim_yaw -= BONK_AIM_RATE * delta;
+    }
+    if input_map.pressed(InputAction::AimRight, keys, mouse) {
+        bonk_res.aim_yaw += BONK_AIM_RATE * delta;
+    }
+}
+
+// Helper: bonk_input_gamepad, bonk_input_keyboard, bonk_input_power_meter
+fn bonk_input_update_direction(
+    bonk_res: &mut ResMut<BonkHandler>,
+    camera_query: &Query<&Transform, With<CameraWorld>>,
+) {
+    if let Ok(camera) = camera_query.get_single() {
+        let camera_yaw = camera.rotation.to_euler(EulerRot::YXZ).0;
+        bonk_res.update_direction_aim_yaw(&camera_yaw);
+    }
+}
@@ -1,12 +1,15 @@
 use bevy::input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
 
+use bevy_rapier3d::prelude::*;
+
 use std::f32::consts::{FRAC_PI_2, PI, TAU};
 
 // State
 use crate::{
     StateCameraOrbitEntity, 
     StatePanOrbit,
+    StateTurn,
 };
 
 // Resource
@@ -15,6 +18,8 @@ use crate::{
     CameraWorld,
     CheckStateRT,
     GolfBall,
+    InputAction,
+    InputMap,
     PanOrbitAction,
     PanOrbitCameraBundle,
     PanOrbitSettings,
@@ -22,13 +27,45 @@ use crate::{
     RunTrigger,
 };
 
+const FOLLOW_FRAMING_MARGIN: f32 = 1.3; // Padding around the ball and cup when zooming out
+const FOLLOW_FOV: f32 = std::f32::consts::FRAC_PI_4; // Bevy's default perspective fov
+const COLLISION_MARGIN: f32 = 0.1;          // Gap kept between the camera and whatever blocked it
+const COLLISION_RADIUS_MIN: f32 = 0.1;      // Never pull in closer than this to the target
+const COLLISION_EASE_OUT: f32 = 0.35;       // Seconds to ease back out once the view clears
+
 impl CameraHandler {
     pub fn new() -> Self {
         let current_coords: Vec3 = Vec3::new(0.0, 0.0, 0.0);
         CameraHandler {
             current_coords,
+            radius_min: 0.0,
+            collision_radius: 0.0,
+            follow_lag: 0.25,
+            follow_cup_distance: 0.3,
+            follow_auto_cup: false,
         }
     }
+
+    pub fn follow_cup_distance_get(&self) -> f32 {
+        self.follow_cup_distance
+    }
+
+    pub fn follow_cup_distance_set(&mut self, distance: f32) {
+        self.follow_cup_distance = distance;
+    }
+
+    pub fn follow_lag_get(&self) -> f32 {
+        self.follow_lag
+    }
+
+    // Seconds for the chase camera to close most of the gap to its target, 0.0 snaps
+    pub fn follow_lag_set(&mut self, lag: f32) {
+        self.follow_lag = lag.max(0.0);
+    }
+
+    pub fn radius_min_get(&self) -> f32 {
+        self.radius_min
+    }
 }
 
 impl Default for StatePanOrbit {
@@ -49,9 +86,6 @@ impl Default for PanOrbitSettings {
             pan_sensitivity: 0.001, // 1000 pixels per world unit
             orbit_sensitivity: 0.1f32.to_radians(), // 0.1 degree per pixel
             zoom_sensitivity: 0.01,
-            pan_key: Some(KeyCode::ControlLeft),
-            orbit_key: Some(MouseButton::Left),
-            zoom_key: Some(KeyCode::ShiftLeft),
             scroll_action: Some(PanOrbitAction::Zoom),
             scroll_line_sensitivity: 16.0, // 1 "line" == 16 "pixels of motion"
             scroll_pixel_sensitivity: 1.0,
@@ -71,6 +105,10 @@ pub fn camera_handler_cycle_state_camera(
             next_camera_orbit_entity_state.set(StateCameraOrbitEntity::Ball);
         },
         StateCameraOrbitEntity::Ball => {
+            info!("StateCameraOrbitEntity::Follow");
+            next_camera_orbit_entity_state.set(StateCameraOrbitEntity::Follow);
+        },
+        StateCameraOrbitEntity::Follow => {
             info!("StateCameraOrbitEntity::Cup");
             next_camera_orbit_entity_state.set(StateCameraOrbitEntity::Cup);
         },
@@ -103,16 +141,113 @@ pub fn setup_3d_camera(
     ));
 }
 
+// Runs after pan_orbit_camera, pulls the camera in front of any level geometry between it and its target
+pub fn camera_handler_collision_avoidance(
+    time: Res<Time>,
+    rapier_context: Res<RapierContext>,
+    camera_orbit_entity_state: Res<State<StateCameraOrbitEntity>>,
+    mut camera_coord_tracker: ResMut<CameraHandler>,
+    mut q_camera: Query<(&StatePanOrbit, &mut Transform)>,
+) {
+    for (state, mut transform) in &mut q_camera {
+        let desired_radius = state.radius.max(camera_coord_tracker.radius_min_get());
+        if *camera_orbit_entity_state.get() == StateCameraOrbitEntity::Menu { // Menu views are authored, leave them alone
+            camera_coord_tracker.collision_radius = desired_radius;
+            continue;
+        }
+
+        // Golf balls are dynamic and sensors are invisible, neither should shove the camera
+        let filter = QueryFilter::default()
+            .exclude_dynamic()
+            .exclude_sensors();
+        let direction = transform.back();
+        let clear_radius = match rapier_context.cast_ray(state.center, *direction, desired_radius, false, filter) {
+            Some((_entity, time_of_impact)) => (time_of_impact - COLLISION_MARGIN).max(COLLISION_RADIUS_MIN),
+            None => desired_radius,
+        };
+
+        // Snap in so the view is never blocked, ease back out so it doesn't pop
+        let current_radius = camera_coord_tracker.collision_radius;
+        let radius = if clear_radius <= current_radius || current_radius <= 0.0 {
+            clear_radius
+        } else {
+            let blend = 1.0 - (-time.delta_seconds() / COLLISION_EASE_OUT).exp();
+            current_radius + (clear_radius - current_radius) * blend
+        };
+        if radius != current_radius {
+            camera_coord_tracker.collision_radius = radius;
+        }
+        let translation = state.center + direction * radius;
+        if transform.translation != translation {
+            transform.translation = translation;
+        }
+    }
+}
+
+pub fn camera_handler_follow_turn_listener(
+    mut turn_events: EventReader<StateTransitionEvent<StateTurn>>,
+    mut camera_coord_tracker: ResMut<CameraHandler>,
+    camera_orbit_entity_state: Res<State<StateCameraOrbitEntity>>,
+    mut next_camera_orbit_entity_state: ResMut<NextState<StateCameraOrbitEntity>>,
+) {
+    // Hand the camera back to the next player's ball if it was pulled to the cup automatically
+    for event in turn_events.read() {
+        if event.entered == Some(StateTurn::Active) && camera_coord_tracker.follow_auto_cup {
+            if *camera_orbit_entity_state.get() == StateCameraOrbitEntity::Cup {
+                info!("StateCameraOrbitEntity::Follow");
+                next_camera_orbit_entity_state.set(StateCameraOrbitEntity::Follow);
+            }
+            camera_coord_tracker.follow_auto_cup = false;
+        }
+    }
+}
+
 pub fn state_camera_orbit_entity_logic(
+    time: Res<Time>,
     camera_orbit_entity_state: ResMut<State<StateCameraOrbitEntity>>,
+    mut next_camera_orbit_entity_state: ResMut<NextState<StateCameraOrbitEntity>>,
     mut camera_coord_tracker: ResMut<CameraHandler>,
     scene_meshes: Query<(Entity, &Name, &Transform)>,
     golf_balls: Query<(&GolfBall, &Transform)>,
     party: Res<Party>,
 ) {
+    if *camera_orbit_entity_state.get() != StateCameraOrbitEntity::Follow && camera_coord_tracker.radius_min != 0.0 {
+        camera_coord_tracker.radius_min = 0.0;
+    }
     match camera_orbit_entity_state.get() {
+        StateCameraOrbitEntity::Follow => {
+            let active_player = party.active_ball_get_id();
+            let Some(ball) = golf_balls.iter().find(|(golf_ball, _)| golf_ball.0.uuid == active_player).map(|(_, transform)| transform.translation) else {
+                return;
+            };
+            let cup = scene_meshes.iter().find(|(_, name, _)| name.as_str() == "cup").map(|(_, _, transform)| transform.translation);
+
+            // Frame the ball and cup together, pulling back far enough to fit both
+            let (target, radius_min) = match cup {
+                Some(cup) => {
+                    if ball.distance(cup) < camera_coord_tracker.follow_cup_distance {
+                        info!("StateCameraOrbitEntity::Cup");
+                        camera_coord_tracker.follow_auto_cup = true;
+                        next_camera_orbit_entity_state.set(StateCameraOrbitEntity::Cup);
+                    }
+                    (ball.lerp(cup, 0.5), ball.distance(cup) * 0.5 / (FOLLOW_FOV * 0.5).tan() * FOLLOW_FRAMING_MARGIN)
+                },
+                None => (ball, 0.0),
+            };
+
+            // Exponential ease so the lag reads the same at any frame rate
+            let blend = if camera_coord_tracker.follow_lag > 0.0 {
+                1.0 - (-time.delta_seconds() / camera_coord_tracker.follow_lag).exp()
+            } else {
+                1.0
+            };
+            let current_coords = camera_coord_tracker.current_coords;
+            let current_radius_min = camera_coord_tracker.radius_min;
+            camera_coord_tracker.current_coords = current_coords.lerp(target, blend);
+            camera_coord_tracker.radius_min = current_radius_min + (radius_min - current_radius_min) * blend;
+        },
         StateCameraOrbitEntity::Ball => {
-            let active_player = party.active_player_get_player_id();
+            let active_player = party.active_ball_get_id();
             for (golf_ball, transform) in golf_balls.iter() {
                 if golf_ball.0.uuid == active_player {
                     camera_coord_tracker.current_coords = transform.translation;
@@ -142,6 +277,7 @@ pub fn state_camera_orbit_entity_logic(
 pub fn pan_orbit_camera(
     kbd: Res<ButtonInput<KeyCode>>,
     mouse: Res<ButtonInput<MouseButton>>,
+    input_map: Res<InputMap>,
     mut evr_motion: EventReader<MouseMotion>,
     mut evr_scroll: EventReader<MouseWheel>,
     mut q_camera: Query<(&PanOrbitSettings, &mut StatePanOrbit, &mut Transform)>,
@@ -170,7 +306,7 @@ pub fn pan_orbit_camera(
     for (settings, mut state, mut transform) in &mut q_camera {
         // Determine the target based on the current camera state
         let target = match camera_orbit_entity_state.get() {
-            StateCameraOrbitEntity::Ball | StateCameraOrbitEntity::Cup |
+            StateCameraOrbitEntity::Ball | StateCameraOrbitEntity::Follow | StateCameraOrbitEntity::Cup |
             StateCameraOrbitEntity::Menu => camera_coord_tracker.current_coords,
             StateCameraOrbitEntity::FreePan => state.center, // Use the original free pan center
         };
@@ -187,7 +323,7 @@ pub fn pan_orbit_camera(
 
         // Only use manual panning if in FreePan mode and the appropriate key is pressed
         if let StateCameraOrbitEntity::FreePan = camera_orbit_entity_state.get() {
-            if settings.pan_key.map(|key| kbd.pressed(key)).unwrap_or(false) {
+            if input_map.pressed(InputAction::CameraPan, &kbd, &mouse) {
                 total_pan -= total_motion * settings.pan_sensitivity;
             }
             if settings.scroll_action == Some(PanOrbitAction::Pan) {
@@ -197,7 +333,7 @@ pub fn pan_orbit_camera(
         }
 
         // Orbit logic - applicable in all modes
-        if allow_interaction && settings.orbit_key.map(|key| mouse.pressed(key)).unwrap_or(false) {
+        if allow_interaction && input_map.pressed(InputAction::CameraOrbit, &kbd, &mouse) {
             total_orbit -= total_motion * settings.orbit_sensitivity;
         }
         if settings.scroll_action == Some(PanOrbitAction::Orbit) {
@@ -205,7 +341,7 @@ pub fn pan_orbit_camera(
             total_orbit -= total_scroll_pixels * settings.scroll_pixel_sensitivity * settings.orbit_sensitivity;
         }
 
-        if allow_interaction && settings.zoom_key.map(|key| kbd.pressed(key)).unwrap_or(false) {
+        if allow_interaction && input_map.pressed(InputAction::CameraZoom, &kbd, &mouse) {
             total_zoom -= total_motion * settings.zoom_sensitivity;
         }
         if allow_interaction && settings.scroll_action == Some(PanOrbitAction::Zoom) {
@@ -214,7 +350,7 @@ pub fn pan_orbit_camera(
         }
 
         // Handle upside-down orbit reversal
-        if settings.orbit_key.map(|key| mouse.just_pressed(key)).unwrap_or(false) {
+        if input_map.just_pressed(InputAction::CameraOrbit, &kbd, &mouse) {
             state.upside_down = state.pitch < -FRAC_PI_2 || state.pitch > FRAC_PI_2;
         }
         if state.upside_down {
@@ -267,10 +403,11 @@ pub fn pan_orbit_camera(
             }
         }
 
-        // Update the camera's transform if anything changed
-        if any || state.is_added() {
+        // Update the camera's transform if anything changed, follow mode may hold the radius open to frame the cup
+        if any || state.is_added() || camera_coord_tracker.is_changed() {
+            let radius = state.radius.max(camera_coord_tracker.radius_min_get());
             transform.rotation = Quat::from_euler(EulerRot::YXZ, state.yaw, state.pitch, 0.0);
-            transform.translation = state.center + transform.back() * state.radius;
+            transform.translation = state.center + transform.back() * radius;
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,169 @@
+use bevy::prelude::*;
+
+// States
+use crate::{
+    StateFlyover,
+    StatePanOrbit,
+    StateTurn,
+};
+
+// Resources
+use crate::{
+    CameraWorld,
+    CheckStateGH,
+    FlyoverHandler,
+    GameHandler,
+    InputAction,
+    InputMap,
+    Preferences,
+    SceneInstanceSpawnedEnvironment,
+};
+
+const FLYOVER_SECONDS_PER_UNIT: f32 = 0.6;
+const FLYOVER_DURATION_MIN: f32 = 3.0;
+const FLYOVER_DURATION_MAX: f32 = 8.0;
+const FLYOVER_HEIGHT: f32 = 2.0;            // Cruise height above the tee/cup line, grows with hole length
+const FLYOVER_SWAY: f32 = 0.25;             // Sideways drift on the generated path so it isn't a straight line
+
+impl FlyoverHandler {
+    pub fn new() -> Self {
+        FlyoverHandler {
+            curve: None,
+            look_from: Vec3::ZERO,
+            look_to: Vec3::ZERO,
+            elapsed: 0.0,
+            duration: 0.0,
+            pending: false,
+            resume_turn: false,
+        }
+    }
+
+    // Levels can author the path as empties named "flyover_0", "flyover_1", ... otherwise one is generated tee to cup
+    pub fn build(&mut self, tee: Vec3, cup: Vec3, markers: Vec<(usize, Vec3)>) {
+        let mut markers = markers;
+        markers.sort_by_key(|(index, _)| *index);
+        let mut points: Vec<Vec3> = markers.into_iter().map(|(_, position)| position).collect();
+        if points.len() < 2 {
+            let distance = tee.distance(cup);
+            let back = (tee - cup).normalize_or_zero();
+            let side = back.cross(Vec3::Y).normalize_or_zero() * distance * FLYOVER_SWAY;
+            let height = Vec3::Y * (FLYOVER_HEIGHT + distance * 0.3);
+            points = vec![
+                tee + back * 1.5 + Vec3::Y,
+                tee.lerp(cup, 0.33) + height + side,
+                tee.lerp(cup, 0.66) + height - side,
+                cup + back + Vec3::Y * 0.75,
+            ];
+        }
+
+        // Catmull-Rom only passes through its inner points, so double up the ends
+        points.insert(0, points[0]);
+        points.push(points[points.len() - 1]);
+        self.curve = Some(CubicCardinalSpline::new_catmull_rom(points).to_curve());
+        self.look_from = tee;
+        self.look_to = cup;
+        self.elapsed = 0.0;
+        self.duration = (tee.distance(cup) * FLYOVER_SECONDS_PER_UNIT).clamp(FLYOVER_DURATION_MIN, FLYOVER_DURATION_MAX);
+    }
+
+    // Camera position and look target at the current point of the flyover
+    pub fn sample(&self) -> Option<(Vec3, Vec3)> {
+        let curve = self.curve.as_ref()?;
+        let t = (self.elapsed / self.duration).clamp(0.0, 1.0);
+        let eased = t * t * (3.0 - 2.0 * t);
+        let position = curve.position(eased * curve.segments().len() as f32);
+        Some((position, self.look_from.lerp(self.look_to, eased)))
+    }
+
+    pub fn finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    pub fn pending_get(&self) -> bool {
+        self.pending
+    }
+}
+
+pub fn flyover_handler_level_listener(
+    mut asset_event_reader: EventReader<SceneInstanceSpawnedEnvironment>,
+    game_handler: Res<GameHandler>,
+    preferences: Res<Preferences>,
+    mut flyover: ResMut<FlyoverHandler>,
+) {
+    for _event in asset_event_reader.read() {
+        if game_handler.get(CheckStateGH::InGame) && preferences.flyover {
+            info!("function: flyover_handler_level_listener: Flyover queued");
+            flyover.pending = true;
+        }
+    }
+}
+
+pub fn flyover_handler_start(
+    scene_meshes: Query<(&Name, &Transform)>,
+    mut flyover: ResMut<FlyoverHandler>,
+    state_turn: Res<State<StateTurn>>,
+    mut next_state_turn: ResMut<NextState<StateTurn>>,
+    mut next_flyover_state: ResMut<NextState<StateFlyover>>,
+) {
+    // The scene's children show up a few frames after the spawn event, wait for the cup
+    let Some(cup) = scene_meshes.iter().find(|(name, _)| name.as_str() == "cup").map(|(_, transform)| transform.translation) else {
+        return;
+    };
+    let markers: Vec<(usize, Vec3)> = scene_meshes
+        .iter()
+        .filter_map(|(name, transform)| {
+            let index = name.as_str().strip_prefix("flyover_")?.parse::<usize>().ok()?;
+            Some((index, transform.translation))
+        })
+        .collect();
+    info!("function: flyover_handler_start: Authored markers: [{}]", markers.len());
+
+    flyover.build(Vec3::ZERO, cup, markers); // Golf balls are placed around the origin
+    flyover.pending = false;
+    // Hold the turn back until the flyover is done
+    flyover.resume_turn = *state_turn.get() == StateTurn::Active;
+    if flyover.resume_turn {
+        info!("StateTurn::Idle");
+        next_state_turn.set(StateTurn::Idle);
+    }
+    info!("StateFlyover::Playing");
+    next_flyover_state.set(StateFlyover::Playing);
+}
+
+pub fn flyover_handler_play(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    input_map: Res<InputMap>,
+    game_handler: Res<GameHandler>,
+    mut flyover: ResMut<FlyoverHandler>,
+    mut q_camera: Query<(&StatePanOrbit, &mut Transform), With<CameraWorld>>,
+    mut next_state_turn: ResMut<NextState<StateTurn>>,
+    mut next_flyover_state: ResMut<NextState<StateFlyover>>,
+) {
+    flyover.elapsed += time.delta_seconds();
+    let skipped = input_map.just_pressed(InputAction::SkipFlyover, &keys, &mouse);
+    if !flyover.finished() && !skipped && game_handler.get(CheckStateGH::InGame) {
+        if let Some((position, look_at)) = flyover.sample() {
+            for (_state, mut transform) in q_camera.iter_mut() {
+                *transform = Transform::from_translation(position).looking_at(look_at, Vec3::Y);
+            }
+        }
+        return;
+    }
+
+    info!("function: flyover_handler_play: Finished: Skipped: [{}]", skipped);
+    // Hand the camera back where the orbit controls left it
+    for (state, mut transform) in q_camera.iter_mut() {
+        transform.rotation = Quat::from_euler(EulerRot::YXZ, state.yaw, state.pitch, 0.0);
+        transform.translation = state.center + transform.back() * state.radius;
+    }
+    flyover.curve = None;
+    if flyover.resume_turn {
+        info!("StateTurn::Active");
+        next_state_turn.set(StateTurn::Active);
+        flyover.resume_turn = false;
+    }
+    info!("StateFlyover::Idle");
+    next_flyover_state.set(StateFlyover::Idle);
+}
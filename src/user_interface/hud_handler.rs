@@ -0,0 +1,273 @@
+use bevy::prelude::*;
+
+use bevy_easy_vec_ui::EasyVecUi;
+
+// States
+use crate::{
+    StateGame,
+    StateMapSet,
+};
+
+// Resources
+use crate::{
+    CheckStateGH,
+    DailyBonk,
+    GameHandler,
+    HudHandler,
+    HudRoot,
+    HudScorecard,
+    HudStatus,
+    MulliganHandler,
+    Party,
+    TimeTrial,
+};
+
+use crate::level_handler::level_handler::level_handler_par_get;
+use crate::level_handler::time_trial_handler::{
+    time_trial_format,
+    time_trial_format_delta,
+};
+
+const HUD_FONT: &str = "fonts/MatrixtypeDisplay-KVELZ.ttf";
+const HUD_FONT_SIZE: f32 = 22.0;
+const HUD_SCORECARD_FONT_SIZE: f32 = 14.0;
+const HUD_TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const HUD_AHEAD_COLOR: Color = Color::srgb(0.4, 0.9, 0.4);
+const HUD_BEHIND_COLOR: Color = Color::srgb(0.95, 0.4, 0.35);
+
+impl HudHandler {
+    pub fn new() -> Self {
+        HudHandler {
+            scorecard_visible: false,
+            developer_panels: false,
+            main_player_name: String::from("Player 1"),
+        }
+    }
+
+    pub fn developer_panels_get(&self) -> bool {
+        self.developer_panels
+    }
+
+    pub fn main_player_name_set(&mut self, name: String) {
+        self.main_player_name = name;
+    }
+
+    pub fn player_name_get(&self, player_index: usize, player_type: &str) -> String {
+        match player_index {
+            0 => self.main_player_name.clone(),
+            _ => format!("Player {} ({})", player_index + 1, player_type.trim_start_matches("Player")),
+        }
+    }
+}
+
+pub fn hud_handler_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    let font: Handle<Font> = asset_server.load(HUD_FONT);
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                top: Val::Px(12.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            visibility: Visibility::Hidden,
+            ..default()
+        })
+        .insert(HudRoot)
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_sections([
+                    TextSection::new("", TextStyle { font: font.clone(), font_size: HUD_FONT_SIZE, color: HUD_TEXT_COLOR }),
+                    TextSection::new("", TextStyle { font: font.clone(), font_size: HUD_FONT_SIZE, color: HUD_TEXT_COLOR }),
+                    TextSection::new("", TextStyle { font: font.clone(), font_size: HUD_FONT_SIZE, color: HUD_TEXT_COLOR }),
+                ]),
+                HudStatus,
+            ));
+            parent.spawn((
+                TextBundle::from_section("", TextStyle { font: font.clone(), font_size: HUD_SCORECARD_FONT_SIZE, color: HUD_TEXT_COLOR })
+                    .with_background_color(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+                HudScorecard,
+            ));
+        });
+}
+
+pub fn hud_handler_toggle_scorecard(
+    mut hud: ResMut<HudHandler>,
+) {
+    hud.scorecard_visible = !hud.scorecard_visible;
+}
+
+pub fn hud_handler_toggle_developer_panels(
+    mut hud: ResMut<HudHandler>,
+    mut easy_vec_ui_resource: ResMut<EasyVecUi>,
+) {
+    hud.developer_panels = !hud.developer_panels;
+    info!("function: hud_handler_toggle_developer_panels: [{}]", hud.developer_panels);
+    if !hud.developer_panels { // easy_vec_ui stops running, so clear what it last drew
+        easy_vec_ui_resource.inject_vec_left(Vec::new());
+        easy_vec_ui_resource.inject_vec_right(Vec::new());
+    }
+}
+
+// Runs every frame, but only writes the text when a line actually changes so it isn't laid out again for nothing
+pub fn hud_handler_update(
+    hud: Res<HudHandler>,
+    party: Res<Party>,
+    game_handler: Res<GameHandler>,
+    daily: Res<DailyBonk>,
+    time_trial: Res<TimeTrial>,
+    mulligan: Res<MulliganHandler>,
+    state_game: Res<State<StateGame>>,
+    state_map_set: Res<State<StateMapSet>>,
+    mut hud_root: Query<&mut Visibility, (With<HudRoot>, Without<HudScorecard>)>,
+    mut status_text: Query<&mut Text, (With<HudStatus>, Without<HudScorecard>)>,
+    mut scorecard: Query<(&mut Text, &mut Visibility), (With<HudScorecard>, Without<HudStatus>)>,
+    mut scorecard_rows: Local<Vec<(String, Color)>>,
+) {
+    let in_game = *state_game.get() == StateGame::InGame;
+    let root_visibility = if in_game { Visibility::Visible } else { Visibility::Hidden };
+    for mut visibility in hud_root.iter_mut() {
+        visibility.set_if_neq(root_visibility);
+    }
+    if !in_game {
+        return;
+    }
+
+    let level = game_handler.current_level_get();
+    let players = party.all_players_get_ids_and_types();
+    let active_index = party.active_player_get_index() as usize - 1;
+    let active_type = &players[active_index].1;
+    let active_color = party.player_get_ball_material(players[active_index].0).unwrap_or(HUD_TEXT_COLOR);
+
+    for mut text in status_text.iter_mut() {
+        let hole = match (state_map_set.get(), level) {
+            (_, 19) => String::from("Tutorial"),
+            (StateMapSet::DailyBonk, _) => {
+                let position = daily.holes_get().iter().position(|hole| *hole == level).unwrap_or(0);
+                format!("Daily Bonk {}/{}   Hole {}", position + 1, daily.holes_get().len(), level)
+            },
+            _ => format!("Hole {}", level),
+        };
+        let stroke_cap = match (state_map_set.get(), daily.stroke_cap_get()) {
+            (StateMapSet::DailyBonk, Some(stroke_cap)) => format!(" of {}", stroke_cap),
+            _ => String::new(),
+        };
+        let mulligans = match mulligan.allowance_get() {
+            0 => String::new(),
+            _ => format!("Mulligans {}   ", mulligan.remaining_get(party.active_player_get_player_id())),
+        };
+        // Spectators watch whoever is up, so the line says so before the hole
+        let spectating = match game_handler.get(CheckStateGH::Spectator) {
+            true => "Spectating   ",
+            false => "",
+        };
+        let status = format!(
+            "{}{}   Par {}   Stroke {}{}   {}",
+            spectating,
+            hole,
+            level_handler_par_get(level),
+            party.active_player_get_bonks_level(level as usize),
+            stroke_cap,
+            mulligans,
+        );
+        let turn = format!("{}'s turn", hud.player_name_get(active_index, active_type));
+
+        // Time trial: the live split with strokes folded in, against the hole's personal best
+        let (split, split_color) = match time_trial.running_get() {
+            true => {
+                let split = time_trial.split_live(party.active_player_get_bonks_level(level as usize));
+                let delta = time_trial.delta_get(level, split);
+                match delta {
+                    Some(delta) if delta < 0.0 => (format!("   {}  {}", time_trial_format(split), time_trial_format_delta(delta)), HUD_AHEAD_COLOR),
+                    Some(delta) => (format!("   {}  {}", time_trial_format(split), time_trial_format_delta(delta)), HUD_BEHIND_COLOR),
+                    None => (format!("   {}", time_trial_format(split)), HUD_TEXT_COLOR),
+                }
+            },
+            false => (String::new(), HUD_TEXT_COLOR),
+        };
+
+        if text.sections[0].value != status {
+            text.sections[0].value = status;
+        }
+        if text.sections[1].value != turn || text.sections[1].style.color != active_color {
+            text.sections[1].value = turn;
+            text.sections[1].style.color = active_color;
+        }
+        if text.sections[2].value != split || text.sections[2].style.color != split_color {
+            text.sections[2].value = split;
+            text.sections[2].style.color = split_color;
+        }
+    }
+
+    for (mut text, mut visibility) in scorecard.iter_mut() {
+        visibility.set_if_neq(if hud.scorecard_visible { Visibility::Inherited } else { Visibility::Hidden });
+        if !hud.scorecard_visible {
+            continue;
+        }
+        let holes = hud_handler_map_set_holes(state_map_set.get(), level, daily.holes_get());
+        let (ids, scores) = party.all_players_get_ids_and_scores();
+
+        // One section per row so each player's row can carry their ball color
+        let mut header = format!("{:<20}", "Hole");
+        let mut par_row = format!("{:<20}", "Par");
+        let mut par_total = 0;
+        for hole in holes.iter() {
+            header.push_str(&format!("{:>4}", hole));
+            par_row.push_str(&format!("{:>4}", level_handler_par_get(*hole)));
+            par_total += level_handler_par_get(*hole);
+        }
+        header.push_str(&format!("{:>6}\n", "Tot"));
+        par_row.push_str(&format!("{:>6}\n", par_total));
+
+        let mut rows: Vec<(String, Color)> = vec![(header, HUD_TEXT_COLOR), (par_row, HUD_TEXT_COLOR)];
+        for (player_index, (player_id, score)) in ids.iter().zip(scores.iter()).enumerate() {
+            if party.player_spectating(*player_id) {
+                continue;
+            }
+            let mut row = format!("{:<20}", hud.player_name_get(player_index, &players[player_index].1));
+            let mut total = 0;
+            for hole in holes.iter() {
+                let strokes = score.get(*hole as usize - 1).copied().unwrap_or(0);
+                total += strokes;
+                let strokes = match (strokes, mulligan.used_on(*player_id, *hole)) {
+                    (0, _) => String::from("-"),
+                    (strokes, true) => format!("{}*", strokes),
+                    (strokes, false) => strokes.to_string(),
+                };
+                row.push_str(&format!("{:>4}", strokes));
+            }
+            row.push_str(&format!("{:>6}\n", total));
+            rows.push((row, party.player_get_ball_material(*player_id).unwrap_or(HUD_TEXT_COLOR)));
+        }
+        if mulligan.allowance_get() > 0 {
+            rows.push((String::from("* mulligan taken\n"), HUD_TEXT_COLOR));
+        }
+        if *scorecard_rows == rows {
+            continue;
+        }
+        let style = text.sections[0].style.clone();
+        text.sections = rows
+            .iter()
+            .map(|(row, color)| TextSection::new(row.clone(), TextStyle { color: *color, ..style.clone() }))
+            .collect();
+        *scorecard_rows = rows;
+    }
+}
+
+// Helper: hud_handler_update, turn_handler_end_game
+pub fn hud_handler_map_set_holes(map_set: &StateMapSet, current_level: i32, daily_holes: &[i32]) -> Vec<i32> {
+    match map_set {
+        StateMapSet::DailyBonk => daily_holes.to_vec(),
+        StateMapSet::WholeCorse => (1..=18).collect(),
+        StateMapSet::FrontNine => (1..=9).collect(),
+        StateMapSet::BackNine => (10..=18).collect(),
+        StateMapSet::Tutorial => vec![19], // Played for par but never carded, see PlayerLocal add_bonk
+        StateMapSet::SelectAHole | StateMapSet::ToBeSelected => vec![current_level.clamp(1, 18)],
+    }
+}
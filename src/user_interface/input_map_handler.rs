@@ -0,0 +1,383 @@
+use bevy::prelude::*;
+
+use std::collections::HashMap;
+
+// Resources
+use crate::{
+    DatabaseConnection,
+    GameHandler,
+    InputAction,
+    InputBinding,
+    InputMap,
+    MenuColumn,
+    MenuPage,
+    Party,
+    RebindAction,
+    RebindButton,
+    RebindText,
+};
+
+use crate::database_handler::db_pipeline_input_map_save;
+use crate::user_interface::menu_button_handler::{
+    menu_button_label_set,
+    menu_button_row,
+    menu_button_text_style,
+};
+
+// Keys the rebind listener will accept, anything outside this list can't round trip through the database
+const BINDABLE_KEYS: &[KeyCode] = &[
+    KeyCode::KeyA, KeyCode::KeyB, KeyCode::KeyC, KeyCode::KeyD, KeyCode::KeyE, KeyCode::KeyF, KeyCode::KeyG,
+    KeyCode::KeyH, KeyCode::KeyI, KeyCode::KeyJ, KeyCode::KeyK, KeyCode::KeyL, KeyCode::KeyM, KeyCode::KeyN,
+    KeyCode::KeyO, KeyCode::KeyP, KeyCode::KeyQ, KeyCode::KeyR, KeyCode::KeyS, KeyCode::KeyT, KeyCode::KeyU,
+    KeyCode::KeyV, KeyCode::KeyW, KeyCode::KeyX, KeyCode::KeyY, KeyCode::KeyZ,
+    KeyCode::Digit0, KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4,
+    KeyCode::Digit5, KeyCode::Digit6, KeyCode::Digit7, KeyCode::Digit8, KeyCode::Digit9,
+    KeyCode::Numpad0, KeyCode::Numpad1, KeyCode::Numpad2, KeyCode::Numpad3, KeyCode::Numpad4,
+    KeyCode::Numpad5, KeyCode::Numpad6, KeyCode::Numpad7, KeyCode::Numpad8, KeyCode::Numpad9,
+    KeyCode::F1, KeyCode::F2, KeyCode::F3, KeyCode::F4, KeyCode::F5, KeyCode::F6,
+    KeyCode::F7, KeyCode::F8, KeyCode::F9, KeyCode::F10, KeyCode::F11, KeyCode::F12,
+    KeyCode::ArrowUp, KeyCode::ArrowDown, KeyCode::ArrowLeft, KeyCode::ArrowRight,
+    KeyCode::ShiftLeft, KeyCode::ShiftRight, KeyCode::ControlLeft, KeyCode::ControlRight,
+    KeyCode::AltLeft, KeyCode::AltRight, KeyCode::Space, KeyCode::Enter, KeyCode::Tab,
+    KeyCode::Backspace, KeyCode::Escape, KeyCode::Backquote, KeyCode::Minus, KeyCode::Equal,
+    KeyCode::BracketLeft, KeyCode::BracketRight,
+];
+
+const BINDABLE_MOUSE_BUTTONS: &[MouseButton] = &[
+    MouseButton::Left,
+    MouseButton::Middle,
+    MouseButton::Right,
+];
+
+impl InputAction {
+    pub fn all() -> [InputAction; 35] {
+        [
+            InputAction::ActivePlayerAddBonk,
+            InputAction::ActivePlayerHoleCompleted,
+            InputAction::AddPlayerAi,
+            InputAction::AddPlayerLocal,
+            InputAction::AddPlayerRemote,
+            InputAction::AimLeft,
+            InputAction::AimRight,
+            InputAction::Bonk,
+            InputAction::BonkCharge,
+            InputAction::CameraOrbit,
+            InputAction::CameraPan,
+            InputAction::CameraZoom,
+            InputAction::CycleAimAssist,
+            InputAction::CycleBonkInput,
+            InputAction::CycleCamera,
+            InputAction::CycleMapSet,
+            InputAction::CyclePutter,
+            InputAction::DebugGolfBallQuery,
+            InputAction::DebugLastGameRecord,
+            InputAction::DebugNamesQuery,
+            InputAction::DebugOptionalParent,
+            InputAction::DebugPartyQuery,
+            InputAction::Interact,
+            InputAction::Mulligan,
+            InputAction::NextPlayer,
+            InputAction::NextTurn,
+            InputAction::Pause,
+            InputAction::PhysicsRebuild,
+            InputAction::RemoveAi,
+            InputAction::RemoveLastPlayer,
+            InputAction::SkipFlyover,
+            InputAction::StartGame,
+            InputAction::SyncClientState,
+            InputAction::ToggleDeveloperPanels,
+            InputAction::ToggleScorecard,
+        ]
+    }
+
+    // Stored by variant name, e.g. "CycleCamera"
+    pub fn from_name(name: &str) -> Option<InputAction> {
+        InputAction::all().into_iter().find(|action| format!("{:?}", action) == name)
+    }
+
+    pub fn default_binding(&self) -> InputBinding {
+        match self {
+            InputAction::ActivePlayerAddBonk => InputBinding::Key(KeyCode::KeyB),
+            InputAction::ActivePlayerHoleCompleted => InputBinding::Key(KeyCode::KeyA),
+            InputAction::AddPlayerAi => InputBinding::Key(KeyCode::Numpad9),
+            InputAction::AddPlayerLocal => InputBinding::Key(KeyCode::Numpad7),
+            InputAction::AddPlayerRemote => InputBinding::Key(KeyCode::Numpad8),
+            InputAction::AimLeft => InputBinding::Key(KeyCode::ArrowLeft),
+            InputAction::AimRight => InputBinding::Key(KeyCode::ArrowRight),
+            InputAction::Bonk => InputBinding::Mouse(MouseButton::Right),
+            InputAction::BonkCharge => InputBinding::Key(KeyCode::Space),
+            InputAction::CameraOrbit => InputBinding::Mouse(MouseButton::Left),
+            InputAction::CameraPan => InputBinding::Key(KeyCode::ControlLeft),
+            InputAction::CameraZoom => InputBinding::Key(KeyCode::ShiftLeft),
+            InputAction::CycleAimAssist => InputBinding::Key(KeyCode::KeyG),
+            InputAction::CycleBonkInput => InputBinding::Key(KeyCode::KeyV),
+            InputAction::CycleCamera => InputBinding::Key(KeyCode::KeyC),
+            InputAction::CycleMapSet => InputBinding::Key(KeyCode::KeyM),
+            InputAction::CyclePutter => InputBinding::Key(KeyCode::KeyK),
+            InputAction::DebugGolfBallQuery => InputBinding::Key(KeyCode::KeyU),
+            InputAction::DebugLastGameRecord => InputBinding::Key(KeyCode::KeyY),
+            InputAction::DebugNamesQuery => InputBinding::Key(KeyCode::KeyO),
+            InputAction::DebugOptionalParent => InputBinding::Key(KeyCode::KeyT),
+            InputAction::DebugPartyQuery => InputBinding::Key(KeyCode::KeyP),
+            InputAction::Interact => InputBinding::Mouse(MouseButton::Left),
+            InputAction::Mulligan => InputBinding::Key(KeyCode::KeyZ),
+            InputAction::NextPlayer => InputBinding::Key(KeyCode::KeyP),
+            InputAction::NextTurn => InputBinding::Key(KeyCode::KeyN),
+            InputAction::Pause => InputBinding::Key(KeyCode::Escape),
+            InputAction::PhysicsRebuild => InputBinding::Key(KeyCode::KeyI),
+            InputAction::RemoveAi => InputBinding::Key(KeyCode::Numpad3),
+            InputAction::RemoveLastPlayer => InputBinding::Key(KeyCode::Numpad1),
+            InputAction::SkipFlyover => InputBinding::Key(KeyCode::Enter),
+            InputAction::StartGame => InputBinding::Key(KeyCode::KeyS),
+            InputAction::SyncClientState => InputBinding::Key(KeyCode::KeyQ),
+            InputAction::ToggleDeveloperPanels => InputBinding::Key(KeyCode::F3),
+            InputAction::ToggleScorecard => InputBinding::Key(KeyCode::Tab),
+        }
+    }
+}
+
+impl InputBinding {
+    // Stored as "Key:KeyA" or "Mouse:Right"
+    pub fn from_name(name: &str) -> Option<InputBinding> {
+        if let Some(key_name) = name.strip_prefix("Key:") {
+            return BINDABLE_KEYS.iter()
+                .find(|key| format!("{:?}", key) == key_name)
+                .map(|key| InputBinding::Key(*key));
+        }
+        if let Some(button_name) = name.strip_prefix("Mouse:") {
+            return BINDABLE_MOUSE_BUTTONS.iter()
+                .find(|button| format!("{:?}", button) == button_name)
+                .map(|button| InputBinding::Mouse(*button));
+        }
+        None
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            InputBinding::Key(key) => format!("Key:{:?}", key),
+            InputBinding::Mouse(button) => format!("Mouse:{:?}", button),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            InputBinding::Key(key) => format!("{:?}", key),
+            InputBinding::Mouse(button) => format!("Mouse {:?}", button),
+        }
+    }
+}
+
+impl InputMap {
+    pub fn new() -> Self {
+        let mut bindings: HashMap<InputAction, InputBinding> = HashMap::new();
+        for action in InputAction::all() {
+            bindings.insert(action, action.default_binding());
+        }
+        InputMap {
+            bindings,
+            rebind_target: None,
+            rebind_selected: InputAction::all()[0],
+        }
+    }
+
+    pub fn binding_get(&self, action: InputAction) -> InputBinding {
+        self.bindings.get(&action).copied().unwrap_or(action.default_binding())
+    }
+
+    // Stored rows go straight in, they were already swapped when they were saved
+    pub fn binding_load(&mut self, action: InputAction, binding: InputBinding) {
+        self.bindings.insert(action, binding);
+    }
+
+    // Any other action already on the binding swaps onto the one being replaced, returned so it can be saved too
+    pub fn binding_set(&mut self, action: InputAction, binding: InputBinding) -> Vec<InputAction> {
+        info!("InputMap: [{:?}] -> [{:?}]", action, binding);
+        let previous = self.binding_get(action);
+        if previous == binding {
+            return Vec::new();
+        }
+        let swapped: Vec<InputAction> = InputAction::all()
+            .into_iter()
+            .filter(|other| *other != action && self.binding_get(*other) == binding)
+            .collect();
+        for other in swapped.iter() {
+            info!("InputMap: [{:?}] swapped to [{:?}]", other, previous);
+            self.bindings.insert(*other, previous);
+        }
+        self.bindings.insert(action, binding);
+        swapped
+    }
+
+    pub fn just_pressed(&self, action: InputAction, keys: &ButtonInput<KeyCode>, mouse: &ButtonInput<MouseButton>) -> bool {
+        match self.binding_get(action) {
+            InputBinding::Key(key) => keys.just_pressed(key),
+            InputBinding::Mouse(button) => mouse.just_pressed(button),
+        }
+    }
+
+    pub fn just_released(&self, action: InputAction, keys: &ButtonInput<KeyCode>, mouse: &ButtonInput<MouseButton>) -> bool {
+        match self.binding_get(action) {
+            InputBinding::Key(key) => keys.just_released(key),
+            InputBinding::Mouse(button) => mouse.just_released(button),
+        }
+    }
+
+    pub fn pressed(&self, action: InputAction, keys: &ButtonInput<KeyCode>, mouse: &ButtonInput<MouseButton>) -> bool {
+        match self.binding_get(action) {
+            InputBinding::Key(key) => keys.pressed(key),
+            InputBinding::Mouse(button) => mouse.pressed(button),
+        }
+    }
+
+    pub fn rebind_clear(&mut self) {
+        self.rebind_target = None;
+    }
+
+    pub fn rebind_start(&mut self, action: InputAction) {
+        info!("InputMap: Awaiting new binding for [{:?}]", action);
+        self.rebind_target = Some(action);
+    }
+
+    pub fn rebind_target_get(&self) -> Option<InputAction> {
+        self.rebind_target
+    }
+
+    pub fn rebind_selected_get(&self) -> InputAction {
+        self.rebind_selected
+    }
+
+    pub fn rebind_selected_step(&mut self, forward: bool) -> InputAction {
+        let all = InputAction::all();
+        let index = all.iter().position(|action| *action == self.rebind_selected).unwrap_or(0);
+        let step = if forward { 1 } else { all.len() - 1 };
+        self.rebind_selected = all[(index + step) % all.len()];
+        self.rebind_selected
+    }
+}
+
+// Run conditions, input_just_pressed(KeyCode) equivalents that read through the InputMap
+pub fn input_action_just_pressed(
+    action: InputAction,
+) -> impl FnMut(Res<InputMap>, Res<ButtonInput<KeyCode>>, Res<ButtonInput<MouseButton>>) -> bool + Clone {
+    move |input_map: Res<InputMap>, keys: Res<ButtonInput<KeyCode>>, mouse: Res<ButtonInput<MouseButton>>| {
+        input_map.rebind_target_get().is_none() && input_map.just_pressed(action, &keys, &mouse)
+    }
+}
+
+pub fn input_action_just_released(
+    action: InputAction,
+) -> impl FnMut(Res<InputMap>, Res<ButtonInput<KeyCode>>, Res<ButtonInput<MouseButton>>) -> bool + Clone {
+    move |input_map: Res<InputMap>, keys: Res<ButtonInput<KeyCode>>, mouse: Res<ButtonInput<MouseButton>>| {
+        input_map.rebind_target_get().is_none() && input_map.just_released(action, &keys, &mouse)
+    }
+}
+
+pub fn input_action_pressed(
+    action: InputAction,
+) -> impl FnMut(Res<InputMap>, Res<ButtonInput<KeyCode>>, Res<ButtonInput<MouseButton>>) -> bool + Clone {
+    move |input_map: Res<InputMap>, keys: Res<ButtonInput<KeyCode>>, mouse: Res<ButtonInput<MouseButton>>| {
+        input_map.rebind_target_get().is_none() && input_map.pressed(action, &keys, &mouse)
+    }
+}
+
+pub fn input_map_pause_toggle(
+    mut time: ResMut<Time<Virtual>>,
+) {
+    if time.is_paused() {
+        info!("Unpaused");
+        time.unpause();
+    } else {
+        info!("Paused");
+        time.pause();
+    }
+}
+
+// Captures the next key or mouse press for the action picked on the preferences page, Escape cancels.
+// Runs before input_map_rebind_button so the click that starts a rebind isn't taken as the new binding.
+pub fn input_map_rebind_listener(
+    db: Res<DatabaseConnection>,
+    party: Res<Party>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut input_map: ResMut<InputMap>,
+) {
+    let Some(action) = input_map.rebind_target_get() else {
+        return;
+    };
+    if keys.just_pressed(KeyCode::Escape) {
+        info!("InputMap: Rebind cancelled [{:?}]", action);
+        input_map.rebind_clear();
+        return;
+    }
+    let binding = if let Some(key) = keys.get_just_pressed().find(|key| BINDABLE_KEYS.contains(key)) {
+        InputBinding::Key(*key)
+    } else if let Some(button) = mouse.get_just_pressed().find(|button| BINDABLE_MOUSE_BUTTONS.contains(button)) {
+        InputBinding::Mouse(*button)
+    } else {
+        return;
+    };
+    let swapped = input_map.binding_set(action, binding);
+    input_map.rebind_clear();
+    let main_player_id = party.main_player_get_player_id();
+    db_pipeline_input_map_save(&db, &main_player_id, action, binding);
+    for other in swapped {
+        db_pipeline_input_map_save(&db, &main_player_id, other, input_map.binding_get(other));
+    }
+}
+
+pub fn input_map_rebind_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    columns: Query<(Entity, &MenuColumn)>,
+) {
+    let text_style = menu_button_text_style(&asset_server);
+    let buttons = [RebindAction::Previous, RebindAction::Start, RebindAction::Next]
+        .into_iter()
+        .map(|action| (RebindButton(action), RebindText(action)))
+        .collect();
+    menu_button_row(&mut commands, &columns, MenuPage::Preferences, &text_style, buttons);
+}
+
+// On the preferences page, the settings themselves are boards on the level
+pub fn input_map_rebind_update_button(
+    game_handler: Res<GameHandler>,
+    input_map: Res<InputMap>,
+    mut button_text: Query<(&RebindText, &mut Text)>,
+) {
+    if !MenuPage::Preferences.shown(&game_handler) {
+        return;
+    }
+    let selected = input_map.rebind_selected_get();
+    for (text, button_text) in button_text.iter_mut() {
+        let label = match (text.0, input_map.rebind_target_get()) {
+            (RebindAction::Next, _) => String::from(">"),
+            (RebindAction::Previous, _) => String::from("<"),
+            (RebindAction::Start, Some(action)) => format!("{:?}: Press a key, Escape cancels", action),
+            (RebindAction::Start, None) => format!("{:?}: {}", selected, input_map.binding_get(selected).label()),
+        };
+        menu_button_label_set(button_text, &label);
+    }
+}
+
+pub fn input_map_rebind_button(
+    mut input_map: ResMut<InputMap>,
+    buttons: Query<(&Interaction, &RebindButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed || input_map.rebind_target_get().is_some() {
+            continue;
+        }
+        match button.0 {
+            RebindAction::Next => {
+                input_map.rebind_selected_step(true);
+            },
+            RebindAction::Previous => {
+                input_map.rebind_selected_step(false);
+            },
+            RebindAction::Start => {
+                let action = input_map.rebind_selected_get();
+                input_map.rebind_start(action);
+            },
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use bevy::prelude::*;
+
+use bevy::core::FrameCount;
+use bevy::input::common_conditions::input_just_pressed;
+
+use bevy_rapier3d::prelude::*;
+
+use std::collections::{HashMap, VecDeque};
+
+// States
+use crate::{
+    StateAimAssist,
+    StateArrow,
+    StateBonkInput,
+    StateCameraOrbitEntity,
+    StateEngineConnection,
+    StateFlyover,
+    StateGame,
+    StateGamePlayStyle,
+    StateLevel,
+    StateMapSet,
+    StateMenu,
+    StateResults,
+    StateTurn,
+};
+
+// Resources
+use crate::{
+    Inspector,
+    InspectorCategory,
+    InspectorEntry,
+    InspectorOverlay,
+    RunTrigger,
+    SceneInstanceCupGolfBall,
+    SceneInstanceHazardGolfBall,
+    SceneInstanceOutOfBoundGolfBall,
+    SceneInstancePurgedEnvironment,
+    SceneInstancePurgedGolfBalls,
+    SceneInstanceRespawnedGolfBall,
+    SceneInstanceSpawnedEnvironment,
+    SceneInstanceSpawnedGolfBalls,
+};
+
+const INSPECTOR_ENTRIES_MAX: usize = 2000;
+const INSPECTOR_ENTRIES_SHOWN: usize = 32;
+const INSPECTOR_EXPORT_PATH: &str = "inspector_log.txt";
+const INSPECTOR_FONT: &str = "fonts/MatrixtypeDisplay-KVELZ.ttf";
+const INSPECTOR_FONT_SIZE: f32 = 12.0;
+
+// Only added by main.rs when built with `--features inspector`
+pub struct InspectorPlugin;
+
+impl Plugin for InspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .insert_resource(Inspector::new())
+            .add_systems(Startup, inspector_handler_setup)
+            .add_systems(Update, (
+                inspector_handler_state_listener::<StateAimAssist>,
+                inspector_handler_state_listener::<StateArrow>,
+                inspector_handler_state_listener::<StateBonkInput>,
+                inspector_handler_state_listener::<StateCameraOrbitEntity>,
+                inspector_handler_state_listener::<StateEngineConnection>,
+                inspector_handler_state_listener::<StateFlyover>,
+                inspector_handler_state_listener::<StateGame>,
+                inspector_handler_state_listener::<StateGamePlayStyle>,
+                inspector_handler_state_listener::<StateLevel>,
+                inspector_handler_state_listener::<StateMapSet>,
+                inspector_handler_state_listener::<StateMenu>,
+                inspector_handler_state_listener::<StateResults>,
+                inspector_handler_state_listener::<StateTurn>,
+            ))
+            .add_systems(Update, inspector_handler_run_trigger_listener)
+            .add_systems(Update, inspector_handler_scene_event_listener)
+            .add_systems(Update, inspector_handler_rapier_listener)
+            .add_systems(Update, inspector_handler_toggle.run_if(input_just_pressed(KeyCode::F4)))
+            .add_systems(Update, inspector_handler_cycle_filter.run_if(input_just_pressed(KeyCode::F5)))
+            .add_systems(Update, inspector_handler_export.run_if(input_just_pressed(KeyCode::F6)))
+            .add_systems(Update, inspector_handler_update_overlay);
+    }
+}
+
+impl Inspector {
+    pub fn new() -> Self {
+        Inspector {
+            entries: VecDeque::new(),
+            filter: None,
+            visible: false,
+            run_triggers_last: vec![false; RunTrigger::all_targets().len()],
+            bodies_sleeping: HashMap::new(),
+            bodies_snapshot: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, frame: u32, category: InspectorCategory, text: String) {
+        if self.entries.len() == INSPECTOR_ENTRIES_MAX {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(InspectorEntry { frame, category, text });
+    }
+
+    // None shows everything, then steps through each category in turn
+    pub fn filter_cycle(&mut self) {
+        self.filter = match self.filter {
+            None => Some(InspectorCategory::State),
+            Some(InspectorCategory::State) => Some(InspectorCategory::RunTrigger),
+            Some(InspectorCategory::RunTrigger) => Some(InspectorCategory::Event),
+            Some(InspectorCategory::Event) => Some(InspectorCategory::Rapier),
+            Some(InspectorCategory::Rapier) => None,
+        };
+    }
+
+    pub fn filtered(&self) -> impl Iterator<Item = &InspectorEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| self.filter.map_or(true, |category| entry.category == category))
+    }
+}
+
+impl InspectorEntry {
+    pub fn line(&self) -> String {
+        format!("[{:>7}] {:<10} {}", self.frame, format!("{:?}", self.category), self.text)
+    }
+}
+
+pub fn inspector_handler_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    let font: Handle<Font> = asset_server.load(INSPECTOR_FONT);
+    commands.spawn((
+        TextBundle::from_section("", TextStyle { font, font_size: INSPECTOR_FONT_SIZE, color: Color::srgb(0.6, 1.0, 0.6) })
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                right: Val::Px(8.0),
+                bottom: Val::Px(8.0),
+                ..default()
+            })
+            .with_background_color(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+        InspectorOverlay,
+    ))
+    .insert(Visibility::Hidden);
+}
+
+pub fn inspector_handler_state_listener<S: States>(
+    mut transitions: EventReader<StateTransitionEvent<S>>,
+    frame: Res<FrameCount>,
+    mut inspector: ResMut<Inspector>,
+) {
+    let state_name = std::any::type_name::<S>().rsplit("::").next().unwrap_or_default();
+    for transition in transitions.read() {
+        inspector.push(frame.0, InspectorCategory::State, format!("{}: {:?} -> {:?}", state_name, transition.exited, transition.entered));
+    }
+}
+
+pub fn inspector_handler_run_trigger_listener(
+    run_trigger: Res<RunTrigger>,
+    frame: Res<FrameCount>,
+    mut inspector: ResMut<Inspector>,
+) {
+    for (index, target) in RunTrigger::all_targets().into_iter().enumerate() {
+        let active = run_trigger.get(target);
+        if active != inspector.run_triggers_last[index] {
+            inspector.run_triggers_last[index] = active;
+            let action = if active { "set" } else { "cleared" };
+            inspector.push(frame.0, InspectorCategory::RunTrigger, format!("{:?} {}", target, action));
+        }
+    }
+}
+
+pub fn inspector_handler_scene_event_listener(
+    mut cup_reader: EventReader<SceneInstanceCupGolfBall>,
+    mut hazard_reader: EventReader<SceneInstanceHazardGolfBall>,
+    mut out_of_bounds_reader: EventReader<SceneInstanceOutOfBoundGolfBall>,
+    mut purged_environment_reader: EventReader<SceneInstancePurgedEnvironment>,
+    mut purged_golf_balls_reader: EventReader<SceneInstancePurgedGolfBalls>,
+    mut respawned_reader: EventReader<SceneInstanceRespawnedGolfBall>,
+    mut spawned_environment_reader: EventReader<SceneInstanceSpawnedEnvironment>,
+    mut spawned_golf_balls_reader: EventReader<SceneInstanceSpawnedGolfBalls>,
+    frame: Res<FrameCount>,
+    mut inspector: ResMut<Inspector>,
+) {
+    let mut lines: Vec<String> = Vec::new();
+    lines.extend(cup_reader.read().map(|event| format!("{:?}", event)));
+    lines.extend(hazard_reader.read().map(|event| format!("{:?}", event)));
+    lines.extend(out_of_bounds_reader.read().map(|event| format!("{:?}", event)));
+    lines.extend(purged_environment_reader.read().map(|event| format!("{:?}", event)));
+    lines.extend(purged_golf_balls_reader.read().map(|event| format!("{:?}", event)));
+    lines.extend(respawned_reader.read().map(|event| format!("{:?}", event)));
+    lines.extend(spawned_environment_reader.read().map(|event| format!("{:?}", event)));
+    lines.extend(spawned_golf_balls_reader.read().map(|event| format!("{:?}", event)));
+    for line in lines {
+        inspector.push(frame.0, InspectorCategory::Event, line);
+    }
+}
+
+pub fn inspector_handler_rapier_listener(
+    rapier_context: Res<RapierContext>,
+    bodies: Query<(Entity, Option<&Name>, &RapierRigidBodyHandle)>,
+    frame: Res<FrameCount>,
+    mut inspector: ResMut<Inspector>,
+) {
+    let mut snapshot: Vec<String> = Vec::new();
+    let mut seen: Vec<Entity> = Vec::new();
+    for (entity, name, rb_handle) in bodies.iter() {
+        let Some(rigid_body) = rapier_context.bodies.get(rb_handle.0) else {
+            continue;
+        };
+        let label = name.map_or(format!("{:?}", entity), |name| name.to_string());
+        let sleeping = rigid_body.is_sleeping();
+        match inspector.bodies_sleeping.insert(entity, sleeping) {
+            None => inspector.push(frame.0, InspectorCategory::Rapier, format!("{} added: {:?}", label, rigid_body.body_type())),
+            Some(was_sleeping) if was_sleeping != sleeping => {
+                let action = if sleeping { "sleeping" } else { "awake" };
+                inspector.push(frame.0, InspectorCategory::Rapier, format!("{} {} at {:?}", label, action, rigid_body.translation()));
+            },
+            Some(_) => {},
+        }
+        if rigid_body.is_dynamic() {
+            snapshot.push(format!("{:<16} sleeping: {:<5} linvel: {:.3}", label, sleeping, rigid_body.linvel().norm()));
+        }
+        seen.push(entity);
+    }
+    let removed: Vec<Entity> = inspector.bodies_sleeping.keys().filter(|entity| !seen.contains(entity)).copied().collect();
+    for entity in removed {
+        inspector.bodies_sleeping.remove(&entity);
+        inspector.push(frame.0, InspectorCategory::Rapier, format!("{:?} removed", entity));
+    }
+    inspector.bodies_snapshot = snapshot;
+}
+
+pub fn inspector_handler_toggle(
+    mut inspector: ResMut<Inspector>,
+) {
+    inspector.visible = !inspector.visible;
+    info!("function: inspector_handler_toggle: [{}]", inspector.visible);
+}
+
+pub fn inspector_handler_cycle_filter(
+    mut inspector: ResMut<Inspector>,
+) {
+    inspector.filter_cycle();
+    info!("function: inspector_handler_cycle_filter: [{:?}]", inspector.filter);
+}
+
+pub fn inspector_handler_export(
+    inspector: Res<Inspector>,
+) {
+    // The export honours the active filter so a single subsystem can be pulled out
+    let log: Vec<String> = inspector.filtered().map(|entry| entry.line()).collect();
+    match std::fs::write(INSPECTOR_EXPORT_PATH, log.join("\n")) {
+        Ok(_) => info!("function: inspector_handler_export: Wrote [{}] entries to [{}]", log.len(), INSPECTOR_EXPORT_PATH),
+        Err(e) => warn!("function: inspector_handler_export: Failed to write [{}]: {:?}", INSPECTOR_EXPORT_PATH, e),
+    }
+}
+
+pub fn inspector_handler_update_overlay(
+    inspector: Res<Inspector>,
+    mut overlay: Query<(&mut Text, &mut Visibility), With<InspectorOverlay>>,
+) {
+    for (mut text, mut visibility) in overlay.iter_mut() {
+        *visibility = if inspector.visible { Visibility::Visible } else { Visibility::Hidden };
+        if !inspector.visible {
+            continue;
+        }
+        let filter = inspector.filter.map_or(String::from("All"), |category| format!("{:?}", category));
+        let mut lines = vec![format!("Inspector   Filter: {}   F5: Filter   F6: Export", filter)];
+        lines.extend(inspector.bodies_snapshot.iter().cloned());
+        lines.push(String::new());
+        let entries: Vec<String> = inspector.filtered().map(|entry| entry.line()).collect();
+        lines.extend(entries.iter().skip(entries.len().saturating_sub(INSPECTOR_ENTRIES_SHOWN)).cloned());
+        text.sections[0].value = lines.join("\n");
+    }
+}
@@ -0,0 +1,152 @@
+use bevy::prelude::*;
+
+// Resources
+use crate::{
+    GameHandler,
+    MenuButton,
+    MenuColumn,
+    MenuPage,
+};
+
+pub const MENU_BUTTON_FONT: &str = "fonts/MatrixtypeDisplay-KVELZ.ttf";
+pub const MENU_BUTTON_FONT_SIZE: f32 = 18.0;
+pub const MENU_BUTTON_TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+pub const MENU_BUTTON_COLOR: Color = Color::srgba(0.15, 0.15, 0.15, 0.9);
+pub const MENU_BUTTON_HOVER_COLOR: Color = Color::srgba(0.3, 0.3, 0.3, 0.9);
+const MENU_BUTTON_MARGIN: f32 = 24.0; // From the bottom right corner of the window
+const MENU_BUTTON_GAP: f32 = 12.0;
+
+impl MenuPage {
+    pub fn all() -> [MenuPage; 5] {
+        [
+            MenuPage::Leaderboard,
+            MenuPage::Local,
+            MenuPage::Online,
+            MenuPage::Player,
+            MenuPage::Preferences,
+        ]
+    }
+
+    // The page is up while its menu level is the one loaded
+    pub fn shown(&self, game_handler: &GameHandler) -> bool {
+        match self {
+            MenuPage::Leaderboard => game_handler.current_level_is_menu_leaderboard(),
+            MenuPage::Local => game_handler.current_level_is_menu_local(),
+            MenuPage::Online => game_handler.current_level_is_menu_online(),
+            MenuPage::Player => game_handler.current_level_is_menu_player(),
+            MenuPage::Preferences => game_handler.current_level_is_menu_preferences(),
+        }
+    }
+}
+
+pub fn menu_button_text_style(asset_server: &AssetServer) -> TextStyle {
+    TextStyle {
+        font: asset_server.load(MENU_BUTTON_FONT),
+        font_size: MENU_BUTTON_FONT_SIZE,
+        color: MENU_BUTTON_TEXT_COLOR,
+    }
+}
+
+pub fn menu_button_bundle() -> (ButtonBundle, MenuButton) {
+    (
+        ButtonBundle {
+            style: Style {
+                padding: UiRect::axes(Val::Px(18.0), Val::Px(10.0)),
+                ..default()
+            },
+            background_color: MENU_BUTTON_COLOR.into(),
+            ..default()
+        },
+        MenuButton,
+    )
+}
+
+// Helper: feature setup systems, chained after menu_button_setup in main.rs so the columns exist.
+// Adds one row to the page's column, rows stack upward in the order they're added.
+// The first bundle of each pair goes on the button, the second on its label.
+pub fn menu_button_row<B: Bundle, T: Bundle>(
+    commands: &mut Commands,
+    columns: &Query<(Entity, &MenuColumn)>,
+    page: MenuPage,
+    text_style: &TextStyle,
+    buttons: Vec<(B, T)>,
+) {
+    let Some((column, _)) = columns.iter().find(|(_, column)| column.0 == page) else {
+        warn!("function: menu_button_row: No column for [{:?}]", page);
+        return;
+    };
+    commands.entity(column).with_children(|column| {
+        column
+            .spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(MENU_BUTTON_GAP),
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|row| {
+                for (button, text) in buttons {
+                    row
+                        .spawn((menu_button_bundle(), button))
+                        .with_children(|parent| {
+                            parent.spawn((TextBundle::from_section("", text_style.clone()), text));
+                        });
+                }
+            });
+    });
+}
+
+// Only writes when the label actually changed, so the text isn't re-laid out every frame
+pub fn menu_button_label_set(mut text: Mut<Text>, label: &str) {
+    if text.sections[0].value != label {
+        text.sections[0].value = String::from(label);
+    }
+}
+
+// Startup: one column per menu page, anchored to the bottom right corner
+pub fn menu_button_setup(
+    mut commands: Commands,
+) {
+    for page in MenuPage::all() {
+        commands.spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(MENU_BUTTON_MARGIN),
+                    bottom: Val::Px(MENU_BUTTON_MARGIN),
+                    flex_direction: FlexDirection::ColumnReverse,
+                    align_items: AlignItems::FlexEnd,
+                    row_gap: Val::Px(MENU_BUTTON_GAP),
+                    ..default()
+                },
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            MenuColumn(page),
+        ));
+    }
+}
+
+pub fn menu_button_update_visibility(
+    game_handler: Res<GameHandler>,
+    mut columns: Query<(&MenuColumn, &mut Visibility)>,
+) {
+    for (column, mut visibility) in columns.iter_mut() {
+        let shown = if column.0.shown(&game_handler) { Visibility::Visible } else { Visibility::Hidden };
+        if *visibility != shown {
+            *visibility = shown;
+        }
+    }
+}
+
+pub fn menu_button_hover(
+    mut buttons: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<MenuButton>)>,
+) {
+    for (interaction, mut background) in buttons.iter_mut() {
+        *background = match interaction {
+            Interaction::None => MENU_BUTTON_COLOR.into(),
+            Interaction::Hovered | Interaction::Pressed => MENU_BUTTON_HOVER_COLOR.into(),
+        };
+    }
+}
@@ -1,7 +1,13 @@
 use bevy::prelude::*;
 
 // Resources
-use crate::Party;
+use crate::{
+    Difficulty,
+    Party,
+    Preferences,
+};
+
+const DIFFICULTY_SELECTED_SCALE: f32 = 1.15; // The picked difficulty board stands out from the other two
 
 pub fn local_party_interface_visibliity_toggle(
     // mut commands: Commands,
@@ -201,37 +207,69 @@ pub fn local_party_interface_visibliity_toggle(
     }
 }
 
-/* 
-pub fn local_party_interface_ai_material_toggle(
-    mut scene_meshes: Query<(Entity, &Name)>,
+// Tints the local menu's golf balls with each seat's color, locals fill from golfball_1 and AI from golfball_5 down.
+// Only balls whose color changed are retinted, since every tint adds a material.
+pub fn local_party_interface_golf_ball_material(
+    mut commands: Commands,
+    scene_meshes: Query<(Entity, &Name)>,
     party: Res<Party>,
-    children_query: Query<(&Children, &Name)>,
-    materials: ResMut<Assets<StandardMaterial>>,
+    children_query: Query<&Children>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
     material_query: Query<&Handle<StandardMaterial>>,
+    mut applied: Local<Vec<(Entity, Color)>>,
 ) {
-    if party.ai_vec.is_some() {
-        let owned_ai_vec = party.ai_vec.as_ref().unwrap(); 
-        let mut owned_entities_vec: Vec<Entity> = Vec::new();
-        for (entity, name) in &mut scene_meshes {
-            let name_owned = name.as_str();
-            match name_owned {
-                "local_menu_players_golfball_2" | "local_menu_players_golfball_3" | "local_menu_players_golfball_4" | "local_menu_players_golfball_5" | "local_menu_players_golfball_6" => {
-                    owned_entities_vec.push(entity);
-                },
-                _ => {},
-            }
+    let mut local_count = 0;
+    let mut ai_count = 0;
+    let mut targets: Vec<(String, Color)> = Vec::new();
+    for (player_type, color) in party.players_get_ball_materials() {
+        match player_type.as_str() {
+            "PlayerLocal" => {
+                local_count += 1;
+                targets.push((format!("local_menu_players_golfball_{}", local_count), color));
+            },
+            "PlayerAi" => {
+                ai_count += 1;
+                targets.push((format!("local_menu_ai_golfball_{}", 6 - ai_count), color));
+            },
+            _ => {},
+        }
+    }
+    applied.retain(|(entity, _)| scene_meshes.contains(*entity));
+    for (entity, name) in scene_meshes.iter() {
+        let Some((_, color)) = targets.iter().find(|(target, _)| target == name.as_str()) else {
+            continue;
+        };
+        if applied.contains(&(entity, *color)) {
+            continue;
         }
-        let mut vec_to_send: Vec<Entity> = Vec::new();
-        for target in owned_ai_vec.iter() {
-            vec_to_send.push(owned_entities_vec[target - 1]);
-        } 
-        if owned_entities_vec.len() > 0 {
-            update_gltf_material_color(vec_to_send, children_query, materials, material_query);
+        if update_gltf_material_color(&mut commands, vec![(entity, *color)], &children_query, &mut materials, &material_query) {
+            applied.retain(|(applied_entity, _)| *applied_entity != entity);
+            applied.push((entity, *color));
+        }
+    }
+}
+
+pub fn local_party_interface_difficulty_highlight(
+    preferences: Res<Preferences>,
+    mut scene_meshes: Query<(&Name, &mut Transform)>,
+) {
+    for (name, mut transform) in scene_meshes.iter_mut() {
+        let difficulty = match name.as_str() {
+            "local_menu_ai_difficulty_easy" => Difficulty::Easy,
+            "local_menu_ai_difficulty_medium" => Difficulty::Medium,
+            "local_menu_ai_difficulty_hard" => Difficulty::Hard,
+            _ => continue,
         };
+        let scale = if difficulty == preferences.difficulty { Vec3::splat(DIFFICULTY_SELECTED_SCALE) } else { Vec3::ONE };
+        if transform.scale != scale {
+            transform.scale = scale;
+        }
     }
 }
 
-fn update_current_mesh_color(
+pub const GOLF_BALL_COLOR_COUNT: u32 = 9; // Indexes cycled through by update_current_mesh_color
+
+pub fn update_current_mesh_color(
     index: u32,
 ) -> Color {
     match index {
@@ -250,62 +288,80 @@ fn update_current_mesh_color(
         4 => { // Blue
             Color::srgb(0.0, 0.0, 1.0)
         },
+        5 => { // Yellow
+            Color::srgb(1.0, 0.9, 0.0)
+        },
+        6 => { // Orange
+            Color::srgb(1.0, 0.45, 0.0)
+        },
+        7 => { // Purple
+            Color::srgb(0.55, 0.0, 1.0)
+        },
+        8 => { // Cyan
+            Color::srgb(0.0, 0.9, 0.9)
+        },
         _ => { // Gray
             Color::srgb(0.3, 0.3, 0.3)
         },
     }
 }
 
-fn update_gltf_material_color(
-    entity_vec: Vec<Entity>,
-    children_query: Query<(&Children, &Name)>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-    material_query: Query<&Handle<StandardMaterial>>,
-) {
-    for entity in entity_vec {
+// glTF instances share their material handles, so each entity gets its own copy before it's recolored.
+// Returns false if none of the entities had a material yet, e.g. the scene hasn't finished spawning.
+pub fn update_gltf_material_color(
+    commands: &mut Commands,
+    entity_vec: Vec<(Entity, Color)>,
+    children_query: &Query<&Children>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    material_query: &Query<&Handle<StandardMaterial>>,
+) -> bool {
+    let mut updated = false;
+    for (entity, color) in entity_vec {
         info!("[{:?}]", entity);
-        if let Ok((children, name)) = children_query.get(entity) {
-            process_entity_children(
-                &mut materials,
-                &material_query,
+        if let Ok(children) = children_query.get(entity) {
+            updated |= process_entity_children(
+                commands,
+                materials,
+                material_query,
                 children,
-                name,
-                &children_query,
+                children_query,
+                color,
             );
         }
     }
+    updated
 }
 
 fn process_entity_children(
+    commands: &mut Commands,
     materials: &mut ResMut<Assets<StandardMaterial>>,
     material_query: &Query<&Handle<StandardMaterial>>,
     children: &Children,
-    name: &Name,
-    children_query: &Query<(&Children, &Name)>,
-) {
+    children_query: &Query<&Children>,
+    color: Color,
+) -> bool {
+    let mut updated = false;
     for &child in children.iter() {
-        let name_owned = name.as_str();
-        match name_owned {
-            "local_menu_players_golfball_2" | "local_menu_players_golfball_3" | "local_menu_players_golfball_4" | "local_menu_players_golfball_5" | "local_menu_players_golfball_6" => {
-                if let Ok(material_handle) = material_query.get(child) {
-                    if let Some(material) = materials.get_mut(material_handle) {
-                        material.base_color = update_current_mesh_color(1);
-                    }
-                }
-            },
-            _ => {},
+        if let Ok(material_handle) = material_query.get(child) {
+            if let Some(material) = materials.get(material_handle) {
+                let mut material = material.clone();
+                material.base_color = color;
+                commands.entity(child).insert(materials.add(material));
+                updated = true;
+            }
         }
 
         // Recursively check grandchildren
-        if let Ok((grandchildren, name)) = children_query.get(child) {
-            process_entity_children(
+        if let Ok(grandchildren) = children_query.get(child) {
+            updated |= process_entity_children(
+                commands,
                 materials,
                 material_query,
                 grandchildren,
-                name,
                 children_query,
+                color,
             );
         }
     }
-}
-*/
\ No newline at end of file
+    updated
+}
\ No newline at end of file
@@ -1,6 +1,14 @@
+pub mod bonk_input_handler;
 pub mod camera_handler;
+pub mod flyover_handler;
+pub mod hud_handler;
+pub mod input_map_handler;
+#[cfg(feature = "inspector")]
+pub mod inspector_handler;
+pub mod menu_button_handler;
 pub mod menu_handler;
 pub mod ray_system_handler;
+pub mod results_handler;
 pub mod run_trigger_handler;
 pub mod turn_handler;
 pub mod user_interface;
\ No newline at end of file
@@ -4,15 +4,22 @@ use bevy_mod_raycast::prelude::*;
 
 // --- resource Imports --- //
 use crate::{
+    AudioCue,
+    AudioSound,
     CameraWorld,
     CheckStateRT, 
+    DatabaseConnection,
+    Difficulty,
     GameHandler, 
     Interactable, 
     Party,
+    Preferences,
     RunTrigger, 
     StatePanOrbit,
 };
 
+use crate::database_handler::db_pipeline_player_golf_ball_save;
+
 pub fn draw_cursor(
     mut raycast: Raycast,
     camera_query: Query<(&Camera, &GlobalTransform), With<CameraWorld>>, // Only query for the CameraWorld    
@@ -87,6 +94,9 @@ pub fn ray_release(
     scene_meshes: Query<(Entity, &Name)>,
     windows: Query<&Window>,
     party: Res<Party>,
+    db: Res<DatabaseConnection>,
+    mut preferences: ResMut<Preferences>,
+    mut audio_cue_writer: EventWriter<AudioCue>,
 ) {    
     let (camera, camera_transform) = match camera_query.get_single() {
         Ok(result) => result,
@@ -117,6 +127,7 @@ pub fn ray_release(
                     info!("Name: {:?} Entity: {:?}", name, &entity);
                     info!("Entity Index: {}, Generation: {}", entity.index(), entity.generation());
                     let owned_name = name.as_str();
+                    let mut handled = true;
                     match owned_name {
                         // --- Menu: Main Interface Mapping --- //
                         "main_menu_interface_tutorial" => {
@@ -186,6 +197,23 @@ pub fn ray_release(
                             run_trigger.set_target(CheckStateRT::PartyHandlerRemoveAi, true);
                         },
 
+                        // Each board is a mesh with two primitives, the board and its text
+                        "local_menu_ai_difficulty_easy.0" | "local_menu_ai_difficulty_easy.1" => {
+                            preferences.difficulty = Difficulty::Easy;
+                        },
+                        "local_menu_ai_difficulty_medium.0" | "local_menu_ai_difficulty_medium.1" => {
+                            preferences.difficulty = Difficulty::Medium;
+                        },
+                        "local_menu_ai_difficulty_hard.0" | "local_menu_ai_difficulty_hard.1" => {
+                            preferences.difficulty = Difficulty::Hard;
+                        },
+
+                        // The main player's ball, clicked to cycle its color
+                        "local_menu_players_golfball_1" => {
+                            let golf_ball = party.main_player_cycle_golf_ball();
+                            db_pipeline_player_golf_ball_save(&db, &party.main_player_get_player_id(), golf_ball);
+                        },
+
                         // "local_playstyle_toggle_button_ordered.1" => {commands.insert_resource(NextState(PlayThroughStyleState::SetOrder))},
                         // "local_playstyle_toggle_button_proximity.1" => {commands.insert_resource(NextState(PlayThroughStyleState::Proximity))},
 
@@ -205,7 +233,16 @@ pub fn ray_release(
                         "player_name_name_input_block" => {
 
                         }
-                        _ => {},
+
+                        // --- Menu: Preferences Interface Mapping --- //
+                        // Each board is a mesh with two primitives, the board and its text. The name before the primitive is the setting.
+                        name if name.starts_with("preferences_") => {
+                            handled = preferences.interact(name.rsplit_once('.').map_or(name, |(setting, _)| setting));
+                        },
+                        _ => handled = false,
+                    }
+                    if handled {
+                        audio_cue_writer.send(AudioCue { sound: AudioSound::MenuClick, intensity: 1.0 });
                     }
                     if menu_camera_adj_left == true {
                         for mut state in pan_orbit_camera_query.iter_mut() {
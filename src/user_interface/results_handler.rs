@@ -0,0 +1,393 @@
+use bevy::prelude::*;
+
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+// States
+use crate::{
+    StateMapSet,
+    StateResults,
+};
+
+// Resources
+use crate::{
+    CheckStateRT,
+    GameHandler,
+    HudHandler,
+    Party,
+    ResultsAction,
+    ResultsButton,
+    ResultsHandler,
+    ResultsRoot,
+    ResultsStanding,
+    RunTrigger,
+    SceneInstanceOutOfBoundGolfBall,
+    ScoringFormat,
+    StatePanOrbit,
+};
+
+use crate::level_handler::level_handler::level_handler_par_get;
+use crate::player_handler::scoring_handler::{
+    scoring_handler_best_ball,
+    scoring_handler_score,
+};
+use crate::user_interface::menu_button_handler::menu_button_bundle;
+
+const RESULTS_FONT: &str = "fonts/MatrixtypeDisplay-KVELZ.ttf";
+const RESULTS_TITLE_FONT_SIZE: f32 = 40.0;
+const RESULTS_FONT_SIZE: f32 = 22.0;
+const RESULTS_BREAKDOWN_FONT_SIZE: f32 = 14.0;
+const RESULTS_TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const RESULTS_HIGHLIGHT_COLOR: Color = Color::srgb(1.0, 0.84, 0.3);
+
+impl ResultsHandler {
+    pub fn new() -> Self {
+        ResultsHandler {
+            map_set: StateMapSet::ToBeSelected,
+            scoring_format: ScoringFormat::StrokePlay,
+            holes: Vec::new(),
+            standings: Vec::new(),
+            scoring_summary: Vec::new(),
+            out_of_bounds: HashMap::new(),
+        }
+    }
+
+    // Snapshot the finished round, the exit routines clear the party's scores right after
+    pub fn capture(&mut self, party: &Party, hud: &HudHandler, map_set: &StateMapSet, holes: Vec<i32>, scoring_format: ScoringFormat) {
+        self.map_set = map_set.clone();
+        self.scoring_format = scoring_format;
+        self.holes = holes;
+        let players = party.all_players_get_ids_and_types();
+        let (ids, scores) = party.all_players_get_ids_and_scores();
+
+        // One standing per side, carded under the captain with the side's best ball on each hole
+        let mut standings: Vec<ResultsStanding> = Vec::new();
+        let mut team_scores: Vec<[i32; 18]> = Vec::new();
+        for team in party.teams_get() {
+            let Some(player_index) = ids.iter().position(|id| *id == team[0]) else {
+                continue;
+            };
+            let player_id = &ids[player_index];
+            let cards: Vec<[i32; 18]> = team
+                .iter()
+                .filter_map(|teammate_id| ids.iter().position(|id| id == teammate_id))
+                .map(|teammate_index| scores[teammate_index])
+                .collect();
+            let score = &scoring_handler_best_ball(&cards);
+            let name = team
+                .iter()
+                .filter_map(|teammate_id| ids.iter().position(|id| id == teammate_id))
+                .map(|teammate_index| hud.player_name_get(teammate_index, &players[teammate_index].1))
+                .collect::<Vec<String>>()
+                .join(" & ");
+            let played: Vec<(i32, i32)> = self.holes
+                .iter()
+                .map(|hole| (*hole, score.get(*hole as usize - 1).copied().unwrap_or(0)))
+                .filter(|(_, strokes)| *strokes > 0)
+                .collect();
+            let total: i32 = played.iter().map(|(_, strokes)| strokes).sum();
+            let par: i32 = played.iter().map(|(hole, _)| level_handler_par_get(*hole)).sum();
+            standings.push(ResultsStanding {
+                place: 0,
+                player_id: *player_id,
+                name,
+                color: party.player_get_ball_material(*player_id).unwrap_or(RESULTS_TEXT_COLOR),
+                score: *score,
+                total,
+                to_par: total - par,
+                points: 0,
+                hole_in_ones: played.iter().filter(|(_, strokes)| *strokes == 1).map(|(hole, _)| *hole).collect(),
+                out_of_bounds: team.iter().map(|teammate_id| self.out_of_bounds.get(teammate_id).copied().unwrap_or(0)).sum(),
+                allowance: 0,
+            });
+            team_scores.push(*score);
+        }
+
+        let names: Vec<String> = standings.iter().map(|standing| standing.name.clone()).collect();
+        let (points, scoring_summary) = scoring_handler_score(scoring_format, &self.holes, &team_scores, &names);
+        for (standing, points) in standings.iter_mut().zip(points) {
+            standing.points = points;
+        }
+        self.scoring_summary = scoring_summary;
+
+        self.standings = standings;
+        self.standings_place();
+        self.out_of_bounds.clear();
+    }
+
+    // Stable sort keeps party order on ties, tied results share a place
+    fn standings_place(&mut self) {
+        let scoring_format = self.scoring_format;
+        let standings = &mut self.standings;
+        standings.sort_by_key(|standing| scoring_format.rank_key(standing));
+        for index in 0..standings.len() {
+            standings[index].place = match index {
+                0 => 1,
+                _ if scoring_format.rank_key(&standings[index]) == scoring_format.rank_key(&standings[index - 1]) => standings[index - 1].place,
+                _ => index + 1,
+            };
+        }
+    }
+
+    // Handicap strokes per player, only stroke play ranks on them
+    pub fn handicaps_apply(&mut self, allowances: &HashMap<Uuid, i32>) {
+        for standing in self.standings.iter_mut() {
+            standing.allowance = allowances.get(&standing.player_id).copied().unwrap_or(0);
+        }
+        self.standings_place();
+    }
+
+    pub fn handicapped(&self) -> bool {
+        self.standings.iter().any(|standing| standing.allowance != 0)
+    }
+
+    // Players who sat the round out, a tournament cut or a spectator, would otherwise lead it on zero strokes
+    pub fn standings_withdraw(&mut self, player_ids: &[Uuid]) {
+        self.standings.retain(|standing| !player_ids.contains(&standing.player_id));
+        self.standings_place();
+    }
+
+    pub fn highlights(&self) -> Vec<String> {
+        let mut highlights: Vec<String> = Vec::new();
+        for standing in self.standings.iter() {
+            for hole in standing.hole_in_ones.iter() {
+                highlights.push(format!("Hole in one! {} on hole {}", standing.name, hole));
+            }
+        }
+        if let Some(most) = self.standings.iter().max_by_key(|standing| standing.out_of_bounds) {
+            if most.out_of_bounds > 0 {
+                highlights.push(format!("Most out of bounds: {} ({})", most.name, most.out_of_bounds));
+            }
+        }
+        highlights
+    }
+
+    pub fn holes_get(&self) -> &Vec<i32> {
+        &self.holes
+    }
+
+    pub fn standings_get(&self) -> &Vec<ResultsStanding> {
+        &self.standings
+    }
+
+    pub fn map_set_get(&self) -> StateMapSet {
+        self.map_set.clone()
+    }
+
+    pub fn scoring_format_get(&self) -> ScoringFormat {
+        self.scoring_format
+    }
+
+    pub fn summary_extend(&mut self, lines: Vec<String>) {
+        self.scoring_summary.extend(lines);
+    }
+
+    pub fn out_of_bounds_add(&mut self, player_id: Uuid) {
+        *self.out_of_bounds.entry(player_id).or_insert(0) += 1;
+    }
+
+    pub fn out_of_bounds_clear(&mut self) {
+        self.out_of_bounds.clear();
+    }
+}
+
+pub fn results_handler_out_of_bounds_listener(
+    mut oob_event_reader: EventReader<SceneInstanceOutOfBoundGolfBall>,
+    party: Res<Party>,
+    mut results: ResMut<ResultsHandler>,
+) {
+    // Every ball is reset on out of bounds, so info_vec lists the whole party. The shot belongs to the active player.
+    for _event in oob_event_reader.read() {
+        results.out_of_bounds_add(party.active_player_get_player_id());
+    }
+}
+
+pub fn results_handler_game_start(
+    mut results: ResMut<ResultsHandler>,
+) {
+    results.out_of_bounds_clear();
+}
+
+pub fn results_handler_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    results: Res<ResultsHandler>,
+) {
+    info!("function: results_handler_setup: Standings: [{}]", results.standings.len());
+    let font: Handle<Font> = asset_server.load(RESULTS_FONT);
+    let text_style = TextStyle { font: font.clone(), font_size: RESULTS_FONT_SIZE, color: RESULTS_TEXT_COLOR };
+    let breakdown_style = TextStyle { font: font.clone(), font_size: RESULTS_BREAKDOWN_FONT_SIZE, color: RESULTS_TEXT_COLOR };
+
+    let title = match results.map_set {
+        StateMapSet::WholeCorse => "Whole Course",
+        StateMapSet::FrontNine => "Front Nine",
+        StateMapSet::BackNine => "Back Nine",
+        StateMapSet::Tutorial => "Tutorial",
+        StateMapSet::DailyBonk => "Daily Bonk",
+        StateMapSet::SelectAHole | StateMapSet::ToBeSelected => "Round",
+    };
+
+    // Podium: one section per player so each line carries their ball color
+    let standings: Vec<TextSection> = results.standings
+        .iter()
+        .map(|standing| {
+            let to_par = match standing.to_par {
+                0 => String::from("E"),
+                to_par if to_par > 0 => format!("+{}", to_par),
+                to_par => to_par.to_string(),
+            };
+            let points = match results.scoring_format {
+                ScoringFormat::StrokePlay => String::new(),
+                format => format!("{:>5} {}", standing.points, format.points_label()),
+            };
+            let net = match results.handicapped() {
+                true => format!("{:>5} net", standing.total - standing.allowance),
+                false => String::new(),
+            };
+            let line = format!("{:<5}{:<20}{:>5}{:>6}{}{}\n", results_handler_ordinal(standing.place), standing.name, standing.total, to_par, points, net);
+            TextSection::new(line, TextStyle { color: standing.color, ..text_style.clone() })
+        })
+        .collect();
+
+    // Per-hole breakdown
+    let mut breakdown = format!("{:<20}", "Hole");
+    for hole in results.holes.iter() {
+        breakdown.push_str(&format!("{:>4}", hole));
+    }
+    breakdown.push_str(&format!("\n{:<20}", "Par"));
+    for hole in results.holes.iter() {
+        breakdown.push_str(&format!("{:>4}", level_handler_par_get(*hole)));
+    }
+    let mut breakdown_sections = vec![TextSection::new(breakdown + "\n", breakdown_style.clone())];
+    for standing in results.standings.iter() {
+        let mut row = format!("{:<20}", standing.name);
+        for hole in results.holes.iter() {
+            let strokes = standing.score.get(*hole as usize - 1).copied().unwrap_or(0);
+            row.push_str(&format!("{:>4}", if strokes == 0 { String::from("-") } else { strokes.to_string() }));
+        }
+        breakdown_sections.push(TextSection::new(row + "\n", TextStyle { color: standing.color, ..breakdown_style.clone() }));
+    }
+
+    let scoring_summary = results.scoring_summary.join("\n");
+    let highlights = results.highlights().join("\n");
+    let heading = match results.scoring_format {
+        ScoringFormat::StrokePlay => format!("{} Results", title),
+        format => format!("{} Results: {}", title, format.title()),
+    };
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            background_color: Color::srgba(0.0, 0.0, 0.0, 0.7).into(),
+            ..default()
+        })
+        .insert(ResultsRoot)
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                heading,
+                TextStyle { font_size: RESULTS_TITLE_FONT_SIZE, ..text_style.clone() },
+            ));
+            parent.spawn(TextBundle::from_sections(standings));
+            parent.spawn(TextBundle::from_sections(breakdown_sections));
+            if !scoring_summary.is_empty() {
+                parent.spawn(TextBundle::from_section(scoring_summary, breakdown_style.clone()));
+            }
+            if !highlights.is_empty() {
+                parent.spawn(TextBundle::from_section(highlights, TextStyle { color: RESULTS_HIGHLIGHT_COLOR, ..text_style.clone() }));
+            }
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(24.0),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|row| {
+                    for (label, action) in [
+                        ("Play Again", ResultsAction::PlayAgain),
+                        ("New Course", ResultsAction::NewCourse),
+                        ("Main Menu", ResultsAction::MainMenu),
+                    ] {
+                        row
+                            .spawn((menu_button_bundle(), ResultsButton(action)))
+                            .with_children(|button| {
+                                button.spawn(TextBundle::from_section(label, text_style.clone()));
+                            });
+                    }
+                });
+        });
+}
+
+pub fn results_handler_teardown(
+    mut commands: Commands,
+    results_root: Query<Entity, With<ResultsRoot>>,
+) {
+    for entity in results_root.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn results_handler_buttons(
+    mut run_trigger: ResMut<RunTrigger>,
+    mut game_handler: ResMut<GameHandler>,
+    results: Res<ResultsHandler>,
+    buttons: Query<(&Interaction, &ResultsButton), Changed<Interaction>>,
+    mut pan_orbit_camera_query: Query<&mut StatePanOrbit>,
+    mut next_results_state: ResMut<NextState<StateResults>>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        info!("function: results_handler_buttons: [{:?}]", button.0);
+        match button.0 {
+            // Same triggers the map set boards in the local menu fire
+            ResultsAction::PlayAgain => match results.map_set_get() {
+                StateMapSet::Tutorial => run_trigger.set_target(CheckStateRT::GameHandlerStartTutorial, true),
+                StateMapSet::WholeCorse => run_trigger.set_target(CheckStateRT::GameHandlerStartLocalWholeCorse, true),
+                StateMapSet::FrontNine => run_trigger.set_target(CheckStateRT::GameHandlerStartLocalFrontNine, true),
+                StateMapSet::BackNine => run_trigger.set_target(CheckStateRT::GameHandlerStartLocalBackNine, true),
+                StateMapSet::SelectAHole => run_trigger.set_target(CheckStateRT::GameHandlerStartLocalSelectAHole, true),
+                StateMapSet::DailyBonk => run_trigger.set_target(CheckStateRT::GameHandlerStartDailyBonk, true),
+                StateMapSet::ToBeSelected => {},
+            },
+            ResultsAction::NewCourse => {
+                run_trigger.set_target(CheckStateRT::LevelHandlerPurgeProtocol, true);
+                game_handler.current_level_set_menu_local();
+                run_trigger.set_target(CheckStateRT::LevelHandlerInitLevelGameHandlerCurrentLevel, true);
+                for mut state in pan_orbit_camera_query.iter_mut() {
+                    state.radius = 38.0;
+                    state.pitch = -12.0f32.to_radians();
+                    state.yaw = 17.0f32.to_radians();
+                }
+            },
+            ResultsAction::MainMenu => {}, // The exit routines already put us back on the main menu
+        }
+        info!("StateResults::Hidden");
+        next_results_state.set(StateResults::Hidden);
+    }
+}
+
+// Helper: results_handler_setup
+pub fn results_handler_ordinal(place: usize) -> String {
+    let suffix = match (place % 10, place % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", place, suffix)
+}
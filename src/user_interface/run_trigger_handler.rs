@@ -39,6 +39,7 @@ impl RunTrigger {
             game_handler_game_start: false,
             game_handler_game_state_exit_routines: false,
             game_handler_game_state_start_routines: false,
+            game_handler_start_daily_bonk: false,
             game_handler_start_local_back_nine: false,
             game_handler_start_local_front_nine: false,
             game_handler_start_local_select_a_hole: false,
@@ -74,6 +75,50 @@ impl RunTrigger {
         }
     }
 
+    // Every trigger, in declaration order
+    pub fn all_targets() -> [CheckStateRT; 38] {
+        [
+            CheckStateRT::AddPhysicsQueryAndUpdateScene,
+            CheckStateRT::CameraHandlerCycleStateCamera,
+            CheckStateRT::GameHandlerGameStart,
+            CheckStateRT::GameHandlerGameStateExitRoutines,
+            CheckStateRT::GameHandlerGameStateStartRoutines,
+            CheckStateRT::GameHandlerStartDailyBonk,
+            CheckStateRT::GameHandlerStartLocalBackNine,
+            CheckStateRT::GameHandlerStartLocalFrontNine,
+            CheckStateRT::GameHandlerStartLocalSelectAHole,
+            CheckStateRT::GameHandlerStartLocalWholeCorse,
+            CheckStateRT::GameHandlerStartTutorial,
+            CheckStateRT::GolfBallHandlerEndGame,
+            CheckStateRT::GolfBallHandlerPartyStoreLocations,
+            CheckStateRT::GolfBallHandlerResetGolfBallLocations,
+            CheckStateRT::GolfBallHandlerSpawnGolfBallsForPartyMembers,
+            CheckStateRT::GolfBallHandlerUpdateLocationsPostBonk,
+            CheckStateRT::LeaderBoardLogGame,
+            CheckStateRT::LeaderBoardReviewLastGame,
+            CheckStateRT::LevelHandlerInitLevelGameHandlerCurrentLevel,
+            CheckStateRT::LevelHandlerNextTurnProtocol,
+            CheckStateRT::LevelHandlerPurgeProtocol,
+            CheckStateRT::LevelHandlerSetStateNextLevel,
+            CheckStateRT::LevelHandlerSetStateNextMapSet,
+            CheckStateRT::NetworkGetClientStateAll,
+            CheckStateRT::NetworkGetClientStateGame,
+            CheckStateRT::PartyHandlerActivePlayerAddBonk,
+            CheckStateRT::PartyHandlerActivePlayerSetHoleCompletionStateTrue,
+            CheckStateRT::PartyHandlerCycleActivePlayer,
+            CheckStateRT::PartyHandlerNewPlayerAi,
+            CheckStateRT::PartyHandlerNewPlayerLocal,
+            CheckStateRT::PartyHandlerNewPlayerRemote,
+            CheckStateRT::PartyHandlerRemoveAi,
+            CheckStateRT::PartyHandlerRemoveLastPlayer,
+            CheckStateRT::PartyHandlerRemoveLocalPlayer,
+            CheckStateRT::TurnHandlerEndGame,
+            CheckStateRT::TurnHandlerNextRoundPrep,
+            CheckStateRT::TurnHandlerSetTurnNext,
+            CheckStateRT::StartMovementListenerTurnHandlerSetTurnNext,
+        ]
+    }
+
     pub fn get(&self, target: CheckStateRT) -> bool {
         match target {
             CheckStateRT::AddPhysicsQueryAndUpdateScene => {
@@ -91,6 +136,9 @@ impl RunTrigger {
             CheckStateRT::GameHandlerGameStateStartRoutines => {
                 self.game_handler_game_state_start_routines
             },
+            CheckStateRT::GameHandlerStartDailyBonk => {
+                self.game_handler_start_daily_bonk
+            },
             CheckStateRT::GameHandlerStartLocalBackNine => {
                 self.game_handler_start_local_back_nine
             },
@@ -212,6 +260,10 @@ impl RunTrigger {
                 self.game_handler_game_state_start_routines = state;
                 info!("response: game_handler_game_state_start_routines: {}", self.get(CheckStateRT::GameHandlerGameStateStartRoutines));
             }
+            CheckStateRT::GameHandlerStartDailyBonk => {
+                self.game_handler_start_daily_bonk = state;
+                info!("response: game_handler_start_daily_bonk: {}", self.get(CheckStateRT::GameHandlerStartDailyBonk));
+            }
             CheckStateRT::GameHandlerStartLocalBackNine => {
                 self.game_handler_start_local_back_nine = state;
                 info!("response: game_handler_start_local_back_nine: {}", self.get(CheckStateRT::GameHandlerStartLocalBackNine));
@@ -7,6 +7,7 @@ use crate::{
     StateGame, 
     StateLevel, 
     StateMapSet, 
+    StateResults,
     StateTurn,
 };
 
@@ -14,12 +15,23 @@ use crate::{
 use crate::{
     CheckStateGH,
     CheckStateRT, 
+    DailyBonk,
     GameHandler,
+    GolfBall,
+    HandicapHandler,
+    HudHandler,
+    MulliganHandler,
     Party, 
+    ResultsHandler,
     RunTrigger,
     SpawnPhysicsCheckTimer,
+    TeamMode,
+    TimeTrial,
 };
 
+use crate::level_handler::level_handler::level_handler_state_level_for_hole;
+use crate::user_interface::hud_handler::hud_handler_map_set_holes;
+
 pub fn turn_handler_set_turn_next(
     mut commands: Commands,
     mut run_trigger: ResMut<RunTrigger>,
@@ -28,7 +40,11 @@ pub fn turn_handler_set_turn_next(
     state_level: Res<State<StateLevel>>,
     state_map_set: Res<State<StateMapSet>>,
     mut next_state_turn: ResMut<NextState<StateTurn>>,
-    party: ResMut<Party>,
+    mut next_level: ResMut<NextState<StateLevel>>,
+    mut party: ResMut<Party>,
+    daily: Res<DailyBonk>,
+    time_trial: Res<TimeTrial>,
+    golf_balls: Query<(Entity, &GolfBall)>,
 ) {
     info!("function: turn_handler_set_turn_next"); 
     let mut physics_timer_check = false;
@@ -41,7 +57,24 @@ pub fn turn_handler_set_turn_next(
         
                     } else {
                         let mut load_next_level = false;
+                        let mut load_daily_hole: Option<i32> = None;
                         run_trigger.set_target(CheckStateRT::GolfBallHandlerPartyStoreLocations, true);
+
+                        // Daily Bonk stroke cap, the active player picks up once they reach it
+                        if let (StateMapSet::DailyBonk, Some(stroke_cap)) = (state_map_set.get(), daily.stroke_cap_get()) {
+                            let level = game_handler.current_level_get() as usize;
+                            if !party.active_player_get_hole_completion_state() && party.active_player_get_bonks_level(level) >= stroke_cap {
+                                info!("Daily Bonk: Stroke cap [{}] reached, picking up", stroke_cap);
+                                party.active_player_set_hole_completion_state(true);
+                                // Same as sinking it, the ball leaves the green
+                                let ball_id = party.active_ball_get_id();
+                                for (entity, golf_ball) in golf_balls.iter() {
+                                    if golf_ball.0.uuid == ball_id {
+                                        commands.entity(entity).despawn();
+                                    }
+                                }
+                            }
+                        }
         
                         let owned_finished_count = party.all_players_get_finished_count();
                         let owned_party_size = party.party_size();
@@ -95,9 +128,21 @@ pub fn turn_handler_set_turn_next(
                                     run_trigger.set_target(CheckStateRT::GolfBallHandlerEndGame, true);
                                     run_trigger.set_target(CheckStateRT::LevelHandlerInitLevelGameHandlerCurrentLevel, true);
                                 },
+                                StateMapSet::DailyBonk => {
+                                    match daily.hole_after(game_handler.current_level_get()) {
+                                        Some(hole) => load_daily_hole = Some(hole),
+                                        None => {
+                                            run_trigger.set_target(CheckStateRT::TurnHandlerEndGame, true);
+                                            run_trigger.set_target(CheckStateRT::GolfBallHandlerEndGame, true);
+                                        },
+                                    }
+                                },
                             };
                         } else { // Non Round switching turn logic below
-                            run_trigger.set_target(CheckStateRT::PartyHandlerCycleActivePlayer, true);
+                            // A time trial is a party of one, there's nobody to hand the turn to
+                            if !time_trial.running_get() {
+                                run_trigger.set_target(CheckStateRT::PartyHandlerCycleActivePlayer, true);
+                            }
                             next_state_turn.set(StateTurn::Active);
                         }   
                         
@@ -110,6 +155,18 @@ pub fn turn_handler_set_turn_next(
                             game_handler.current_level_set_next_level();
                             run_trigger.set_target(CheckStateRT::LevelHandlerInitLevelGameHandlerCurrentLevel, true);
                         }         
+
+                        // Daily holes aren't consecutive, so jump straight there instead of LevelHandlerNextTurnProtocol
+                        if let Some(hole) = load_daily_hole {
+                            run_trigger.set_target(CheckStateRT::GolfBallHandlerResetGolfBallLocations, true);
+                            run_trigger.set_target(CheckStateRT::TurnHandlerNextRoundPrep, true);
+                            run_trigger.set_target(CheckStateRT::LevelHandlerPurgeProtocol, true);
+                            next_state_turn.set(StateTurn::Active);
+                            physics_timer_check = true;
+                            game_handler.current_level_set(hole);
+                            next_level.set(level_handler_state_level_for_hole(hole));
+                            run_trigger.set_target(CheckStateRT::LevelHandlerInitLevelGameHandlerCurrentLevel, true);
+                        }
                     }
                 }
             },
@@ -129,9 +186,33 @@ pub fn turn_handler_set_turn_next(
 
 pub fn turn_handler_end_game(
     mut run_trigger: ResMut<RunTrigger>,
+    mut results: ResMut<ResultsHandler>,
+    game_handler: Res<GameHandler>,
+    hud: Res<HudHandler>,
+    party: Res<Party>,
+    daily: Res<DailyBonk>,
+    time_trial: Res<TimeTrial>,
+    mulligan: Res<MulliganHandler>,
+    handicap: Res<HandicapHandler>,
+    state_map_set: Res<State<StateMapSet>>,
+    mut next_results_state: ResMut<NextState<StateResults>>,
 ) {
     info!("function: turn_handler_end_game"); 
     {
+        let holes = hud_handler_map_set_holes(state_map_set.get(), game_handler.current_level_get(), daily.holes_get());
+        results.capture(&party, &hud, state_map_set.get(), holes, game_handler.scoring_format_get());
+        results.standings_withdraw(&party.spectators_get());
+        // The Daily Bonk is a scored attempt and sides share a card, so both play off scratch
+        if handicap.enabled_get() && *state_map_set.get() != StateMapSet::DailyBonk && party.team_mode_get() == TeamMode::Individual {
+            let hole_count = results.holes_get().len();
+            results.handicaps_apply(&handicap.allowances_get(&party.all_players_get_ids(), hole_count));
+        }
+        if time_trial.running_get() {
+            results.summary_extend(time_trial.summary_get());
+        }
+        results.summary_extend(mulligan.summary_get(&party, &hud));
+        info!("StateResults::Showing");
+        next_results_state.set(StateResults::Showing);
         run_trigger.set_target(CheckStateRT::LeaderBoardLogGame, true);
         run_trigger.set_target(CheckStateRT::GameHandlerGameStateExitRoutines, true);
     }
@@ -148,7 +229,7 @@ pub fn turn_handler_next_round_prep(
     {
         game_handler.set_target(CheckStateGH::RoundStart, true);
         party.next_round_prep();
-        party.active_player_set(1);
+        party.active_player_set_first();
     }
     run_trigger.set_target(CheckStateRT::TurnHandlerNextRoundPrep, false);
     info!("post response: turn_handler_next_round_prep: [{}]", run_trigger.get(CheckStateRT::TurnHandlerNextRoundPrep));  
@@ -1,10 +1,13 @@
 use bevy::prelude::*;
 
 use bevy_easy_vec_ui::EasyVecUi;
+use bevy_rapier3d::prelude::*;
 
 // --- State Imports --- //
 use crate::{
+    StateAimAssist,
     StateArrow,
+    StateBonkInput,
     StateCameraOrbitEntity, 
     StateEngineConnection, 
     StateGame, 
@@ -24,8 +27,10 @@ use crate::{
     CheckStateRT,
     GameHandler,
     GolfBall,
+    GolfBallStyle,
     LeaderBoard,
     Party,
+    Preferences,
     PurgeHandler,
     RunTrigger,
     StatesRef,
@@ -33,6 +38,73 @@ use crate::{
     XYMatrix,
 };
 
+use crate::level_handler::physics_handler::bonk_predict_path;
+
+pub fn aim_assist_cycle_state( // Goes through Preferences so the choice is persisted, preferences_handler_apply sets the state
+    mut preferences: ResMut<Preferences>,
+) {
+    preferences.interact("preferences_aim_assist");
+}
+
+const TRAIL_LENGTH: usize = 48;             // Points kept per ball
+const TRAIL_SPACING: f32 = 0.01;            // Minimum travel before a new point is recorded
+
+pub fn golf_ball_trail_gizmo(
+    mut gizmos: Gizmos,
+    preferences: Res<Preferences>,
+    mut golf_balls: Query<(&Transform, &mut GolfBallStyle)>,
+) {
+    for (transform, mut style) in golf_balls.iter_mut() {
+        let position = transform.translation;
+        let moved = style.trail.last().map_or(true, |last| last.distance(position) > TRAIL_SPACING);
+        if moved {
+            style.trail.push(position);
+            if style.trail.len() > TRAIL_LENGTH {
+                style.trail.remove(0);
+            }
+        } else if !style.trail.is_empty() { // Let the tail catch up once the ball settles
+            style.trail.remove(0);
+        }
+
+        if !preferences.ball_trails || style.trail.len() < 2 {
+            continue;
+        }
+        // Fade from clear at the tail to the ball's color at its head
+        let count = style.trail.len() as f32;
+        let color = style.color;
+        gizmos.linestrip_gradient(
+            style.trail
+                .iter()
+                .enumerate()
+                .map(|(idx, point)| (*point, color.with_alpha(idx as f32 / count))),
+        );
+    }
+}
+
+pub fn aim_assist_gizmo(
+    mut gizmos: Gizmos,
+    bonk: Res<BonkHandler>,
+    party: Res<Party>,
+    rapier_context: Res<RapierContext>,
+    aim_assist_state: Res<State<StateAimAssist>>,
+    golf_balls: Query<(Entity, &Transform, &GolfBall)>,
+) {
+    let max_bounces = match aim_assist_state.get() {
+        StateAimAssist::Off => return,
+        StateAimAssist::FirstBounce => Some(1),
+        StateAimAssist::Full => None,
+    };
+    if bonk.power == 0.0 {
+        return;
+    }
+    for (entity, transform, golf_ball) in golf_balls.iter() {
+        if golf_ball.0.uuid == party.active_ball_get_id() {
+            let path = bonk_predict_path(&rapier_context, entity, transform.translation, bonk.impulse_get(&party.active_player_get_putter()), max_bounces);
+            gizmos.linestrip(path, Color::srgb(1.0, 1.0, 1.0));
+        }
+    }
+}
+
 pub fn apply_rotation_matrix_camera_yaw(
     camera_yaw: &f32, // Query only for CameraWorld's Transform
     direction_x: f32,
@@ -61,27 +133,33 @@ pub fn bonk_gizmo(
     windows: Query<&Window>,
     camera_query: Query<&Transform, With<CameraWorld>>, // Query only for CameraWorld's Transform
     game_handler: Res<GameHandler>,
+    bonk_input_state: Res<State<StateBonkInput>>,
 ) {  
-    let Some(cursor_position) = windows.single().cursor_position() else {
-        return;
-    };
     let camera = camera_query.get_single();
     // Extract the yaw rotation around the y-axis from the camera's quaternion
     let camera_yaw = camera.unwrap().rotation.to_euler(EulerRot::YXZ).0; // Theta in the rotation vec
     for (transform, golf_ball) in golf_balls.iter() {
-        if golf_ball.0.uuid == party.active_player_get_player_id() { // && transform.translation != Vec3::new(0.0, 0.0, 0.0) {
+        if golf_ball.0.uuid == party.active_ball_get_id() { // && transform.translation != Vec3::new(0.0, 0.0, 0.0) {
            let ball_position = transform.translation;
-            
-            // Calculate the direction from the ball to the intersection point.
-            let direction_x = bonk.cursor_origin_position.x - cursor_position.x;
-            let direction_y = bonk.cursor_origin_position.y - cursor_position.y;
 
-            let bonk_magnitude: f32 = 2.5;
-            let adjusted_xy = apply_rotation_matrix_camera_yaw(&camera_yaw, direction_x, direction_y);
+            // The other input schemes set the direction themselves in bonk_input_handler
+            if *bonk_input_state.get() == StateBonkInput::Mouse {
+                let Some(cursor_position) = windows.single().cursor_position() else {
+                    return;
+                };
 
-            // Localize arrow to a flat xz plane 
-            let direction_xyz: Vec3 = Vec3::new(adjusted_xy.x, 0.0, adjusted_xy.y).normalize() * (bonk_magnitude * bonk.power);
-            bonk.update_direction(&direction_xyz);
+                // Calculate the direction from the ball to the intersection point.
+                let direction_x = bonk.cursor_origin_position.x - cursor_position.x;
+                let direction_y = bonk.cursor_origin_position.y - cursor_position.y;
+
+                let bonk_magnitude: f32 = 2.5;
+                let adjusted_xy = apply_rotation_matrix_camera_yaw(&camera_yaw, direction_x, direction_y);
+
+                // Localize arrow to a flat xz plane 
+                let direction_xyz: Vec3 = Vec3::new(adjusted_xy.x, 0.0, adjusted_xy.y).normalize() * (bonk_magnitude * bonk.power);
+                bonk.update_direction(&direction_xyz);
+            }
+            let direction_xyz = bonk.direction;
 
             let arrow_color = { // Color the arrow Green/Blue if the ball is sleeping
                 if game_handler.get(CheckStateGH::AllSleeping) {
@@ -150,6 +228,9 @@ pub fn easy_vec_ui(
         String::from(format!("KeyU: golf_ball_query, KeyI: add_physics_query_and_update_scene")),
         String::from(format!("KeyO: debug_names_query, KeyP: party_query")),
         String::from(format!("KeyY: last_game_record, Right Mouse: In-Game Bonk, Left mouse: Interact w/world")),
+        String::from(format!("KeyG: cycle_aim_assist, KeyV: cycle_bonk_input")),
+        String::from(format!("KeyK: cycle_putter, Tab: scorecard, F3: developer panels, Enter: skip flyover")),
+        String::from(format!("Keyboard/Meter: Arrows aim, Space charge/lock,   Gamepad: Left Stick aim, Right Trigger charge")),
     ];
 
     for entry in dedicated_left_data_vec.iter() {
@@ -179,6 +260,7 @@ pub fn easy_vec_ui(
         String::from(format!("game_handler_game_start: {:?}", run_trigger.get(CheckStateRT::GameHandlerGameStart))),
         String::from(format!("game_handler_game_state_exit_routines: {:?}", run_trigger.get(CheckStateRT::GameHandlerGameStateExitRoutines))),
         String::from(format!("game_handler_game_state_start_routines: {:?}", run_trigger.get(CheckStateRT::GameHandlerGameStateStartRoutines))),
+        String::from(format!("game_handler_start_daily_bonk: {:?}", run_trigger.get(CheckStateRT::GameHandlerStartDailyBonk))),
         String::from(format!("game_handler_start_local_back_nine: {:?}", run_trigger.get(CheckStateRT::GameHandlerStartLocalBackNine))),
         String::from(format!("game_handler_start_local_front_nine: {:?}", run_trigger.get(CheckStateRT::GameHandlerStartLocalFrontNine))),
         String::from(format!("game_handler_start_local_select_a_hole: {:?}", run_trigger.get(CheckStateRT::GameHandlerStartLocalSelectAHole))),
@@ -225,7 +307,9 @@ pub fn easy_vec_ui(
 }
 
 pub fn updated_states_ref(
+    state_aim_assist: Res<State<StateAimAssist>>,
     state_arrow: Res<State<StateArrow>>,
+    state_bonk_input: Res<State<StateBonkInput>>,
     state_camera: Res<State<StateCameraOrbitEntity>>,
     state_game: Res<State<StateGame>>,
     state_engine_connection : Res<State<StateEngineConnection>>,
@@ -237,7 +321,9 @@ pub fn updated_states_ref(
     mut all_states_ref: ResMut<StatesRef>,
 ) {
     let all_states = vec![
+        String::from(format!("state_aim_assist: {:?}", *state_aim_assist)),
         String::from(format!("state_arrow: {:?}", *state_arrow)),
+        String::from(format!("state_bonk_input: {:?}", *state_bonk_input)),
         String::from(format!("state_camera: {:?}", *state_camera)),
         String::from(format!("state_game: {:?}", *state_game)),
         String::from(format!("state_engine_connection: {:?}", *state_engine_connection)),
@@ -0,0 +1,33 @@
+use time::{
+    Date,
+    Month,
+};
+
+use minigolf::level_handler::daily_bonk_handler::daily_bonk_course;
+
+// Days since the unix epoch, the same count daily_bonk_day_today works in
+fn day(year: i32, month: Month, day: u8) -> i64 {
+    Date::from_calendar_date(year, month, day).unwrap().midnight().assume_utc().unix_timestamp().div_euclid(86_400)
+}
+
+#[test]
+fn course_is_pinned_for_a_fixed_date() {
+    // Every client has to draw this exact course, a change here is a change to everyone's Daily Bonk
+    assert_eq!(day(2026, Month::October, 19), 20745);
+    assert_eq!(daily_bonk_course(20745), (vec![9, 16, 12, 1, 17, 6], None));
+    assert_eq!(daily_bonk_course(20746), (vec![13, 9, 17, 6, 5, 12], Some(6)));
+}
+
+#[test]
+fn course_holes_are_unique_and_on_the_course() {
+    let first = day(2026, Month::January, 1);
+    for day in first..first + 365 {
+        let (holes, stroke_cap) = daily_bonk_course(day);
+        let mut sorted = holes.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), holes.len(), "Day {} repeats a hole: {:?}", day, holes);
+        assert!(holes.iter().all(|hole| (1..=18).contains(hole)), "Day {} is off the course: {:?}", day, holes);
+        assert!(stroke_cap.map_or(true, |stroke_cap| stroke_cap > 0));
+    }
+}
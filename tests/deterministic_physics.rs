@@ -0,0 +1,105 @@
+use bevy::{
+    prelude::*,
+    scene::ScenePlugin,
+    time::TimeUpdateStrategy,
+};
+
+use bevy_rapier3d::prelude::*;
+
+use std::time::Duration;
+
+use minigolf::{
+    level_handler::physics_handler::{
+        golf_ball_physics_bundle,
+        performance_physics_setup,
+        physics_handler_apply_impulses,
+        PHYSICS_TIMESTEP,
+    },
+    PhysicsHandler,
+};
+
+// Ball spawn order and bonk sequence, applied identically on every run
+const BALL_SPAWNS: [Vec3; 2] = [
+    Vec3::new(0.0, 0.05, 0.0),
+    Vec3::new(0.05, 0.05, 0.0),
+];
+const BONKS: [(usize, u32, Vec3); 3] = [
+    (0, 30, Vec3::new(0.00020, 0.0, 0.00005)),
+    (1, 45, Vec3::new(-0.00010, 0.0, 0.00025)),
+    (0, 400, Vec3::new(0.0, 0.0, -0.00025)),
+];
+const TICKS: u32 = 1200;
+
+fn build_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        HierarchyPlugin,
+        AssetPlugin::default(),
+        ScenePlugin,
+        RapierPhysicsPlugin::<NoUserData>::default().in_fixed_schedule(),
+    ))
+    .init_asset::<Mesh>()
+    .insert_resource(PhysicsHandler::new())
+    .insert_resource(Time::<Fixed>::from_seconds(PHYSICS_TIMESTEP as f64))
+    // One app update advances exactly one fixed step
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(PHYSICS_TIMESTEP)))
+    .add_systems(Startup, performance_physics_setup)
+    .add_systems(FixedUpdate, physics_handler_apply_impulses.before(PhysicsSet::SyncBackend));
+    app
+}
+
+fn spawn_level(app: &mut App) -> Vec<Entity> {
+    let world = app.world_mut();
+    // Green with a banked wall so shots rebound and settle
+    world.spawn((
+        Collider::cuboid(2.0, 0.05, 2.0),
+        TransformBundle::from(Transform::from_xyz(0.0, -0.05, 0.0)),
+    ));
+    world.spawn((
+        Collider::cuboid(0.05, 0.1, 2.0),
+        TransformBundle::from(Transform::from_xyz(0.6, 0.05, 0.0).with_rotation(Quat::from_rotation_z(0.3))),
+    ));
+    BALL_SPAWNS
+        .iter()
+        .map(|position| {
+            world
+                .spawn((golf_ball_physics_bundle(), TransformBundle::from(Transform::from_translation(*position))))
+                .id()
+        })
+        .collect()
+}
+
+fn run_bonk_sequence() -> Vec<[u32; 3]> {
+    let mut app = build_app();
+    let balls = spawn_level(&mut app);
+    for tick in 0..TICKS {
+        for (ball, at_tick, impulse) in BONKS.iter() {
+            if *at_tick == tick {
+                app.world_mut()
+                    .resource_mut::<PhysicsHandler>()
+                    .impulse_queue_push(balls[*ball], *impulse, 1.0);
+            }
+        }
+        app.update();
+    }
+    balls
+        .iter()
+        .map(|ball| {
+            let translation = app.world().get::<Transform>(*ball).unwrap().translation;
+            [translation.x.to_bits(), translation.y.to_bits(), translation.z.to_bits()]
+        })
+        .collect()
+}
+
+#[test]
+fn identical_bonk_sequence_rests_bit_identical() {
+    let first = run_bonk_sequence();
+    let second = run_bonk_sequence();
+    assert_eq!(first, second);
+    // Sanity check the bonks actually moved the balls off their spawns
+    for (rest, spawn) in first.iter().zip(BALL_SPAWNS.iter()) {
+        assert_ne!(*rest, [spawn.x.to_bits(), spawn.y.to_bits(), spawn.z.to_bits()]);
+    }
+}
@@ -0,0 +1,68 @@
+use uuid::Uuid;
+
+use minigolf::{
+    player_handler::handicap_handler::handicap_handler_from_rounds,
+    HandicapHandler,
+};
+
+const LOW_ID: Uuid = Uuid::from_u128(1);
+const HIGH_ID: Uuid = Uuid::from_u128(2);
+const SCRATCH_ID: Uuid = Uuid::from_u128(3);
+
+fn round(over_par: i32, holes: i32) -> (i32, i32, String) {
+    (over_par, holes, String::from("WholeCorse"))
+}
+
+#[test]
+fn from_rounds_needs_three_rounds() {
+    assert_eq!(handicap_handler_from_rounds(&[round(4, 18), round(6, 18)]), None);
+    assert_eq!(handicap_handler_from_rounds(&[round(4, 18), round(6, 18), round(8, 18)]), Some(6.0));
+}
+
+#[test]
+fn from_rounds_averages_the_best_four() {
+    let rounds = [round(20, 18), round(2, 18), round(4, 18), round(30, 18), round(6, 18), round(8, 18)];
+    assert_eq!(handicap_handler_from_rounds(&rounds), Some(5.0));
+}
+
+#[test]
+fn from_rounds_scales_to_eighteen_holes_and_rounds_to_one_decimal() {
+    // 9 holes at 1 over counts as 2 over, the average is 7 / 3
+    let rounds = [round(1, 9), round(2, 18), round(3, 18)];
+    assert_eq!(handicap_handler_from_rounds(&rounds), Some(2.3));
+}
+
+#[test]
+fn from_rounds_skips_short_and_daily_bonk_rounds() {
+    let rounds = [
+        round(0, 18),
+        round(0, 18),
+        (-3, 6, String::from("WholeCorse")),
+        (-6, 18, String::from("DailyBonk")),
+    ];
+    assert_eq!(handicap_handler_from_rounds(&rounds), None);
+    let mut rounds = rounds.to_vec();
+    rounds.push(round(3, 18));
+    assert_eq!(handicap_handler_from_rounds(&rounds), Some(1.0));
+}
+
+#[test]
+fn allowances_are_against_the_lowest_handicap() {
+    let mut handicap_handler = HandicapHandler::new();
+    handicap_handler.handicap_set(LOW_ID, Some(2.0));
+    handicap_handler.handicap_set(HIGH_ID, Some(11.0));
+    let allowances = handicap_handler.allowances_get(&[LOW_ID, HIGH_ID, SCRATCH_ID], 18);
+    assert_eq!(allowances.get(&HIGH_ID), Some(&9));
+    // The lowest handicap gets nothing and a player without one plays off scratch
+    assert_eq!(allowances.get(&LOW_ID), None);
+    assert_eq!(allowances.get(&SCRATCH_ID), None);
+}
+
+#[test]
+fn allowances_scale_to_the_holes_played() {
+    let mut handicap_handler = HandicapHandler::new();
+    handicap_handler.handicap_set(LOW_ID, Some(-1.0));
+    handicap_handler.handicap_set(HIGH_ID, Some(11.0));
+    assert_eq!(handicap_handler.allowances_get(&[LOW_ID, HIGH_ID], 9).get(&HIGH_ID), Some(&6));
+    assert!(handicap_handler.allowances_get(&[HIGH_ID], 18).is_empty());
+}
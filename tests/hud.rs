@@ -0,0 +1,20 @@
+use minigolf::{
+    user_interface::hud_handler::hud_handler_map_set_holes,
+    StateMapSet,
+};
+
+#[test]
+fn tutorial_cards_its_own_hole() {
+    assert_eq!(hud_handler_map_set_holes(&StateMapSet::Tutorial, 19, &[]), vec![19]);
+}
+
+#[test]
+fn single_holes_stay_on_the_course() {
+    assert_eq!(hud_handler_map_set_holes(&StateMapSet::SelectAHole, 7, &[]), vec![7]);
+    assert_eq!(hud_handler_map_set_holes(&StateMapSet::ToBeSelected, 0, &[]), vec![1]);
+}
+
+#[test]
+fn daily_bonk_cards_the_drawn_holes() {
+    assert_eq!(hud_handler_map_set_holes(&StateMapSet::DailyBonk, 9, &[9, 16, 12]), vec![9, 16, 12]);
+}
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+
+use minigolf::{
+    InputAction,
+    InputBinding,
+    InputMap,
+};
+
+#[test]
+fn rebinding_onto_a_taken_key_swaps() {
+    let mut input_map = InputMap::new();
+    let swapped = input_map.binding_set(InputAction::Mulligan, InputBinding::Key(KeyCode::KeyC));
+    assert_eq!(swapped, vec![InputAction::CycleCamera]);
+    assert_eq!(input_map.binding_get(InputAction::Mulligan), InputBinding::Key(KeyCode::KeyC));
+    assert_eq!(input_map.binding_get(InputAction::CycleCamera), InputBinding::Key(KeyCode::KeyZ));
+}
+
+#[test]
+fn rebinding_onto_a_free_key_swaps_nothing() {
+    let mut input_map = InputMap::new();
+    assert!(input_map.binding_set(InputAction::Mulligan, InputBinding::Key(KeyCode::KeyJ)).is_empty());
+    assert_eq!(input_map.binding_get(InputAction::Mulligan), InputBinding::Key(KeyCode::KeyJ));
+    assert!(input_map.binding_set(InputAction::Mulligan, InputBinding::Key(KeyCode::KeyJ)).is_empty());
+}
+
+#[test]
+fn rebinding_swaps_every_action_on_the_key() {
+    // NextPlayer and DebugPartyQuery share KeyP by default
+    let mut input_map = InputMap::new();
+    let swapped = input_map.binding_set(InputAction::NextTurn, InputBinding::Key(KeyCode::KeyP));
+    assert_eq!(swapped, vec![InputAction::DebugPartyQuery, InputAction::NextPlayer]);
+    assert_eq!(input_map.binding_get(InputAction::NextPlayer), InputBinding::Key(KeyCode::KeyN));
+    assert_eq!(input_map.binding_get(InputAction::DebugPartyQuery), InputBinding::Key(KeyCode::KeyN));
+}
+
+#[test]
+fn loading_a_stored_binding_never_swaps() {
+    let mut input_map = InputMap::new();
+    input_map.binding_load(InputAction::Mulligan, InputBinding::Key(KeyCode::KeyC));
+    assert_eq!(input_map.binding_get(InputAction::CycleCamera), InputBinding::Key(KeyCode::KeyC));
+}
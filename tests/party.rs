@@ -0,0 +1,70 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use minigolf::{
+    user_interface::menu_handler::update_current_mesh_color,
+    Party,
+    Player,
+    PlayerAi,
+    PlayerLocal,
+    TeamMode,
+};
+
+#[test]
+fn adding_a_player_keeps_their_golf_ball() {
+    let party = Party::new();
+    let mut player = PlayerLocal::new();
+    player.set_golf_ball(4);
+    party.players_add_player(Arc::new(Mutex::new(player)));
+    let players = party.players_get_ball_materials();
+    assert_eq!(players[1], (String::from("PlayerLocal"), update_current_mesh_color(4)));
+}
+
+#[test]
+fn free_golf_ball_skips_the_taken_colors() {
+    let party = Party::new();
+    let main_golf_ball = party.main_player_cycle_golf_ball();
+    let mut player = PlayerAi::new();
+    player.set_golf_ball(main_golf_ball + 1);
+    party.players_add_player(Arc::new(Mutex::new(player)));
+    assert_eq!(party.players_golf_ball_free(), main_golf_ball + 2);
+}
+
+#[test]
+fn hazard_penalties_count_on_the_card_apart_from_the_bonks() {
+    let party = Party::new();
+    let player_id = party.main_player_get_player_id();
+    party.active_player_add_bonk(3);
+    party.player_add_penalty(player_id, 3, 2);
+    assert_eq!(party.player_get_bonks_level(player_id, 3), 3);
+    assert_eq!(party.player_get_penalties_level(player_id, 3), 2);
+    assert_eq!(party.player_get_score(player_id)[2], 3);
+}
+
+#[test]
+fn last_hole_counts_and_the_tutorial_stays_off_the_card() {
+    let party = Party::new();
+    let player_id = party.main_player_get_player_id();
+    party.active_player_add_bonk(18);
+    party.active_player_add_bonk(19);
+    party.player_add_penalty(player_id, 19, 1);
+    assert_eq!(party.player_get_bonks_level(player_id, 18), 1);
+    assert_eq!(party.player_get_bonks_level(player_id, 19), 0);
+}
+
+#[test]
+fn alternate_shot_penalties_do_not_move_the_turn_on() {
+    let mut party = Party::new();
+    party.players_add_player(Arc::new(Mutex::new(PlayerLocal::new())));
+    while party.team_mode_get() != TeamMode::AlternateShot {
+        party.team_mode_cycle();
+    }
+    let ids = party.all_players_get_ids();
+    party.active_player_add_bonk(1);
+    party.player_add_penalty(ids[0], 1, 1);
+    assert_eq!(party.player_get_score(ids[1])[0], 2); // The side shares the card
+    party.next_player_set_order();
+    assert_eq!(party.active_player_get_player_id(), ids[1]);
+}
@@ -0,0 +1,54 @@
+use minigolf::{
+    preferences_handler::{
+        preferences_aim_assist_from_names,
+        preferences_aim_assist_names,
+    },
+    Difficulty,
+    Preferences,
+    StateAimAssist,
+};
+
+#[test]
+fn camera_follow_lag_steps_and_stops_at_zero() {
+    let mut preferences = Preferences::new();
+    let lag = preferences.camera_follow_lag;
+    assert!(preferences.interact("preferences_camera_follow_lag_up"));
+    assert!(preferences.camera_follow_lag > lag);
+    for _ in 0..40 {
+        preferences.interact("preferences_camera_follow_lag_down");
+    }
+    assert_eq!(preferences.camera_follow_lag, 0.0);
+}
+
+#[test]
+fn cup_framing_reads_off_at_zero() {
+    let mut preferences = Preferences::new();
+    for _ in 0..40 {
+        preferences.interact("preferences_camera_follow_cup_distance_down");
+    }
+    assert_eq!(preferences.camera_follow_cup_distance, 0.0);
+    assert_eq!(preferences.label("preferences_camera_follow_cup_distance"), "Cup framing: Off");
+}
+
+#[test]
+fn aim_assist_follows_the_difficulty() {
+    let mut preferences = Preferences::new();
+    preferences.difficulty = Difficulty::Easy;
+    assert_eq!(preferences.aim_assist_get(), StateAimAssist::Full);
+    preferences.difficulty = Difficulty::Hard;
+    assert_eq!(preferences.aim_assist_get(), StateAimAssist::Off);
+    // Cycling only changes the difficulty that's picked
+    preferences.interact("preferences_aim_assist");
+    assert_eq!(preferences.aim_assist_get(), StateAimAssist::FirstBounce);
+    preferences.difficulty = Difficulty::Easy;
+    assert_eq!(preferences.aim_assist_get(), StateAimAssist::Full);
+}
+
+#[test]
+fn aim_assist_round_trips_through_its_stored_names() {
+    let stored = preferences_aim_assist_names(&Preferences::new().aim_assist);
+    assert_eq!(stored, "Full,FirstBounce,Off");
+    assert_eq!(preferences_aim_assist_from_names(&stored), Preferences::new().aim_assist);
+    // Rows saved before the split hold one name for every difficulty
+    assert_eq!(preferences_aim_assist_from_names("Full"), [StateAimAssist::Full, StateAimAssist::Full, StateAimAssist::Full]);
+}
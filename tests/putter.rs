@@ -0,0 +1,26 @@
+use uuid::Uuid;
+
+use minigolf::Putter;
+
+const PLAYER_ID: Uuid = Uuid::from_u128(0x0192_3f4e_5a6b_7c8d_9eaf_b0c1_d2e3_f405);
+
+#[test]
+fn wobble_repeats_for_the_same_shot() {
+    let cannon = Putter::from_index(3);
+    let yaw = cannon.wobble_yaw(1.0, PLAYER_ID, 4, 2);
+    assert_eq!(yaw, cannon.wobble_yaw(1.0, PLAYER_ID, 4, 2));
+    assert!(yaw.abs() <= cannon.wobble);
+}
+
+#[test]
+fn wobble_varies_between_strokes() {
+    let cannon = Putter::from_index(3);
+    let yaws: Vec<f32> = (1..=6).map(|stroke| cannon.wobble_yaw(1.0, PLAYER_ID, 4, stroke)).collect();
+    assert!(yaws.windows(2).any(|pair| pair[0] != pair[1]));
+}
+
+#[test]
+fn steady_putters_never_wobble() {
+    let blade = Putter::from_index(0);
+    assert_eq!(blade.wobble_yaw(1.0, PLAYER_ID, 4, 2), 0.0);
+}
@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use uuid::Uuid;
+
+use minigolf::{
+    user_interface::results_handler::results_handler_ordinal,
+    HudHandler,
+    Party,
+    PlayerLocal,
+    ResultsHandler,
+    ScoringFormat,
+    StateMapSet,
+};
+
+// One player per entry, each played hole 1 in the strokes given, in party order
+fn results_for(strokes: &[i32]) -> (ResultsHandler, Vec<Uuid>) {
+    let mut party = Party::new();
+    for _ in 1..strokes.len() {
+        party.players_add_player(Arc::new(Mutex::new(PlayerLocal::new())));
+    }
+    let ids = party.all_players_get_ids();
+    for (index, strokes) in strokes.iter().enumerate() {
+        party.active_player_set(index as i32 + 1);
+        for _ in 0..*strokes {
+            party.active_player_add_bonk(1);
+        }
+    }
+    let mut results = ResultsHandler::new();
+    results.capture(&party, &HudHandler::new(), &StateMapSet::WholeCorse, vec![1], ScoringFormat::StrokePlay);
+    (results, ids)
+}
+
+fn places(results: &ResultsHandler) -> Vec<(Uuid, usize)> {
+    results.standings_get().iter().map(|standing| (standing.player_id, standing.place)).collect()
+}
+
+#[test]
+fn standings_rank_on_total_strokes() {
+    let (results, ids) = results_for(&[5, 3, 4, 6]);
+    assert_eq!(places(&results), vec![(ids[1], 1), (ids[2], 2), (ids[0], 3), (ids[3], 4)]);
+}
+
+#[test]
+fn tied_standings_share_a_place_in_party_order() {
+    let (results, ids) = results_for(&[3, 4, 3, 5]);
+    // The next place after a tie is skipped
+    assert_eq!(places(&results), vec![(ids[0], 1), (ids[2], 1), (ids[1], 3), (ids[3], 4)]);
+}
+
+#[test]
+fn withdrawn_players_leave_the_standings_and_the_rest_move_up() {
+    let (mut results, ids) = results_for(&[3, 4, 3, 5]);
+    results.standings_withdraw(&[ids[0]]);
+    assert_eq!(places(&results), vec![(ids[2], 1), (ids[1], 2), (ids[3], 3)]);
+}
+
+#[test]
+fn handicap_allowances_can_tie_the_standings() {
+    let (mut results, ids) = results_for(&[3, 5, 4, 6]);
+    results.handicaps_apply(&HashMap::from([(ids[1], 2)]));
+    assert!(results.handicapped());
+    assert_eq!(places(&results), vec![(ids[0], 1), (ids[1], 1), (ids[2], 3), (ids[3], 4)]);
+}
+
+#[test]
+fn ordinals_use_the_right_suffix() {
+    let ordinals: Vec<String> = [1, 2, 3, 4, 11, 12, 13, 21, 22, 23, 101, 111, 112].into_iter().map(results_handler_ordinal).collect();
+    assert_eq!(ordinals, ["1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "22nd", "23rd", "101st", "111th", "112th"]);
+}
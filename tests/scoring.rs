@@ -0,0 +1,92 @@
+use minigolf::{
+    player_handler::scoring_handler::{
+        scoring_handler_best_ball,
+        scoring_handler_score,
+        scoring_handler_stableford_points,
+    },
+    ScoringFormat,
+};
+
+const FRONT_THREE: [i32; 3] = [1, 2, 3]; // Pars 2, 2, 3
+
+// Strokes for the front three holes, the rest unplayed
+fn card(strokes: [i32; 3]) -> [i32; 18] {
+    let mut card = [0; 18];
+    card[..3].copy_from_slice(&strokes);
+    card
+}
+
+fn names(count: usize) -> Vec<String> {
+    ["Ann", "Bob", "Cat", "Dan"].iter().take(count).map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn stableford_points_from_par() {
+    assert_eq!(scoring_handler_stableford_points(0, 3), 0); // Unplayed
+    assert_eq!(scoring_handler_stableford_points(1, 3), 4);
+    assert_eq!(scoring_handler_stableford_points(2, 3), 3);
+    assert_eq!(scoring_handler_stableford_points(3, 3), 2);
+    assert_eq!(scoring_handler_stableford_points(4, 3), 1);
+    assert_eq!(scoring_handler_stableford_points(5, 3), 0);
+    assert_eq!(scoring_handler_stableford_points(9, 3), 0); // Never negative
+}
+
+#[test]
+fn stroke_play_scores_no_points() {
+    let (points, summary) = scoring_handler_score(ScoringFormat::StrokePlay, &FRONT_THREE, &[card([2, 2, 3]), card([3, 3, 3])], &names(2));
+    assert_eq!(points, vec![0, 0]);
+    assert!(summary.is_empty());
+}
+
+#[test]
+fn stableford_totals_the_holes_played() {
+    let (points, _) = scoring_handler_score(ScoringFormat::Stableford, &FRONT_THREE, &[card([2, 1, 5]), card([3, 0, 3])], &names(2));
+    assert_eq!(points, vec![2 + 3 + 0, 1 + 0 + 2]);
+}
+
+#[test]
+fn skins_carry_over_ties() {
+    let (points, summary) = scoring_handler_score(ScoringFormat::Skins, &FRONT_THREE, &[card([2, 3, 3]), card([2, 3, 4])], &names(2));
+    assert_eq!(points, vec![3, 0]);
+    assert_eq!(summary, vec![String::from("Hole 3: Ann takes 3 skins")]);
+}
+
+#[test]
+fn skins_left_on_the_table() {
+    let (points, summary) = scoring_handler_score(ScoringFormat::Skins, &FRONT_THREE, &[card([2, 2, 4]), card([3, 2, 4])], &names(2));
+    assert_eq!(points, vec![1, 0]);
+    assert_eq!(summary.last(), Some(&String::from("2 skins left on the table")));
+}
+
+#[test]
+fn match_play_closes_out_early() {
+    let (points, summary) = scoring_handler_score(ScoringFormat::MatchPlay, &FRONT_THREE, &[card([2, 2, 5]), card([3, 3, 3])], &names(2));
+    assert_eq!(points, vec![2, 0]);
+    assert_eq!(summary, vec![String::from("Ann beats Bob 2&1")]);
+}
+
+#[test]
+fn match_play_all_square_splits_the_points() {
+    let (points, summary) = scoring_handler_score(ScoringFormat::MatchPlay, &FRONT_THREE, &[card([2, 3, 3]), card([3, 2, 3])], &names(2));
+    assert_eq!(points, vec![1, 1]);
+    assert_eq!(summary, vec![String::from("Ann and Bob: All square")]);
+}
+
+#[test]
+fn match_play_plays_every_pairing() {
+    let scores = [card([2, 2, 3]), card([3, 2, 4]), card([3, 2, 2])];
+    let (points, summary) = scoring_handler_score(ScoringFormat::MatchPlay, &FRONT_THREE, &scores, &names(3));
+    // Ann beats Bob 2 up, Ann and Cat halve, Cat beats Bob 1 up
+    assert_eq!(points, vec![3, 0, 3]);
+    assert_eq!(summary, vec![
+        String::from("Ann beats Bob 2 up"),
+        String::from("Ann and Cat: All square"),
+        String::from("Cat beats Bob 1 up"),
+    ]);
+}
+
+#[test]
+fn best_ball_takes_the_low_score_per_hole() {
+    assert_eq!(scoring_handler_best_ball(&[card([2, 4, 0]), card([3, 2, 0])]), card([2, 2, 0]));
+    assert_eq!(scoring_handler_best_ball(&[card([2, 0, 3]), card([0, 3, 4])]), card([2, 3, 3])); // A side member who didn't finish doesn't count
+}
@@ -0,0 +1,85 @@
+use bevy::prelude::Vec3;
+
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use uuid::Uuid;
+
+use minigolf::{
+    player_handler::team_handler::team_handler_scramble_lie,
+    Party,
+    Player,
+    PlayerLocal,
+    TeamMode,
+};
+
+// A party of the given size, already on the team mode
+fn party_with(player_count: usize, team_mode: TeamMode) -> (Party, Vec<Uuid>) {
+    let mut party = Party::new();
+    for _ in 1..player_count {
+        party.players_add_player(Arc::new(Mutex::new(PlayerLocal::new())));
+    }
+    while party.team_mode_get() != team_mode {
+        party.team_mode_cycle();
+    }
+    let player_ids = party.all_players_get_ids();
+    (party, player_ids)
+}
+
+#[test]
+fn teams_pair_up_in_party_order() {
+    let (party, ids) = party_with(4, TeamMode::AlternateShot);
+    assert_eq!(party.teams_get(), vec![vec![ids[0], ids[1]], vec![ids[2], ids[3]]]);
+}
+
+#[test]
+fn teams_leave_out_spectators_before_pairing() {
+    let (mut party, ids) = party_with(4, TeamMode::AlternateShot);
+    party.spectator_add(ids[1]);
+    assert_eq!(party.teams_get(), vec![vec![ids[0], ids[2]], vec![ids[3]]]);
+}
+
+#[test]
+fn teams_leave_out_players_sitting_out() {
+    let (mut party, ids) = party_with(4, TeamMode::Scramble);
+    party.sitting_out_set(vec![ids[0]]);
+    assert_eq!(party.teams_get(), vec![vec![ids[1], ids[2]], vec![ids[3]]]);
+}
+
+#[test]
+fn alternate_shot_never_hands_the_turn_to_a_spectator() {
+    let (mut party, ids) = party_with(4, TeamMode::AlternateShot);
+    party.spectator_add(ids[1]);
+    // One stroke on the first side's card puts its second member up next
+    party.active_player_add_bonk(1);
+    for _ in 0..8 {
+        party.next_player_set_order();
+        assert_ne!(party.active_player_get_player_id(), ids[1]);
+    }
+}
+
+#[test]
+fn scramble_hazard_charges_no_penalty() {
+    let (party, ids) = party_with(2, TeamMode::Scramble);
+    party.active_player_add_bonk(1);
+    party.player_add_penalty(ids[0], 1, 1);
+    // The card stays level with the teammate's so the side still groups after the stroke
+    assert_eq!(party.player_get_bonks_level(ids[0], 1), 1);
+    assert_eq!(party.player_get_penalties_level(ids[0], 1), 0);
+}
+
+#[test]
+fn scramble_lie_skips_a_drop_zone_nearer_the_cup() {
+    let cup = Vec3::ZERO;
+    let lies = [(Vec3::new(0.0, 0.0, 5.0), false), (Vec3::new(0.0, 0.0, 1.0), true), (Vec3::new(4.0, 0.0, 4.0), false)];
+    assert_eq!(team_handler_scramble_lie(&lies, cup), Some(Vec3::new(0.0, 0.0, 5.0)));
+}
+
+#[test]
+fn scramble_lie_plays_from_the_drops_when_every_ball_found_a_hazard() {
+    let cup = Vec3::ZERO;
+    let lies = [(Vec3::new(0.0, 0.0, 3.0), true), (Vec3::new(0.0, 0.0, 2.0), true)];
+    assert_eq!(team_handler_scramble_lie(&lies, cup), Some(Vec3::new(0.0, 0.0, 2.0)));
+}